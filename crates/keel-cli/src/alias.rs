@@ -0,0 +1,129 @@
+//! Cargo-style `[alias]` expansion, run before clap ever parses argv.
+//!
+//! `main.rs` calls [`expand`] on the raw process args before
+//! `Cli::try_parse_from`. If the first positional argument isn't a real
+//! subcommand but matches an alias key in `KeelConfig.alias`, its value is
+//! whitespace-split and spliced into argv in that argument's place -- so
+//! `m = "map --tier3"` turns `keel m` into `keel map --tier3` before clap
+//! ever sees it.
+//!
+//! Aliases can expand to another alias (`shortcut = "m --verbose"`); each
+//! expansion is re-checked against the alias map, bounded by
+//! `MAX_EXPANSIONS` and a seen-set, so a cycle (`a = "b"`, `b = "a"`) can't
+//! loop forever -- it just falls through to clap's own unrecognized-command
+//! error. A built-in subcommand name is always checked before the alias
+//! map, so an alias can never shadow one.
+
+use std::collections::{HashMap, HashSet};
+
+/// Expansions bound: generous for any real alias chain, not so much a
+/// cycle could hang the CLI.
+const MAX_EXPANSIONS: usize = 8;
+
+/// Expand a user-defined alias in `argv[1]` (the first positional
+/// argument, right after the binary name), if any. Returns `argv`
+/// unchanged when there's no first argument, it's already a known
+/// built-in command, or it doesn't match any alias key. If expansion
+/// cycles, returns the last expansion reached before the repeat.
+pub fn expand(argv: &[String], known_commands: &[String], aliases: &HashMap<String, String>) -> Vec<String> {
+    let mut current = argv.to_vec();
+    if aliases.is_empty() {
+        return current;
+    }
+
+    let mut seen = HashSet::new();
+    for _ in 0..MAX_EXPANSIONS {
+        let Some(first) = current.get(1) else {
+            return current;
+        };
+        if known_commands.iter().any(|c| c == first) {
+            return current;
+        }
+        let Some(replacement) = aliases.get(first) else {
+            return current;
+        };
+        if !seen.insert(first.clone()) {
+            // Cycle -- bail with argv as last expanded, so clap reports the
+            // unresolved name as an unrecognized subcommand.
+            return current;
+        }
+
+        let tokens: Vec<String> = replacement.split_whitespace().map(str::to_string).collect();
+        if tokens.is_empty() {
+            return current;
+        }
+
+        let mut next = vec![current[0].clone()];
+        next.extend(tokens);
+        next.extend(current[2..].iter().cloned());
+        current = next;
+    }
+
+    current
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn aliases(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    fn argv(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn expands_alias_to_multiple_tokens() {
+        let known = vec!["map".to_string(), "discover".to_string()];
+        let al = aliases(&[("m", "map --tier3")]);
+        let out = expand(&argv(&["keel", "m"]), &known, &al);
+        assert_eq!(out, argv(&["keel", "map", "--tier3"]));
+    }
+
+    #[test]
+    fn preserves_trailing_arguments() {
+        let known = vec!["discover".to_string()];
+        let al = aliases(&[("d", "discover")]);
+        let out = expand(&argv(&["keel", "d", "--name", "foo"]), &known, &al);
+        assert_eq!(out, argv(&["keel", "discover", "--name", "foo"]));
+    }
+
+    #[test]
+    fn builtin_commands_are_never_shadowed() {
+        let known = vec!["map".to_string()];
+        let al = aliases(&[("map", "discover")]);
+        let out = expand(&argv(&["keel", "map"]), &known, &al);
+        assert_eq!(out, argv(&["keel", "map"]));
+    }
+
+    #[test]
+    fn unknown_first_argument_is_left_alone() {
+        let known = vec!["map".to_string()];
+        let al = aliases(&[("m", "map")]);
+        let out = expand(&argv(&["keel", "bogus"]), &known, &al);
+        assert_eq!(out, argv(&["keel", "bogus"]));
+    }
+
+    #[test]
+    fn chained_aliases_resolve_transitively() {
+        let known = vec!["map".to_string()];
+        let al = aliases(&[("shortcut", "m --verbose"), ("m", "map --tier3")]);
+        let out = expand(&argv(&["keel", "shortcut"]), &known, &al);
+        assert_eq!(out, argv(&["keel", "map", "--tier3", "--verbose"]));
+    }
+
+    #[test]
+    fn self_referential_alias_does_not_hang() {
+        let known = vec!["map".to_string()];
+        let al = aliases(&[("a", "b"), ("b", "a")]);
+        let out = expand(&argv(&["keel", "a"]), &known, &al);
+        // Never resolves to a built-in -- left as the last expansion for
+        // clap to reject as unrecognized.
+        assert!(!known.contains(&out[1]));
+    }
+}
@@ -17,6 +17,11 @@ pub(crate) struct Cli {
     /// Include info block in output
     #[arg(long, global = true)]
     pub verbose: bool,
+
+    /// Override config: a path to a JSON file, a literal JSON object, or
+    /// comma-separated key.path=value pairs (e.g. "enforce.type_hints=false")
+    #[arg(long, global = true)]
+    pub config: Option<String>,
 }
 
 #[derive(Subcommand, Debug)]
@@ -59,12 +64,39 @@ pub(crate) enum Commands {
         /// End batch mode
         #[arg(long)]
         batch_end: bool,
+        /// Report whether a batch is active, without ending it
+        #[arg(long)]
+        batch_status: bool,
+        /// Output format for --batch-end/--batch-status: "text" (default) or "json"
+        #[arg(long, default_value = "text")]
+        batch_format: String,
         /// Treat warnings as errors
         #[arg(long)]
         strict: bool,
         /// Suppress a specific error/warning code
         #[arg(long)]
         suppress: Option<String>,
+        /// Re-run enforcement on every source change until Ctrl+C
+        #[arg(long)]
+        watch: bool,
+        /// Bypass the incremental content-hash cache and recheck every file
+        #[arg(long)]
+        force: bool,
+        /// Run enforcement across N worker threads (default: 1, serial).
+        /// Bypasses the incremental content-hash cache, like --force.
+        #[arg(long, default_value = "1")]
+        jobs: usize,
+        /// Randomize file processing order with a seeded PRNG, to surface
+        /// enforcement rules that accidentally depend on file order. Pass a
+        /// fixed seed to reproduce a previous run: --shuffle=12345
+        #[arg(long, num_args = 0..=1, default_missing_value = "random")]
+        shuffle: Option<String>,
+        /// Report per-rule evaluation coverage instead of violations
+        #[arg(long)]
+        coverage: bool,
+        /// Output format for --coverage: "table" (default) or "json"
+        #[arg(long, default_value = "table")]
+        coverage_format: String,
     },
 
     /// Resolve a hash to file:line
@@ -102,6 +134,12 @@ pub(crate) enum Commands {
 
     /// Display telemetry dashboard
     Stats,
+
+    /// Run a JSONPath expression against a snapshot of the code graph
+    Query {
+        /// JSONPath expression, e.g. $.definitions[?(@.kind=='function')]
+        path: String,
+    },
 }
 
 #[cfg(test)]
@@ -186,12 +224,102 @@ mod tests {
     fn parse_compile_no_files() {
         let cli = parse(&["keel", "compile"]);
         match cli.command {
-            Commands::Compile { files, batch_start, batch_end, strict, suppress } => {
+            Commands::Compile {
+                files, batch_start, batch_end, batch_status, batch_format, strict, suppress,
+                watch, force, jobs, shuffle, coverage, coverage_format,
+            } => {
                 assert!(files.is_empty());
                 assert!(!batch_start);
                 assert!(!batch_end);
+                assert!(!batch_status);
+                assert_eq!(batch_format, "text");
                 assert!(!strict);
                 assert!(suppress.is_none());
+                assert!(!watch);
+                assert!(!force);
+                assert_eq!(jobs, 1);
+                assert!(shuffle.is_none());
+                assert!(!coverage);
+                assert_eq!(coverage_format, "table");
+            }
+            _ => panic!("expected Compile"),
+        }
+    }
+
+    #[test]
+    fn parse_compile_watch() {
+        let cli = parse(&["keel", "compile", "--watch"]);
+        match cli.command {
+            Commands::Compile { watch, .. } => {
+                assert!(watch);
+            }
+            _ => panic!("expected Compile"),
+        }
+    }
+
+    #[test]
+    fn parse_compile_force() {
+        let cli = parse(&["keel", "compile", "--force"]);
+        match cli.command {
+            Commands::Compile { force, .. } => {
+                assert!(force);
+            }
+            _ => panic!("expected Compile"),
+        }
+    }
+
+    #[test]
+    fn parse_compile_jobs() {
+        let cli = parse(&["keel", "compile", "--jobs", "4"]);
+        match cli.command {
+            Commands::Compile { jobs, .. } => {
+                assert_eq!(jobs, 4);
+            }
+            _ => panic!("expected Compile"),
+        }
+    }
+
+    #[test]
+    fn parse_compile_shuffle_bare() {
+        let cli = parse(&["keel", "compile", "--shuffle"]);
+        match cli.command {
+            Commands::Compile { shuffle, .. } => {
+                assert_eq!(shuffle.as_deref(), Some("random"));
+            }
+            _ => panic!("expected Compile"),
+        }
+    }
+
+    #[test]
+    fn parse_compile_shuffle_with_seed() {
+        let cli = parse(&["keel", "compile", "--shuffle=12345"]);
+        match cli.command {
+            Commands::Compile { shuffle, .. } => {
+                assert_eq!(shuffle.as_deref(), Some("12345"));
+            }
+            _ => panic!("expected Compile"),
+        }
+    }
+
+    #[test]
+    fn parse_compile_coverage() {
+        let cli = parse(&["keel", "compile", "--coverage"]);
+        match cli.command {
+            Commands::Compile { coverage, coverage_format, .. } => {
+                assert!(coverage);
+                assert_eq!(coverage_format, "table");
+            }
+            _ => panic!("expected Compile"),
+        }
+    }
+
+    #[test]
+    fn parse_compile_coverage_format_json() {
+        let cli = parse(&["keel", "compile", "--coverage", "--coverage-format", "json"]);
+        match cli.command {
+            Commands::Compile { coverage, coverage_format, .. } => {
+                assert!(coverage);
+                assert_eq!(coverage_format, "json");
             }
             _ => panic!("expected Compile"),
         }
@@ -232,6 +360,31 @@ mod tests {
         }
     }
 
+    #[test]
+    fn parse_compile_batch_status() {
+        let cli = parse(&["keel", "compile", "--batch-status"]);
+        match cli.command {
+            Commands::Compile { batch_start, batch_end, batch_status, .. } => {
+                assert!(!batch_start);
+                assert!(!batch_end);
+                assert!(batch_status);
+            }
+            _ => panic!("expected Compile"),
+        }
+    }
+
+    #[test]
+    fn parse_compile_batch_format_json() {
+        let cli = parse(&["keel", "compile", "--batch-end", "--batch-format", "json"]);
+        match cli.command {
+            Commands::Compile { batch_end, batch_format, .. } => {
+                assert!(batch_end);
+                assert_eq!(batch_format, "json");
+            }
+            _ => panic!("expected Compile"),
+        }
+    }
+
     #[test]
     fn parse_compile_strict_and_suppress() {
         let cli = parse(&["keel", "compile", "--strict", "--suppress", "W001"]);
@@ -324,6 +477,15 @@ mod tests {
         assert!(matches!(cli.command, Commands::Stats));
     }
 
+    #[test]
+    fn parse_query() {
+        let cli = parse(&["keel", "query", "$.definitions[*]"]);
+        match cli.command {
+            Commands::Query { path } => assert_eq!(path, "$.definitions[*]"),
+            _ => panic!("expected Query"),
+        }
+    }
+
     // --- Global flags ---
 
     #[test]
@@ -362,6 +524,18 @@ mod tests {
         assert!(cli.verbose);
     }
 
+    #[test]
+    fn global_config_flag() {
+        let cli = parse(&["keel", "--config", "tier=enterprise", "stats"]);
+        assert_eq!(cli.config.as_deref(), Some("tier=enterprise"));
+    }
+
+    #[test]
+    fn global_config_flag_defaults_to_none() {
+        let cli = parse(&["keel", "stats"]);
+        assert!(cli.config.is_none());
+    }
+
     // --- Error cases ---
 
     #[test]
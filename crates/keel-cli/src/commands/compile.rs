@@ -1,7 +1,10 @@
+use std::collections::HashSet;
 use std::fs;
 use std::path::Path;
 use std::time::Instant;
 
+use keel_enforce::engine::EnforcementEngine;
+use keel_enforce::types::{CompileInfo, CompileResult};
 use keel_output::OutputFormatter;
 use keel_parsers::go::GoResolver;
 use keel_parsers::python::PyResolver;
@@ -13,6 +16,14 @@ use keel_parsers::typescript::TsResolver;
 /// Supported file extensions for --changed filtering.
 const SUPPORTED_EXTENSIONS: &[&str] = &["rs", "py", "ts", "tsx", "js", "jsx", "go"];
 
+/// Directories `--watch` never recurses into.
+const WATCH_IGNORED_DIRS: &[&str] =
+    &[".keel", ".git", "node_modules", "__pycache__", "target", "dist", "build"];
+
+/// How long `--watch` waits with no new filesystem events before flushing
+/// the buffered set of changed paths into a single recompile.
+const WATCH_DEBOUNCE_MS: u64 = 200;
+
 /// Run `keel compile` — incremental validation of changed files.
 #[allow(clippy::too_many_arguments)]
 pub fn run(
@@ -21,6 +32,8 @@ pub fn run(
     files: Vec<String>,
     batch_start: bool,
     batch_end: bool,
+    batch_status: bool,
+    batch_format: String,
     strict: bool,
     suppress: Option<String>,
     _depth: u32,
@@ -28,6 +41,12 @@ pub fn run(
     since: Option<String>,
     delta: bool,
     timeout: Option<u64>,
+    watch: bool,
+    force: bool,
+    jobs: usize,
+    shuffle: Option<String>,
+    coverage: bool,
+    coverage_format: String,
 ) -> i32 {
     let start = Instant::now();
 
@@ -45,6 +64,12 @@ pub fn run(
         return 2;
     }
 
+    // --batch-status only reads the on-disk journal -- it doesn't touch the
+    // graph database or end the batch, so it runs without the compile lock.
+    if batch_status {
+        return output_batch_status(&keel_dir, &batch_format);
+    }
+
     // Acquire compile lock to prevent concurrent corruption
     let _lock = match acquire_compile_lock(&keel_dir, verbose) {
         Some(lock) => lock,
@@ -75,9 +100,20 @@ pub fn run(
         engine.suppress(code);
     }
 
-    // Handle batch mode
+    // --watch takes over the process entirely: it never returns until the
+    // user hits Ctrl+C, so batch/changed/since/delta/timeout don't apply.
+    if watch {
+        return run_watch(formatter, verbose, strict, engine, &db_path, &cwd);
+    }
+
+    // Handle batch mode: the in-memory `batch_state` on `engine` doesn't
+    // survive this process exiting, so the durable record of an
+    // in-progress batch is the on-disk journal -- see `BatchJournal`.
     if batch_start {
-        engine.batch_start();
+        if let Err(e) = keel_enforce::batch::BatchJournal::start(&keel_dir) {
+            eprintln!("keel compile: failed to start batch journal: {}", e);
+            return 2;
+        }
         if verbose {
             eprintln!("keel compile: batch mode started");
         }
@@ -85,8 +121,16 @@ pub fn run(
     }
 
     if batch_end {
-        let result = engine.batch_end();
-        return output_result(formatter, &result, strict, verbose);
+        return output_batch_end(&keel_dir, &batch_format, strict);
+    }
+
+    // If a batch is in progress, put this invocation's engine into batch
+    // mode too, so `compile()` defers non-structural violations instead of
+    // firing them immediately -- they're appended to the journal below and
+    // only surface together once `--batch-end` reads it back.
+    let mut batch_journal = keel_enforce::batch::BatchJournal::load(&keel_dir);
+    if batch_journal.is_some() {
+        engine.batch_start();
     }
 
     // Resolve target files: --changed, --since, explicit list, or all
@@ -106,13 +150,7 @@ pub fn run(
         }
     }
 
-    // Parse target files into FileIndex entries.
-    let mut ts: Option<TsResolver> = None;
-    let mut py: Option<PyResolver> = None;
-    let mut go_resolver: Option<GoResolver> = None;
-    let mut rs: Option<RustLangResolver> = None;
-
-    let target_files = if effective_files.is_empty() {
+    let mut target_files = if effective_files.is_empty() {
         let walker = keel_parsers::walker::FileWalker::new(&cwd);
         walker
             .walk()
@@ -133,52 +171,50 @@ pub fn run(
             .collect::<Vec<_>>()
     };
 
-    let mut file_indices: Vec<FileIndex> = Vec::new();
-
-    for file_str in &target_files {
-        let file_path = Path::new(file_str);
-        let lang = match detect_language(file_path) {
-            Some(l) => l,
-            None => continue,
-        };
-        let content = match fs::read_to_string(file_path) {
-            Ok(c) => c,
-            Err(e) => {
-                if verbose {
-                    eprintln!("keel compile: skipping {}: {}", file_str, e);
-                }
-                continue;
-            }
-        };
-
-        let resolver: &dyn LanguageResolver = match lang {
-            "typescript" | "javascript" | "tsx" => ts.get_or_insert_with(TsResolver::new),
-            "python" => py.get_or_insert_with(PyResolver::new),
-            "go" => go_resolver.get_or_insert_with(GoResolver::new),
-            "rust" => rs.get_or_insert_with(RustLangResolver::new),
-            _ => continue,
-        };
-
-        let result = resolver.parse_file(file_path, &content);
-        let rel_path = make_relative(&cwd, file_path);
-        let content_hash = xxhash_rust::xxh64::xxh64(content.as_bytes(), 0);
-
-        file_indices.push(FileIndex {
-            file_path: rel_path,
-            content_hash,
-            definitions: result.definitions,
-            references: result.references,
-            imports: result.imports,
-            external_endpoints: result.external_endpoints,
-            parse_duration_us: 0,
-        });
+    if let Some(seed_arg) = &shuffle {
+        let seed = resolve_shuffle_seed(seed_arg);
+        eprintln!("keel compile: shuffled file order (seed={})", seed);
+        shuffle_seeded(&mut target_files, seed);
     }
 
+    let mut resolvers = Resolvers::new();
+    let file_indices = resolvers.parse_files(&cwd, &target_files, verbose);
+
     if verbose && !file_indices.is_empty() {
         eprintln!("keel compile: checking {} file(s)", file_indices.len());
     }
 
-    let result = engine.compile(&file_indices);
+    // Captured before `file_indices` is potentially moved into `run_parallel`,
+    // for the batch journal entry recorded below.
+    let checked_file_paths: Vec<String> = file_indices.iter().map(|f| f.file_path.clone()).collect();
+
+    // --jobs runs enforcement across a thread pool instead of the normal
+    // incremental path: partitioning files per worker isn't compatible with
+    // the global dirty-file selection in `compile_incremental`, so a
+    // multi-job run always rechecks every file, like --force.
+    let (mut engine, result, rule_coverage) = if jobs > 1 {
+        run_parallel(&db_path, &config, &cb_state, &suppress, batch_journal.is_some(), file_indices, jobs)
+    } else {
+        let mut hash_cache = keel_enforce::incremental::FileHashCache::load(&keel_dir);
+        let result = engine.compile_incremental(&file_indices, &mut hash_cache, force);
+        if let Err(e) = hash_cache.save(&keel_dir) {
+            if verbose {
+                eprintln!("keel compile: failed to save file hash cache: {}", e);
+            }
+        }
+        let rule_coverage = engine.rule_coverage().to_vec();
+        (engine, result, rule_coverage)
+    };
+
+    // Append this invocation's deferred violations to the batch journal, if
+    // a batch is in progress.
+    if let Some(journal) = &mut batch_journal {
+        let deferred = engine.take_deferred_violations();
+        journal.record(checked_file_paths.clone(), deferred);
+        if let Err(e) = journal.save(&keel_dir) {
+            eprintln!("keel compile: failed to persist batch journal: {}", e);
+        }
+    }
 
     // Persist circuit breaker state back to SQLite
     let cb_out = engine.export_circuit_breaker();
@@ -194,6 +230,11 @@ pub fn run(
         }
     }
 
+    // --coverage reports per-rule evaluation counts instead of violations.
+    if coverage {
+        return output_coverage(&rule_coverage, &coverage_format);
+    }
+
     // Delta mode: diff against previous snapshot
     if delta {
         use keel_enforce::snapshot::{compute_delta, ViolationSnapshot};
@@ -254,6 +295,414 @@ pub fn run(
     output_result(formatter, &result, strict, verbose)
 }
 
+/// Holds the per-language resolvers used to parse a batch of files,
+/// reused across multiple `parse_files` calls so tier2 caches (cross-file
+/// resolution, type tables, etc.) stay warm instead of being rebuilt on
+/// every `--watch` cycle.
+struct Resolvers {
+    ts: Option<TsResolver>,
+    py: Option<PyResolver>,
+    go: Option<GoResolver>,
+    rs: Option<RustLangResolver>,
+}
+
+impl Resolvers {
+    fn new() -> Self {
+        Resolvers { ts: None, py: None, go: None, rs: None }
+    }
+
+    /// Parse `target_files` into `FileIndex` entries, skipping files with
+    /// an unsupported or undetectable language.
+    fn parse_files(&mut self, cwd: &Path, target_files: &[String], verbose: bool) -> Vec<FileIndex> {
+        let mut file_indices = Vec::new();
+
+        for file_str in target_files {
+            let file_path = Path::new(file_str);
+            let lang = match detect_language(file_path) {
+                Some(l) => l,
+                None => continue,
+            };
+            let content = match fs::read_to_string(file_path) {
+                Ok(c) => c,
+                Err(e) => {
+                    if verbose {
+                        eprintln!("keel compile: skipping {}: {}", file_str, e);
+                    }
+                    continue;
+                }
+            };
+
+            let resolver: &dyn LanguageResolver = match lang {
+                "typescript" | "javascript" | "tsx" => self.ts.get_or_insert_with(TsResolver::new),
+                "python" => self.py.get_or_insert_with(PyResolver::new),
+                "go" => self.go.get_or_insert_with(GoResolver::new),
+                "rust" => self.rs.get_or_insert_with(RustLangResolver::new),
+                _ => continue,
+            };
+
+            let result = resolver.parse_file(file_path, &content);
+            let rel_path = make_relative(cwd, file_path);
+            let content_hash = xxhash_rust::xxh64::xxh64(content.as_bytes(), 0);
+
+            file_indices.push(FileIndex {
+                file_path: rel_path,
+                content_hash,
+                definitions: result.definitions,
+                references: result.references,
+                imports: result.imports,
+                external_endpoints: result.external_endpoints,
+                parse_duration_us: 0,
+            });
+        }
+
+        file_indices
+    }
+}
+
+/// Resolve a `--shuffle` argument into a concrete seed: the sentinel
+/// `"random"` (clap's `default_missing_value` for a bare `--shuffle`)
+/// generates a fresh seed from the system clock, otherwise the argument is
+/// parsed as the seed to reuse from a previous run.
+fn resolve_shuffle_seed(arg: &str) -> u64 {
+    if arg == "random" {
+        return std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0);
+    }
+    arg.parse().unwrap_or_else(|_| {
+        eprintln!(
+            "keel compile: invalid --shuffle seed '{}', generating a random one instead",
+            arg
+        );
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|d| d.as_nanos() as u64)
+            .unwrap_or(0)
+    })
+}
+
+/// Small, seedable, non-cryptographic PRNG (splitmix64) used to shuffle file
+/// processing order deterministically. Not suitable for anything security
+/// sensitive -- it exists purely to reproduce order-dependent flakiness.
+struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        SplitMix64 { state: seed }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E3779B97F4A7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..bound` (slightly biased for very large bounds,
+    /// irrelevant here since `bound` is at most a few thousand files).
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+/// Fisher-Yates shuffle, seeded so a run can be reproduced via `--shuffle=<seed>`.
+fn shuffle_seeded(items: &mut [String], seed: u64) {
+    let mut rng = SplitMix64::new(seed);
+    for i in (1..items.len()).rev() {
+        let j = rng.below(i + 1);
+        items.swap(i, j);
+    }
+}
+
+/// Run enforcement for `file_indices` across `jobs` worker threads: files
+/// are split into contiguous chunks and each chunk is checked by its own
+/// `EnforcementEngine`, backed by its own `SqliteGraphStore` connection to
+/// `db_path` -- workers don't share a lock for the duration of `compile()`,
+/// so they actually overlap instead of queueing behind one engine-wide
+/// mutex. SQLite's own locking, not a Rust-level `Mutex`, is what keeps
+/// concurrent writes to `db_path` safe. Per-worker results (violations,
+/// rule coverage, circuit breaker state, batch-deferred violations) are
+/// merged afterward into one coordinator `EnforcementEngine` with the same
+/// `config`/`cb_state`/`suppress`/batch-mode setup as the caller's engine,
+/// so the rest of `run()` can keep treating it like a single engine.
+/// Violations are sorted by file then line so output is stable regardless
+/// of thread scheduling. `--jobs` bypasses the incremental content-hash
+/// cache (see caller).
+#[allow(clippy::too_many_arguments)]
+fn run_parallel(
+    db_path: &Path,
+    config: &keel_core::config::KeelConfig,
+    cb_state: &[(String, String, u32, bool)],
+    suppress: &Option<String>,
+    batch_active: bool,
+    file_indices: Vec<FileIndex>,
+    jobs: usize,
+) -> (EnforcementEngine, CompileResult, Vec<keel_enforce::types::RuleCoverage>) {
+    let chunk_size = (file_indices.len() + jobs - 1) / jobs.max(1);
+    let chunks: Vec<Vec<FileIndex>> = if chunk_size == 0 {
+        vec![file_indices]
+    } else {
+        file_indices
+            .chunks(chunk_size)
+            .map(|c| c.to_vec())
+            .collect()
+    };
+
+    type WorkerOutput = (
+        CompileResult,
+        Vec<keel_enforce::types::RuleCoverage>,
+        Vec<keel_enforce::types::Violation>,
+        Vec<(String, String, u32, bool)>,
+    );
+
+    let per_worker: Vec<WorkerOutput> = std::thread::scope(|scope| {
+        let handles: Vec<_> = chunks
+            .into_iter()
+            .filter(|chunk| !chunk.is_empty())
+            .map(|chunk| {
+                scope.spawn(move || {
+                    let store = keel_core::sqlite::SqliteGraphStore::open(
+                        db_path.to_str().unwrap_or(""),
+                    )
+                    .expect("enforcement worker failed to open its own graph database connection");
+                    let mut worker_engine =
+                        EnforcementEngine::with_config(Box::new(store), config);
+                    worker_engine.import_circuit_breaker(cb_state);
+                    if let Some(code) = suppress {
+                        worker_engine.suppress(code);
+                    }
+                    if batch_active {
+                        worker_engine.batch_start();
+                    }
+                    let result = worker_engine.compile(&chunk);
+                    let coverage = worker_engine.rule_coverage().to_vec();
+                    let deferred = worker_engine.take_deferred_violations();
+                    let cb_out = worker_engine.export_circuit_breaker();
+                    (result, coverage, deferred, cb_out)
+                })
+            })
+            .collect();
+        handles
+            .into_iter()
+            .map(|h| h.join().expect("enforcement worker thread panicked"))
+            .collect()
+    });
+
+    let store = keel_core::sqlite::SqliteGraphStore::open(db_path.to_str().unwrap_or(""))
+        .expect("failed to reopen graph database to merge parallel compile results");
+    let mut engine = EnforcementEngine::with_config(Box::new(store), config);
+    if let Some(code) = suppress {
+        engine.suppress(code);
+    }
+    if batch_active {
+        engine.batch_start();
+    }
+
+    let mut results = Vec::new();
+    let mut coverages = Vec::new();
+    let mut merged_cb: Vec<(String, String, u32, bool)> = Vec::new();
+    let mut merged_deferred = Vec::new();
+    for (result, coverage, deferred, cb_out) in per_worker {
+        results.push(result);
+        coverages.push(coverage);
+        merged_deferred.extend(deferred);
+        merged_cb.extend(cb_out);
+    }
+    engine.import_circuit_breaker(&merged_cb);
+    engine.merge_deferred_violations(merged_deferred);
+
+    (engine, merge_compile_results(results), merge_rule_coverage(coverages))
+}
+
+/// Merge each worker's `rule_coverage()` snapshot from `run_parallel` into
+/// one set of totals, since each worker only calls `compile` on its own
+/// chunk and `EnforcementEngine::rule_coverage` reports the most recent
+/// call only.
+fn merge_rule_coverage(
+    coverages: Vec<Vec<keel_enforce::types::RuleCoverage>>,
+) -> Vec<keel_enforce::types::RuleCoverage> {
+    use keel_enforce::types::RuleCoverage;
+
+    let mut merged: Vec<RuleCoverage> = Vec::new();
+    for worker_coverage in coverages {
+        for rule in worker_coverage {
+            match merged.iter_mut().find(|r| r.rule == rule.rule) {
+                Some(existing) => {
+                    existing.evaluated += rule.evaluated;
+                    existing.flagged += rule.flagged;
+                    existing.per_file.extend(rule.per_file);
+                }
+                None => merged.push(rule),
+            }
+        }
+    }
+    merged
+}
+
+/// Merge per-worker `CompileResult`s from `run_parallel` into one result,
+/// sorting violations by file then line so the merged output doesn't
+/// depend on which worker finished first.
+fn merge_compile_results(results: Vec<CompileResult>) -> CompileResult {
+    let mut merged = CompileResult {
+        version: "0.1.0".to_string(),
+        command: "compile".to_string(),
+        status: "ok".to_string(),
+        files_analyzed: Vec::new(),
+        errors: Vec::new(),
+        warnings: Vec::new(),
+        info: CompileInfo {
+            nodes_updated: 0,
+            edges_updated: 0,
+            hashes_changed: Vec::new(),
+        },
+    };
+
+    for r in results {
+        merged.files_analyzed.extend(r.files_analyzed);
+        merged.errors.extend(r.errors);
+        merged.warnings.extend(r.warnings);
+        merged.info.nodes_updated += r.info.nodes_updated;
+        merged.info.edges_updated += r.info.edges_updated;
+        merged.info.hashes_changed.extend(r.info.hashes_changed);
+        if r.status == "error" {
+            merged.status = "error".to_string();
+        } else if r.status == "warning" && merged.status != "error" {
+            merged.status = "warning".to_string();
+        }
+    }
+
+    merged.errors.sort_by(|a, b| (a.file.as_str(), a.line).cmp(&(b.file.as_str(), b.line)));
+    merged.warnings.sort_by(|a, b| (a.file.as_str(), a.line).cmp(&(b.file.as_str(), b.line)));
+    merged.files_analyzed.sort();
+    merged.info.hashes_changed.sort();
+    merged
+}
+
+/// Run `keel compile --watch` -- a debounced, long-running enforcement
+/// loop. Watches the project directory and, on source changes, waits for a
+/// quiet interval before re-parsing the changed files and re-running
+/// `EnforcementEngine::compile` in-process. The terminal is cleared before
+/// each report so the screen always shows the latest status; exit codes
+/// only matter once the user stops the loop with Ctrl+C.
+fn run_watch(
+    formatter: &dyn OutputFormatter,
+    verbose: bool,
+    strict: bool,
+    mut engine: keel_enforce::engine::EnforcementEngine,
+    db_path: &Path,
+    cwd: &Path,
+) -> i32 {
+    use notify::{Event, RecursiveMode, Watcher};
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    let (tx, rx) = mpsc::channel::<Event>();
+    let mut watcher = match notify::recommended_watcher(move |res: Result<Event, _>| {
+        if let Ok(event) = res {
+            let _ = tx.send(event);
+        }
+    }) {
+        Ok(w) => w,
+        Err(e) => {
+            eprintln!("keel compile --watch: failed to create watcher: {}", e);
+            return 2;
+        }
+    };
+
+    // Watch the project root recursively rather than individual files, so
+    // editors that save by writing a temp file and renaming it over the
+    // original still surface a change event for the original path.
+    if let Err(e) = watcher.watch(cwd, RecursiveMode::Recursive) {
+        eprintln!(
+            "keel compile --watch: failed to watch {}: {}",
+            cwd.display(),
+            e
+        );
+        return 2;
+    }
+
+    let mut resolvers = Resolvers::new();
+    eprintln!("keel compile --watch: watching for changes... (Ctrl+C to stop)");
+
+    while let Ok(event) = rx.recv() {
+        let mut changed = HashSet::new();
+        collect_watched_paths(&event, cwd, &mut changed);
+
+        // Coalesce a burst of events (a single save can fire several, and
+        // rename-on-save fires a remove plus a create) into one recompile.
+        while let Ok(ev) = rx.recv_timeout(Duration::from_millis(WATCH_DEBOUNCE_MS)) {
+            collect_watched_paths(&ev, cwd, &mut changed);
+        }
+
+        if changed.is_empty() {
+            continue;
+        }
+
+        let target_files: Vec<String> = changed.into_iter().collect();
+        let file_indices = resolvers.parse_files(cwd, &target_files, verbose);
+        if file_indices.is_empty() {
+            continue;
+        }
+
+        let result = engine.compile(&file_indices);
+
+        let cb_out = engine.export_circuit_breaker();
+        if !cb_out.is_empty() {
+            if let Ok(cb_store) =
+                keel_core::sqlite::SqliteGraphStore::open(db_path.to_str().unwrap_or(""))
+            {
+                let _ = cb_store.save_circuit_breaker(&cb_out);
+            }
+        }
+
+        // Clear the terminal so the latest result is the only thing on
+        // screen, the same way `cargo watch`-style tools do.
+        print!("\x1B[2J\x1B[1;1H");
+        output_result(formatter, &result, strict, verbose);
+    }
+
+    0
+}
+
+/// Collect changed file paths from a filesystem event, filtering out
+/// ignored directories and unsupported extensions. Paths are recorded
+/// relative to `cwd` to match what `Resolvers::parse_files` expects.
+fn collect_watched_paths(event: &notify::Event, cwd: &Path, changed: &mut HashSet<String>) {
+    if !matches!(
+        event.kind,
+        notify::EventKind::Create(_) | notify::EventKind::Modify(_)
+    ) {
+        return;
+    }
+    for path in &event.paths {
+        if !is_watchable_source(path) {
+            continue;
+        }
+        let rel = path.strip_prefix(cwd).unwrap_or(path);
+        changed.insert(rel.to_string_lossy().to_string());
+    }
+}
+
+/// Whether `path` is a source file `--watch` should react to: a supported
+/// extension, outside any ignored directory.
+fn is_watchable_source(path: &Path) -> bool {
+    for component in path.components() {
+        if let std::path::Component::Normal(s) = component {
+            if WATCH_IGNORED_DIRS.contains(&s.to_str().unwrap_or("")) {
+                return false;
+            }
+        }
+    }
+    path.extension()
+        .and_then(|e| e.to_str())
+        .map(|e| SUPPORTED_EXTENSIONS.contains(&e))
+        .unwrap_or(false)
+}
+
 /// Advisory lock guard for compile serialization.
 /// Dropped automatically when the guard goes out of scope.
 struct CompileLock {
@@ -366,6 +815,155 @@ fn filter_supported_files(text: &str) -> Vec<String> {
         .collect()
 }
 
+/// Render the `--coverage` report: for every enforcement rule, how many
+/// definitions it was evaluated against and how many it flagged this run,
+/// as either a human-readable table (default) or JSON
+/// (`--coverage-format=json`). Exits non-zero if any rule was never
+/// evaluated against a single definition -- a sign it silently stopped
+/// running -- so CI can catch the regression.
+fn output_coverage(coverage: &[keel_enforce::types::RuleCoverage], format: &str) -> i32 {
+    if format == "json" {
+        match serde_json::to_string_pretty(coverage) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("keel compile --coverage: failed to serialize report: {}", e);
+                return 2;
+            }
+        }
+    } else {
+        println!("{:<6} {:>9} {:>8} {:>6}", "RULE", "EVALUATED", "FLAGGED", "FILES");
+        for rule in coverage {
+            println!(
+                "{:<6} {:>9} {:>8} {:>6}",
+                rule.rule,
+                rule.evaluated,
+                rule.flagged,
+                rule.per_file.len()
+            );
+        }
+    }
+
+    let dead_rules: Vec<&str> = coverage
+        .iter()
+        .filter(|r| r.evaluated == 0)
+        .map(|r| r.rule.as_str())
+        .collect();
+    if !dead_rules.is_empty() {
+        eprintln!(
+            "keel compile --coverage: rule(s) never evaluated against any definition: {}",
+            dead_rules.join(", ")
+        );
+        return 1;
+    }
+
+    0
+}
+
+/// Render the `--batch-end` report: every violation deferred across the
+/// on-disk batch journal since the matching `--batch-start`, deduplicated by
+/// `(code, hash)`, as text (default) or JSON (`--batch-format=json`).
+/// Deletes the journal once read, ending the batch. A missing journal (no
+/// `--batch-start` was ever run, or a previous `--batch-end` already
+/// consumed it) is reported as zero files/violations rather than an error.
+fn output_batch_end(keel_dir: &Path, format: &str, strict: bool) -> i32 {
+    let journal = keel_enforce::batch::BatchJournal::load(keel_dir);
+    let (files_checked, errors, warnings) = match &journal {
+        Some(j) => {
+            let mut errors = Vec::new();
+            let mut warnings = Vec::new();
+            for v in j.deduplicated_violations() {
+                if v.severity == "ERROR" {
+                    errors.push(v);
+                } else {
+                    warnings.push(v);
+                }
+            }
+            (j.files_queued(), errors, warnings)
+        }
+        None => (Vec::new(), Vec::new(), Vec::new()),
+    };
+    keel_enforce::batch::BatchJournal::delete(keel_dir);
+
+    let result = keel_enforce::types::BatchEndResult {
+        version: "0.1.0".to_string(),
+        command: "compile --batch-end".to_string(),
+        files_checked,
+        errors,
+        warnings,
+    };
+
+    if format == "json" {
+        match serde_json::to_string_pretty(&result) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("keel compile --batch-end: failed to serialize report: {}", e);
+                return 2;
+            }
+        }
+    } else {
+        println!(
+            "Batch complete: {} file(s) checked, {} error(s), {} warning(s)",
+            result.files_checked.len(),
+            result.errors.len(),
+            result.warnings.len()
+        );
+        for v in result.errors.iter().chain(result.warnings.iter()) {
+            println!("  {} {}:{} {}", v.code, v.file, v.line, v.message);
+        }
+    }
+
+    if !result.errors.is_empty() || (strict && !result.warnings.is_empty()) {
+        1
+    } else {
+        0
+    }
+}
+
+/// Render the `--batch-status` report: a read-only snapshot of an
+/// in-progress batch (files checked so far, deferred violation count),
+/// without ending it or touching the journal on disk.
+fn output_batch_status(keel_dir: &Path, format: &str) -> i32 {
+    let journal = keel_enforce::batch::BatchJournal::load(keel_dir);
+    let result = match &journal {
+        Some(j) => keel_enforce::types::BatchStatusResult {
+            version: "0.1.0".to_string(),
+            command: "compile --batch-status".to_string(),
+            active: true,
+            started_at_unix_ms: Some(j.started_at_unix_ms),
+            files_checked: j.files_queued(),
+            deferred_count: j.deduplicated_violations().len() as u32,
+        },
+        None => keel_enforce::types::BatchStatusResult {
+            version: "0.1.0".to_string(),
+            command: "compile --batch-status".to_string(),
+            active: false,
+            started_at_unix_ms: None,
+            files_checked: Vec::new(),
+            deferred_count: 0,
+        },
+    };
+
+    if format == "json" {
+        match serde_json::to_string_pretty(&result) {
+            Ok(json) => println!("{}", json),
+            Err(e) => {
+                eprintln!("keel compile --batch-status: failed to serialize report: {}", e);
+                return 2;
+            }
+        }
+    } else if result.active {
+        println!(
+            "Batch active: {} file(s) checked, {} deferred violation(s)",
+            result.files_checked.len(),
+            result.deferred_count
+        );
+    } else {
+        println!("No active batch");
+    }
+
+    0
+}
+
 fn output_result(
     formatter: &dyn OutputFormatter,
     result: &keel_enforce::types::CompileResult,
@@ -3,6 +3,7 @@ use keel_core::types::{EdgeDirection, EdgeKind};
 use keel_output::OutputFormatter;
 
 use super::input_detect;
+use super::snapshot_helpers;
 
 /// Run `keel discover <query>` — accepts hash, file path, or --name.
 pub fn run(
@@ -28,8 +29,7 @@ pub fn run(
         return 2;
     }
 
-    let db_path = keel_dir.join("graph.db");
-    let store = match keel_core::sqlite::SqliteGraphStore::open(db_path.to_str().unwrap_or("")) {
+    let store = match snapshot_helpers::open_fastest_store(&cwd, &keel_dir) {
         Ok(s) => s,
         Err(e) => {
             eprintln!("keel discover: failed to open graph database: {}", e);
@@ -39,16 +39,16 @@ pub fn run(
 
     // Name lookup mode: --name flag
     if name_mode {
-        return discover_by_name(&store, &query, verbose);
+        return discover_by_name(store.as_ref(), &query, verbose);
     }
 
     // File path mode: auto-detected
     if input_detect::looks_like_file_path(&query) {
-        return discover_file(&store, &query, &cwd, verbose);
+        return discover_file(store.as_ref(), &query, &cwd, verbose);
     }
 
     // Hash mode: existing behavior
-    let engine = keel_enforce::engine::EnforcementEngine::new(Box::new(store));
+    let engine = keel_enforce::engine::EnforcementEngine::new(store);
     match engine.discover(&query, depth) {
         Some(mut result) => {
             // Add body context if --context was requested
@@ -166,6 +166,9 @@ fn discover_by_name(store: &dyn GraphStore, name: &str, _verbose: bool) -> i32 {
     let nodes = store.find_nodes_by_name(name, "", "");
     if nodes.is_empty() {
         eprintln!("keel discover: no function named '{}' found", name);
+        if let Some(suggestion) = fuzzy_name_suggestion(store, name) {
+            eprintln!("did you mean: {}", suggestion);
+        }
         return 2;
     }
 
@@ -187,3 +190,40 @@ fn discover_by_name(store: &dyn GraphStore, name: &str, _verbose: bool) -> i32 {
     }
     0
 }
+
+/// Build an FST symbol index over the whole graph and return a
+/// comma-separated list of names within edit distance 2 of `name`, for the
+/// "did you mean" hint on a failed `--name` lookup. Prefers the rkyv
+/// symbol index cache `keel map` wrote (feature `rkyv-snapshot`), falling
+/// back to a full rebuild from `store`.
+fn fuzzy_name_suggestion(store: &dyn GraphStore, name: &str) -> Option<String> {
+    let index = cached_symbol_index(store).unwrap_or_else(|| {
+        keel_core::symbol_index::SymbolIndex::from_store(store)
+    });
+    let mut names: Vec<&str> = index
+        .fuzzy(name, 2)
+        .iter()
+        .map(|m| m.name.as_str())
+        .collect();
+    if names.is_empty() {
+        return None;
+    }
+    names.sort();
+    names.dedup();
+    Some(names.join(", "))
+}
+
+/// Try `.keel/index.rkyv`, only when the cwd is still at the same tree
+/// state `keel map` stamped it with.
+#[cfg(feature = "rkyv-snapshot")]
+fn cached_symbol_index(_store: &dyn GraphStore) -> Option<keel_core::symbol_index::SymbolIndex> {
+    let cwd = std::env::current_dir().ok()?;
+    let keel_dir = cwd.join(".keel");
+    let fingerprint = snapshot_helpers::tree_fingerprint(&cwd);
+    keel_core::index_cache::IndexCache::open(&keel_dir, &fingerprint)
+}
+
+#[cfg(not(feature = "rkyv-snapshot"))]
+fn cached_symbol_index(_store: &dyn GraphStore) -> Option<keel_core::symbol_index::SymbolIndex> {
+    None
+}
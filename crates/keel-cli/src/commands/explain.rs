@@ -1,5 +1,7 @@
 use keel_output::OutputFormatter;
 
+use super::snapshot_helpers;
+
 /// Run `keel explain <error_code> <hash>` — show resolution reasoning.
 pub fn run(
     formatter: &dyn OutputFormatter,
@@ -23,10 +25,7 @@ pub fn run(
         return 2;
     }
 
-    let db_path = keel_dir.join("graph.db");
-    let store = match keel_core::sqlite::SqliteGraphStore::open(
-        db_path.to_str().unwrap_or(""),
-    ) {
+    let store = match snapshot_helpers::open_fastest_store(&cwd, &keel_dir) {
         Ok(s) => s,
         Err(e) => {
             eprintln!("keel explain: failed to open graph database: {}", e);
@@ -34,7 +33,7 @@ pub fn run(
         }
     };
 
-    let engine = keel_enforce::engine::EnforcementEngine::new(Box::new(store));
+    let engine = keel_enforce::engine::EnforcementEngine::new(store);
 
     match engine.explain(&error_code, &hash) {
         Some(mut result) => {
@@ -67,17 +67,22 @@ pub fn run(
             return 2;
         }
     };
-    let plans = keel_enforce::fix_generator::generate_fix_plans(&all_violations, &fix_store);
+    let plans = keel_enforce::fix_generator::generate_fix_plans(&all_violations, &fix_store, &cwd);
 
     let files_affected: std::collections::HashSet<&str> = plans
         .iter()
         .flat_map(|p| p.actions.iter().map(|a| a.file.as_str()))
         .collect();
+    // One violation may offer several alternative plans; count distinct violations.
+    let violations_addressed: std::collections::HashSet<(&str, &str)> = plans
+        .iter()
+        .map(|p| (p.code.as_str(), p.hash.as_str()))
+        .collect();
 
     let result = FixResult {
         version: "0.1.0".to_string(),
         command: "fix".to_string(),
-        violations_addressed: plans.len() as u32,
+        violations_addressed: violations_addressed.len() as u32,
         files_affected: files_affected.len() as u32,
         plans,
     };
@@ -111,124 +116,60 @@ pub fn run(
     exit_code
 }
 
-/// Apply fix plans by writing changes to files, then re-compile to verify.
+/// Apply fix plans via `keel-enforce`'s atomic, grouped-by-file apply
+/// engine, then re-compile to verify. The batch is all-or-nothing: either
+/// every action lands, or none do and `report.skipped` explains why.
 fn apply_fix_plans(result: &FixResult, cwd: &std::path::Path, verbose: bool) -> FixApplyResult {
+    // `result.plans` may hold several mutually-exclusive alternatives per
+    // violation (see `select_one_plan_per_violation`'s doc comment) --
+    // narrow down to one per violation before handing them to the atomic
+    // apply engine, which has no notion of "pick one" itself.
+    let selected_plans = keel_enforce::fix_generator::select_one_plan_per_violation(&result.plans);
+    let report = keel_enforce::fix_generator::apply_fix_plans(&selected_plans, cwd);
+
     let mut details = Vec::new();
-    let mut files_modified = std::collections::HashSet::new();
     let mut applied = 0u32;
     let mut failed = 0u32;
 
-    for plan in &result.plans {
-        // Validate before applying
-        let validation_errors = keel_enforce::fix_generator::validate_fix_plan(plan, cwd);
-
-        for (i, action) in plan.actions.iter().enumerate() {
-            if let Some((_, err)) = validation_errors.iter().find(|(idx, _)| *idx == i) {
+    if report.skipped.is_empty() {
+        for plan in &selected_plans {
+            for action in &plan.actions {
                 details.push(FixApplyDetail {
                     file: action.file.clone(),
                     line: action.line,
-                    status: "failed".into(),
-                    error: Some(err.clone()),
+                    status: "applied".into(),
+                    error: None,
                 });
-                failed += 1;
-                continue;
-            }
-
-            match apply_single_action(action, cwd) {
-                Ok(()) => {
-                    files_modified.insert(action.file.clone());
-                    details.push(FixApplyDetail {
-                        file: action.file.clone(),
-                        line: action.line,
-                        status: "applied".into(),
-                        error: None,
-                    });
-                    applied += 1;
-                }
-                Err(e) => {
-                    details.push(FixApplyDetail {
-                        file: action.file.clone(),
-                        line: action.line,
-                        status: "failed".into(),
-                        error: Some(e),
-                    });
-                    failed += 1;
-                }
+                applied += 1;
             }
         }
+    } else {
+        for skipped in &report.skipped {
+            details.push(FixApplyDetail {
+                file: skipped.file.clone(),
+                line: skipped.line,
+                status: "failed".into(),
+                error: Some(skipped.reason.clone()),
+            });
+            failed += 1;
+        }
     }
 
     // Re-compile to verify fixes
     let (recompile_clean, recompile_errors) = recompile_verify(cwd, verbose);
 
-    let files_vec: Vec<String> = files_modified.into_iter().collect();
     FixApplyResult {
         version: "0.1.0".into(),
         command: "fix --apply".into(),
         actions_applied: applied,
         actions_failed: failed,
-        files_modified: files_vec,
+        files_modified: report.files_changed,
         recompile_clean,
         recompile_errors,
         details,
     }
 }
 
-/// Apply a single fix action to a file.
-fn apply_single_action(
-    action: &keel_enforce::types::FixAction,
-    cwd: &std::path::Path,
-) -> Result<(), String> {
-    let path = cwd.join(&action.file);
-    let content = std::fs::read_to_string(&path).map_err(|e| format!("read error: {}", e))?;
-
-    let mut lines: Vec<String> = content.lines().map(|l| l.to_string()).collect();
-    let idx = (action.line as usize).saturating_sub(1);
-
-    if action.old_text.is_empty() {
-        // Insert new_text before the target line
-        if idx <= lines.len() {
-            lines.insert(idx, action.new_text.clone());
-        } else {
-            lines.push(action.new_text.clone());
-        }
-    } else if idx < lines.len() && lines[idx].contains(&action.old_text) {
-        // Exact replacement on the target line
-        lines[idx] = lines[idx].replace(&action.old_text, &action.new_text);
-    } else {
-        // Search nearby lines (±2) for old_text
-        let start = idx.saturating_sub(2);
-        let end = (idx + 3).min(lines.len());
-        let mut found = false;
-        for line in &mut lines[start..end] {
-            if line.contains(&action.old_text) {
-                *line = line.replace(&action.old_text, &action.new_text);
-                found = true;
-                break;
-            }
-        }
-        if !found {
-            // Fallback: insert as guidance comment
-            let comment = format!("// FIX: {}", action.new_text);
-            if idx <= lines.len() {
-                lines.insert(idx, comment);
-            } else {
-                lines.push(comment);
-            }
-        }
-    }
-
-    let new_content = lines.join("\n");
-    // Preserve trailing newline if original had one
-    let final_content = if content.ends_with('\n') && !new_content.ends_with('\n') {
-        format!("{}\n", new_content)
-    } else {
-        new_content
-    };
-
-    std::fs::write(&path, final_content).map_err(|e| format!("write error: {}", e))
-}
-
 /// Re-compile after applying fixes and return (is_clean, error_count).
 fn recompile_verify(cwd: &std::path::Path, verbose: bool) -> (bool, u32) {
     let db_path = cwd.join(".keel").join("graph.db");
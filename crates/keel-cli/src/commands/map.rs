@@ -55,9 +55,11 @@ pub fn run(
         return 2;
     }
 
-    // Walk all source files
+    // Walk all source files, annotating each with its monorepo package (if any)
+    // so enforcement rules like layering can tell which package a node belongs to.
+    let monorepo_layout = keel_parsers::monorepo::detect_monorepo(&cwd);
     let walker = FileWalker::new(&cwd);
-    let entries = walker.walk();
+    let entries = walker.walk_with_packages(&monorepo_layout);
 
     if verbose {
         eprintln!("keel map: found {} source files", entries.len());
@@ -147,6 +149,7 @@ pub fn run(
             external_endpoints: vec![],
             previous_hashes: vec![],
             module_id: 0,
+            package: entry.package.clone(),
         }));
 
         // Create definition nodes
@@ -192,6 +195,7 @@ pub fn run(
                 external_endpoints: vec![],
                 previous_hashes: vec![],
                 module_id,
+                package: entry.package.clone(),
             }));
 
             // "contains" edge from module to definition
@@ -363,6 +367,28 @@ pub fn run(
     // Re-enable FK enforcement
     let _ = store.set_foreign_keys(true);
 
+    // Best-effort rkyv snapshot for `discover`/`explain` warm starts --
+    // failure here never fails the map itself, same as the batch journal.
+    #[cfg(feature = "rkyv-snapshot")]
+    {
+        let fingerprint = super::snapshot_helpers::tree_fingerprint(&cwd);
+        if let Err(e) = keel_core::graph_snapshot::GraphSnapshot::write(&keel_dir, &store, &fingerprint) {
+            if verbose {
+                eprintln!("keel map: failed to write graph snapshot: {}", e);
+            }
+        }
+
+        // Same warm-start story for the FST symbol index `discover` uses
+        // for "did you mean" suggestions: rebuild it once here instead of
+        // on every subsequent `discover` invocation.
+        let symbol_index = keel_core::symbol_index::SymbolIndex::from_store(&store);
+        if let Err(e) = keel_core::index_cache::IndexCache::write(&keel_dir, &symbol_index, &fingerprint) {
+            if verbose {
+                eprintln!("keel map: failed to write symbol index cache: {}", e);
+            }
+        }
+    }
+
     if verbose {
         eprintln!("keel map: mapped {} files, {} edges", entries.len(), total_edges);
     }
@@ -0,0 +1,56 @@
+use keel_core::jsonpath;
+use keel_core::snapshot::build_graph_snapshot;
+use keel_output::OutputFormatter;
+
+/// Run `keel query <path>` — evaluate a JSONPath expression against a
+/// snapshot of the code graph.
+pub fn run(_formatter: &dyn OutputFormatter, json: bool, path: String) -> i32 {
+    let cwd = match std::env::current_dir() {
+        Ok(p) => p,
+        Err(e) => {
+            eprintln!("keel query: failed to get current directory: {}", e);
+            return 2;
+        }
+    };
+
+    let keel_dir = cwd.join(".keel");
+    if !keel_dir.exists() {
+        eprintln!("keel query: not initialized. Run `keel init` first.");
+        return 2;
+    }
+
+    let db_path = keel_dir.join("graph.db");
+    let store = match keel_core::sqlite::SqliteGraphStore::open(db_path.to_str().unwrap_or("")) {
+        Ok(s) => s,
+        Err(e) => {
+            eprintln!("keel query: failed to open graph database: {}", e);
+            return 2;
+        }
+    };
+
+    let snapshot = build_graph_snapshot(&store);
+    let results = match jsonpath::evaluate(&snapshot, &path) {
+        Ok(r) => r,
+        Err(e) => {
+            eprintln!("keel query: invalid JSONPath expression: {}", e);
+            return 2;
+        }
+    };
+
+    if json {
+        println!(
+            "{}",
+            serde_json::json!({
+                "version": env!("CARGO_PKG_VERSION"), "command": "query",
+                "path": path, "count": results.len(), "results": results,
+            })
+        );
+    } else {
+        println!("Query '{}' ({} matches):", path, results.len());
+        for result in &results {
+            println!("  {}", result);
+        }
+    }
+
+    0
+}
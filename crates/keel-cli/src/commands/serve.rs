@@ -150,7 +150,9 @@ pub fn run(
             if verbose {
                 eprintln!("keel serve: HTTP on http://127.0.0.1:{}", port);
             }
-            if let Err(e) = keel_server::http::serve(server.engine, port).await {
+            let keel_dir = root_dir.join(".keel");
+            let config = keel_core::config::KeelConfig::load(&keel_dir);
+            if let Err(e) = keel_server::http::serve(server.engine, port, config.auth).await {
                 eprintln!("keel serve: HTTP error: {}", e);
                 return 2;
             }
@@ -0,0 +1,68 @@
+//! Shared helper for opening the fastest graph store available: an rkyv
+//! snapshot (`keel_core::graph_snapshot::GraphSnapshot`, feature
+//! `rkyv-snapshot`) if one exists and is still fresh, otherwise the SQLite
+//! database it was built from. `map` writes the snapshot; `discover` and
+//! `explain` are read-only, so they're the ones that benefit from skipping
+//! the SQLite reload.
+
+use std::path::Path;
+
+use keel_core::store::GraphStore;
+
+/// Cheap fingerprint of the source tree: hashes `(path, size, mtime)` of
+/// every file [`keel_parsers::walker::FileWalker`] would parse, without
+/// reading or parsing file contents. `keel map` stamps a freshly written
+/// snapshot with this value; reopening it later only succeeds if nothing
+/// the walker would see has changed size or mtime since.
+pub fn tree_fingerprint(cwd: &Path) -> String {
+    let mut entries: Vec<(String, u64, u64)> = keel_parsers::walker::FileWalker::new(cwd)
+        .walk()
+        .into_iter()
+        .filter_map(|entry| {
+            let meta = std::fs::metadata(&entry.path).ok()?;
+            let mtime = meta
+                .modified()
+                .ok()?
+                .duration_since(std::time::UNIX_EPOCH)
+                .ok()?
+                .as_secs();
+            Some((entry.path.to_string_lossy().into_owned(), meta.len(), mtime))
+        })
+        .collect();
+    entries.sort();
+
+    let mut input = String::new();
+    for (path, len, mtime) in &entries {
+        input.push_str(path);
+        input.push('\0');
+        input.push_str(&len.to_string());
+        input.push('\0');
+        input.push_str(&mtime.to_string());
+        input.push('\n');
+    }
+    format!("{:016x}", xxhash_rust::xxh64::xxh64(input.as_bytes(), 0))
+}
+
+/// Open `.keel/graph.snapshot` if it's present and matches the current
+/// tree fingerprint (feature `rkyv-snapshot` only); otherwise open
+/// `.keel/graph.db` via SQLite, same as every command did before this
+/// existed.
+#[allow(unused_variables)]
+pub fn open_fastest_store(
+    cwd: &Path,
+    keel_dir: &Path,
+) -> Result<Box<dyn GraphStore + Send>, String> {
+    #[cfg(feature = "rkyv-snapshot")]
+    {
+        let fingerprint = tree_fingerprint(cwd);
+        if let Some(snapshot) = keel_core::graph_snapshot::GraphSnapshot::open(keel_dir, &fingerprint)
+        {
+            return Ok(Box::new(snapshot));
+        }
+    }
+
+    let db_path = keel_dir.join("graph.db");
+    let store = keel_core::sqlite::SqliteGraphStore::open(db_path.to_str().unwrap_or(""))
+        .map_err(|e| e.to_string())?;
+    Ok(Box::new(store))
+}
@@ -156,6 +156,23 @@ pub fn run(_formatter: &dyn OutputFormatter, verbose: bool, json: bool) -> i32 {
                     .collect();
                 println!("    languages:    {}", lang_str.join(", "));
             }
+
+            if !agg.latency_regressions.is_empty() {
+                println!("    regressions:");
+                for r in &agg.latency_regressions {
+                    println!(
+                        "      {}: {:.0}ms -> {:.0}ms (z={:.1})",
+                        r.command, r.baseline_ms, r.recent_ms, r.z_score
+                    );
+                }
+            }
+
+            if !agg.error_clusters.is_empty() {
+                println!("    error clusters:");
+                for c in &agg.error_clusters {
+                    println!("      [{}] (support={})", c.codes.join(", "), c.support);
+                }
+            }
         }
     }
 
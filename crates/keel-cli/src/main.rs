@@ -5,8 +5,9 @@
 
 use std::time::Instant;
 
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 
+mod alias;
 mod auth;
 mod cli_args;
 mod commands;
@@ -14,8 +15,55 @@ mod telemetry_recorder;
 
 use cli_args::{Cli, Commands};
 
+/// Within what edit distance an unrecognized subcommand still earns a
+/// "did you mean" hint.
+const SUBCOMMAND_SUGGESTION_MAX_DISTANCE: usize = 3;
+
+/// On `clap::error::ErrorKind::InvalidSubcommand`, find the closest real
+/// subcommand name to what the user typed; if one is within
+/// `SUBCOMMAND_SUGGESTION_MAX_DISTANCE`, print an "unrecognized subcommand"
+/// line plus a "did you mean" hint and exit with status 2. Otherwise exit
+/// with clap's own error (and its own exit code).
+fn suggest_subcommand_and_exit(err: clap::Error, attempted: &str) -> ! {
+    if err.kind() == clap::error::ErrorKind::InvalidSubcommand {
+        let closest = Cli::command()
+            .get_subcommands()
+            .filter_map(|sub| {
+                let name = sub.get_name();
+                keel_core::levenshtein::distance_within(attempted, name, SUBCOMMAND_SUGGESTION_MAX_DISTANCE)
+                    .map(|dist| (dist, name.to_string()))
+            })
+            .min_by_key(|(dist, _)| *dist);
+        if let Some((_, name)) = closest {
+            eprintln!("error: unrecognized subcommand '{attempted}'");
+            eprintln!("  hint: did you mean `{name}`?");
+            std::process::exit(2);
+        }
+    }
+    err.exit();
+}
+
 fn main() {
-    let cli = Cli::parse();
+    let known_commands: Vec<String> = Cli::command()
+        .get_subcommands()
+        .map(|sub| sub.get_name().to_string())
+        .collect();
+    let alias_config = std::env::current_dir()
+        .map(|cwd| keel_core::config::KeelConfig::resolve(&cwd.join(".keel"), None))
+        .unwrap_or_default();
+    let argv = alias::expand(
+        &std::env::args().collect::<Vec<_>>(),
+        &known_commands,
+        &alias_config.alias,
+    );
+
+    let cli = match Cli::try_parse_from(&argv) {
+        Ok(cli) => cli,
+        Err(err) => {
+            let attempted = argv.get(1).cloned().unwrap_or_default();
+            suggest_subcommand_and_exit(err, &attempted);
+        }
+    };
 
     // Extract depth values before creating formatter (needed for LLM depth-awareness)
     let (map_depth, compile_depth) = match &cli.command {
@@ -80,6 +128,8 @@ fn main() {
             files,
             batch_start,
             batch_end,
+            batch_status,
+            batch_format,
             strict,
             tier3,
             suppress,
@@ -88,6 +138,12 @@ fn main() {
             since,
             delta,
             timeout,
+            watch,
+            force,
+            jobs,
+            shuffle,
+            coverage,
+            coverage_format,
         } => {
             // tier3 flag is accepted but not yet wired into compile
             let _ = tier3;
@@ -97,6 +153,8 @@ fn main() {
                 files,
                 batch_start,
                 batch_end,
+                batch_status,
+                batch_format,
                 strict,
                 suppress,
                 depth,
@@ -104,6 +162,12 @@ fn main() {
                 since,
                 delta,
                 timeout,
+                watch,
+                force,
+                jobs,
+                shuffle,
+                coverage,
+                coverage_format,
             )
         }
         Commands::Check { query, name } => {
@@ -138,6 +202,7 @@ fn main() {
         Commands::Watch => (commands::watch::run(cli.verbose), Default::default()),
         Commands::Deinit => (commands::deinit::run(&*formatter, cli.verbose), Default::default()),
         Commands::Stats => (commands::stats::run(&*formatter, cli.verbose, cli.json), Default::default()),
+        Commands::Query { path } => (commands::query::run(&*formatter, cli.json, path), Default::default()),
         Commands::Config { key, value } => {
             (commands::config::run(&*formatter, cli.verbose, key, value), Default::default())
         }
@@ -154,7 +219,7 @@ fn main() {
     if let Ok(cwd) = std::env::current_dir() {
         let keel_dir = cwd.join(".keel");
         if keel_dir.exists() {
-            let config = keel_core::config::KeelConfig::load(&keel_dir);
+            let config = keel_core::config::KeelConfig::resolve(&keel_dir, cli.config.as_deref());
             let mut metrics = metrics;
             metrics.client_name = client_name;
             telemetry_recorder::record_event(
@@ -0,0 +1,201 @@
+//! Startup-selectable [`GraphStore`] backend.
+//!
+//! `keel-server` and `keel-cli` need to pick a concrete store without
+//! hardcoding SQLite: [`GraphStoreBackend::open`] inspects the connection
+//! string and returns whichever variant applies, and the `impl GraphStore`
+//! below just matches and delegates. This is the same shape as the
+//! `tier3` feature split in `keel_parsers` -- one enum/cfg boundary, no
+//! change to callers that only know about `dyn GraphStore`.
+
+use std::collections::HashMap;
+
+use crate::sqlite::SqliteGraphStore;
+use crate::store::GraphStore;
+use crate::types::{
+    EdgeChange, EdgeDirection, GraphEdge, GraphError, GraphNode, ModuleProfile, NodeChange,
+};
+
+/// A `GraphStore` implementation selected at startup based on the
+/// configured connection string.
+pub enum GraphStoreBackend {
+    Sqlite(SqliteGraphStore),
+    #[cfg(feature = "postgres")]
+    Postgres(crate::postgres::PostgresGraphStore),
+}
+
+impl GraphStoreBackend {
+    /// Open a backend for `url`. `postgres://` and `postgresql://` select
+    /// the Postgres backend (requires the `postgres` feature); anything
+    /// else is treated as a SQLite file path.
+    pub fn open(url: &str) -> Result<Self, GraphError> {
+        if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+            #[cfg(feature = "postgres")]
+            {
+                return Ok(GraphStoreBackend::Postgres(
+                    crate::postgres::PostgresGraphStore::open(url)?,
+                ));
+            }
+            #[cfg(not(feature = "postgres"))]
+            {
+                return Err(GraphError::Internal(format!(
+                    "Postgres backend requested ({url}) but keel-core was built without the \
+                     \"postgres\" feature"
+                )));
+            }
+        }
+        Ok(GraphStoreBackend::Sqlite(SqliteGraphStore::open(url)?))
+    }
+}
+
+impl GraphStore for GraphStoreBackend {
+    fn get_node(&self, hash: &str) -> Option<GraphNode> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.get_node(hash),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.get_node(hash),
+        }
+    }
+
+    fn get_node_by_id(&self, id: u64) -> Option<GraphNode> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.get_node_by_id(id),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.get_node_by_id(id),
+        }
+    }
+
+    fn get_edges(&self, node_id: u64, direction: EdgeDirection) -> Vec<GraphEdge> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.get_edges(node_id, direction),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.get_edges(node_id, direction),
+        }
+    }
+
+    fn get_module_profile(&self, module_id: u64) -> Option<ModuleProfile> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.get_module_profile(module_id),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.get_module_profile(module_id),
+        }
+    }
+
+    fn get_nodes_in_file(&self, file_path: &str) -> Vec<GraphNode> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.get_nodes_in_file(file_path),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.get_nodes_in_file(file_path),
+        }
+    }
+
+    fn get_all_modules(&self) -> Vec<GraphNode> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.get_all_modules(),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.get_all_modules(),
+        }
+    }
+
+    fn update_nodes(&mut self, changes: Vec<NodeChange>) -> Result<(), GraphError> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.update_nodes(changes),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.update_nodes(changes),
+        }
+    }
+
+    fn update_edges(&mut self, changes: Vec<EdgeChange>) -> Result<(), GraphError> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.update_edges(changes),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.update_edges(changes),
+        }
+    }
+
+    fn get_previous_hashes(&self, node_id: u64) -> Vec<String> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.get_previous_hashes(node_id),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.get_previous_hashes(node_id),
+        }
+    }
+
+    fn find_modules_by_prefix(&self, prefix: &str, exclude_file: &str) -> Vec<ModuleProfile> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.find_modules_by_prefix(prefix, exclude_file),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.find_modules_by_prefix(prefix, exclude_file),
+        }
+    }
+
+    fn find_nodes_by_name(&self, name: &str, kind: &str, exclude_file: &str) -> Vec<GraphNode> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.find_nodes_by_name(name, kind, exclude_file),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.find_nodes_by_name(name, kind, exclude_file),
+        }
+    }
+
+    fn nodes_with_relations_batch(&self, hashes: &[String]) -> HashMap<String, GraphNode> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.nodes_with_relations_batch(hashes),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.nodes_with_relations_batch(hashes),
+        }
+    }
+
+    fn nodes_by_ids_batch(&self, ids: &[u64]) -> HashMap<u64, GraphNode> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.nodes_by_ids_batch(ids),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.nodes_by_ids_batch(ids),
+        }
+    }
+
+    fn edges_batch(
+        &self,
+        node_ids: &[u64],
+        direction: EdgeDirection,
+    ) -> HashMap<u64, Vec<GraphEdge>> {
+        match self {
+            GraphStoreBackend::Sqlite(s) => s.edges_batch(node_ids, direction),
+            #[cfg(feature = "postgres")]
+            GraphStoreBackend::Postgres(s) => s.edges_batch(node_ids, direction),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_sqlite_path_selects_sqlite_variant() {
+        let backend = GraphStoreBackend::open(":memory:").unwrap();
+        assert!(matches!(backend, GraphStoreBackend::Sqlite(_)));
+    }
+
+    #[test]
+    fn test_open_file_path_selects_sqlite_variant() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("graph.db");
+        let backend = GraphStoreBackend::open(&path.to_string_lossy()).unwrap();
+        assert!(matches!(backend, GraphStoreBackend::Sqlite(_)));
+    }
+
+    #[cfg(not(feature = "postgres"))]
+    #[test]
+    fn test_open_postgres_url_errors_without_postgres_feature() {
+        let err = GraphStoreBackend::open("postgres://localhost/keel").unwrap_err();
+        assert!(matches!(err, GraphError::Internal(_)));
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn test_open_postgresql_url_selects_postgres_variant() {
+        // Opening a connection genuinely requires a live Postgres instance,
+        // so this only checks dispatch, not a successful connection.
+        let result = GraphStoreBackend::open("postgresql://localhost/does-not-exist");
+        assert!(result.is_err() || matches!(result, Ok(GraphStoreBackend::Postgres(_))));
+    }
+}
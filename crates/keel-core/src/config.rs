@@ -3,6 +3,7 @@
 //! Reads `.keel/keel.json` and provides typed access to all settings.
 //! Falls back to sensible defaults when the config file is missing or incomplete.
 
+use std::collections::HashMap;
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
@@ -30,6 +31,13 @@ pub struct KeelConfig {
     pub monorepo: MonorepoConfig,
     #[serde(default)]
     pub tier3: Tier3Config,
+    #[serde(default)]
+    pub auth: AuthConfig,
+    /// User-defined subcommand aliases, e.g. `"m": "map --tier3"`. Expanded
+    /// by `keel-cli`'s `main.rs` before clap ever sees argv -- this crate
+    /// just carries the map.
+    #[serde(default)]
+    pub alias: HashMap<String, String>,
 }
 
 /// Product tier — gates feature access.
@@ -90,6 +98,27 @@ pub struct MonorepoConfig {
     pub kind: Option<String>,
     #[serde(default)]
     pub packages: Vec<String>,
+    /// Architectural layering rules, keyed by package name. Checked by
+    /// E006 (`layer_violation`) during `keel compile`: a cross-package
+    /// call/import from package `p` is flagged unless `p`'s rule allows it.
+    #[serde(default)]
+    pub layers: HashMap<String, LayerRule>,
+}
+
+/// Which packages a single package may depend on.
+///
+/// An empty `allow` means "no allowlist restriction" -- only `deny` is
+/// checked. A non-empty `allow` makes the rule exhaustive: any package not
+/// listed (and not already excluded by `deny`) is treated as disallowed.
+/// `deny` always wins over `allow` so a blanket allowlist can still carve
+/// out specific forbidden edges (e.g. "core" must never import "cli" even
+/// if something careless adds it to `allow` later).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct LayerRule {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
 }
 
 /// Tier 3 (LSP/SCIP) resolution configuration.
@@ -116,6 +145,37 @@ impl Default for Tier3Config {
     }
 }
 
+/// Bearer-token authentication for `keel serve --http`.
+///
+/// Empty (the default) means auth is disabled — every request is allowed,
+/// matching today's "bind to localhost, trust the caller" behavior. Once
+/// any key is configured, every route except `/health` requires a matching
+/// `Authorization: Bearer <key>` header.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AuthConfig {
+    #[serde(default)]
+    pub keys: Vec<ApiKeyConfig>,
+}
+
+/// A single API key and the scope of routes it may call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApiKeyConfig {
+    pub key: String,
+    #[serde(default)]
+    pub scope: KeyScope,
+}
+
+/// What a key is allowed to call.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyScope {
+    /// `/discover`, `/where`, `/explain`, `/query`.
+    #[default]
+    Read,
+    /// Everything `Read` can call, plus `/compile` and `/compile/stream`.
+    Write,
+}
+
 /// Enforcement severity toggles.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct EnforceConfig {
@@ -191,6 +251,8 @@ impl Default for KeelConfig {
             naming_conventions: NamingConventionsConfig::default(),
             monorepo: MonorepoConfig::default(),
             tier3: Tier3Config::default(),
+            auth: AuthConfig::default(),
+            alias: HashMap::new(),
         }
     }
 }
@@ -216,6 +278,175 @@ impl KeelConfig {
             }
         }
     }
+
+    /// Resolve configuration from every layer, in increasing precedence:
+    /// built-in defaults < global `~/.config/keel/keel.json` < project
+    /// `.keel/keel.json` < `KEEL_*` environment variables < an inline
+    /// `--config` argument (see [`parse_config_arg`]). Layers are deep-merged
+    /// (objects merge key-by-key; scalars and arrays from the higher layer
+    /// win), so unknown/custom keys from any layer survive into the result.
+    pub fn resolve(keel_dir: &Path, inline_config: Option<&str>) -> Self {
+        let mut merged = serde_json::to_value(Self::default()).unwrap_or(serde_json::Value::Null);
+
+        if let Some(global) = global_config_value() {
+            merged = deep_merge(&merged, &global);
+        }
+        if let Some(project) = project_config_value(keel_dir) {
+            merged = deep_merge(&merged, &project);
+        }
+        let env_overlay = env_overlay();
+        if !matches!(&env_overlay, serde_json::Value::Object(m) if m.is_empty()) {
+            merged = deep_merge(&merged, &env_overlay);
+        }
+        if let Some(raw) = inline_config {
+            match parse_config_arg(raw) {
+                Ok(overlay) => merged = deep_merge(&merged, &overlay),
+                Err(e) => eprintln!("keel: warning: failed to parse --config: {e}, ignoring"),
+            }
+        }
+
+        serde_json::from_value(merged).unwrap_or_else(|e| {
+            eprintln!("keel: warning: failed to apply resolved config: {e}, using defaults");
+            Self::default()
+        })
+    }
+}
+
+/// Deep-merge two JSON values for config layering: objects merge recursively
+/// key-by-key; everything else (scalars, arrays) from `overlay` replaces
+/// whatever `base` had, so a higher layer can fully override a lower one's
+/// array or scalar without needing to repeat every other field.
+fn deep_merge(base: &serde_json::Value, overlay: &serde_json::Value) -> serde_json::Value {
+    match (base, overlay) {
+        (serde_json::Value::Object(base_map), serde_json::Value::Object(overlay_map)) => {
+            let mut merged = base_map.clone();
+            for (key, overlay_val) in overlay_map {
+                let merged_val = match merged.get(key) {
+                    Some(base_val) => deep_merge(base_val, overlay_val),
+                    None => overlay_val.clone(),
+                };
+                merged.insert(key.clone(), merged_val);
+            }
+            serde_json::Value::Object(merged)
+        }
+        (_, overlay_val) => overlay_val.clone(),
+    }
+}
+
+/// Read the global `~/.config/keel/keel.json`, if `$HOME` is set and the
+/// file exists and parses. Absent or invalid just means "no global layer".
+fn global_config_value() -> Option<serde_json::Value> {
+    let home = std::env::var_os("HOME")?;
+    let path = Path::new(&home)
+        .join(".config")
+        .join("keel")
+        .join("keel.json");
+    let content = std::fs::read_to_string(path).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Read the project's `.keel/keel.json`, if it exists and parses.
+fn project_config_value(keel_dir: &Path) -> Option<serde_json::Value> {
+    let content = std::fs::read_to_string(keel_dir.join("keel.json")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// `KEEL_*` environment variables recognized as config overrides, mapped to
+/// the dotted path they set. Env var names can't be split into nested path
+/// segments automatically (field names like `type_hints` already contain
+/// underscores), so this is an explicit allow-list rather than a generic
+/// `KEEL_SECTION_FIELD` -> `section.field` transform.
+const ENV_CONFIG_PATHS: &[(&str, &str)] = &[
+    ("KEEL_ENFORCE_TYPE_HINTS", "enforce.type_hints"),
+    ("KEEL_ENFORCE_DOCSTRINGS", "enforce.docstrings"),
+    ("KEEL_ENFORCE_PLACEMENT", "enforce.placement"),
+    (
+        "KEEL_CIRCUIT_BREAKER_MAX_FAILURES",
+        "circuit_breaker.max_failures",
+    ),
+    ("KEEL_BATCH_TIMEOUT_SECONDS", "batch.timeout_seconds"),
+    ("KEEL_TIER", "tier"),
+    ("KEEL_TELEMETRY_ENABLED", "telemetry.enabled"),
+    ("KEEL_TELEMETRY_REMOTE", "telemetry.remote"),
+    ("KEEL_TELEMETRY_ENDPOINT", "telemetry.endpoint"),
+];
+
+/// Build a config overlay from recognized `KEEL_*` environment variables.
+fn env_overlay() -> serde_json::Value {
+    let mut root = serde_json::Map::new();
+    for (var, dotted_path) in ENV_CONFIG_PATHS {
+        if let Ok(value) = std::env::var(var) {
+            nested_set(&mut root, dotted_path, parse_scalar(&value));
+        }
+    }
+    serde_json::Value::Object(root)
+}
+
+/// Parse a raw scalar as JSON (so `"false"`/`"42"` become their natural
+/// types) and fall back to a plain JSON string when that fails.
+fn parse_scalar(raw: &str) -> serde_json::Value {
+    serde_json::from_str(raw).unwrap_or_else(|_| serde_json::Value::String(raw.to_string()))
+}
+
+/// Set a value at a dotted path inside a JSON object map, creating nested
+/// objects along the way as needed (e.g. `"enforce.type_hints"` creates or
+/// extends an `enforce` object with a `type_hints` key).
+fn nested_set(
+    map: &mut serde_json::Map<String, serde_json::Value>,
+    dotted_path: &str,
+    value: serde_json::Value,
+) {
+    match dotted_path.split_once('.') {
+        Some((head, rest)) => {
+            let entry = map
+                .entry(head.to_string())
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+            if !entry.is_object() {
+                *entry = serde_json::Value::Object(serde_json::Map::new());
+            }
+            if let serde_json::Value::Object(nested) = entry {
+                nested_set(nested, rest, value);
+            }
+        }
+        None => {
+            map.insert(dotted_path.to_string(), value);
+        }
+    }
+}
+
+/// Parse a `--config` CLI argument. Accepts three forms, tried in order:
+/// 1. A literal JSON object (starts with `{`).
+/// 2. A path to a JSON file on disk.
+/// 3. Comma-separated `key.path=value` pairs, expanded into nested objects
+///    via [`nested_set`] (e.g. `"enforce.type_hints=false,tier=team"`).
+pub fn parse_config_arg(raw: &str) -> Result<serde_json::Value, String> {
+    let trimmed = raw.trim();
+    if trimmed.starts_with('{') {
+        return serde_json::from_str(trimmed).map_err(|e| format!("invalid inline JSON: {e}"));
+    }
+    let path = Path::new(trimmed);
+    if path.is_file() {
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        return serde_json::from_str(&content)
+            .map_err(|e| format!("invalid JSON in {}: {}", path.display(), e));
+    }
+
+    let mut map = serde_json::Map::new();
+    for pair in trimmed.split(',') {
+        let pair = pair.trim();
+        if pair.is_empty() {
+            continue;
+        }
+        let (key, value) = pair
+            .split_once('=')
+            .ok_or_else(|| format!("expected key.path=value, got: {pair}"))?;
+        nested_set(&mut map, key.trim(), parse_scalar(value.trim()));
+    }
+    if map.is_empty() {
+        return Err(format!("empty or unrecognized --config value: {raw}"));
+    }
+    Ok(serde_json::Value::Object(map))
 }
 
 #[cfg(test)]
@@ -275,6 +506,24 @@ mod tests {
                 enabled: true,
                 kind: Some("CargoWorkspace".to_string()),
                 packages: vec!["core".to_string(), "cli".to_string()],
+                layers: {
+                    let mut m = std::collections::HashMap::new();
+                    m.insert(
+                        "cli".to_string(),
+                        LayerRule {
+                            allow: vec!["core".to_string()],
+                            deny: vec![],
+                        },
+                    );
+                    m.insert(
+                        "core".to_string(),
+                        LayerRule {
+                            allow: vec![],
+                            deny: vec!["cli".to_string()],
+                        },
+                    );
+                    m
+                },
             },
             tier3: Tier3Config {
                 enabled: true,
@@ -293,6 +542,24 @@ mod tests {
                 },
                 prefer_scip: false,
             },
+            auth: AuthConfig {
+                keys: vec![
+                    ApiKeyConfig {
+                        key: "ci-readonly-key".to_string(),
+                        scope: KeyScope::Read,
+                    },
+                    ApiKeyConfig {
+                        key: "ci-write-key".to_string(),
+                        scope: KeyScope::Write,
+                    },
+                ],
+            },
+            alias: {
+                let mut m = std::collections::HashMap::new();
+                m.insert("m".to_string(), "map --tier3".to_string());
+                m.insert("d".to_string(), "discover".to_string());
+                m
+            },
         };
 
         // Serialize to JSON
@@ -346,6 +613,14 @@ mod tests {
             Some("CargoWorkspace".to_string())
         );
         assert_eq!(roundtripped.monorepo.packages, vec!["core", "cli"]);
+        assert_eq!(
+            roundtripped.monorepo.layers.get("cli").unwrap().allow,
+            vec!["core"]
+        );
+        assert_eq!(
+            roundtripped.monorepo.layers.get("core").unwrap().deny,
+            vec!["cli"]
+        );
         assert!(roundtripped.tier3.enabled);
         assert_eq!(
             roundtripped.tier3.scip_paths.get("typescript").unwrap(),
@@ -356,6 +631,11 @@ mod tests {
             &vec!["pyright-langserver", "--stdio"]
         );
         assert!(!roundtripped.tier3.prefer_scip);
+        assert_eq!(roundtripped.auth.keys.len(), 2);
+        assert_eq!(roundtripped.auth.keys[0].scope, KeyScope::Read);
+        assert_eq!(roundtripped.auth.keys[1].scope, KeyScope::Write);
+        assert_eq!(roundtripped.alias.get("m").unwrap(), "map --tier3");
+        assert_eq!(roundtripped.alias.get("d").unwrap(), "discover");
     }
 
     #[test]
@@ -445,6 +725,132 @@ mod tests {
         assert!(cfg.tier3.scip_paths.is_empty());
         assert!(cfg.tier3.lsp_commands.is_empty());
         assert!(cfg.tier3.prefer_scip);
+        assert!(cfg.auth.keys.is_empty());
+    }
+
+    #[test]
+    fn test_key_scope_roundtrip() {
+        for (scope, expected_json) in [(KeyScope::Read, "\"read\""), (KeyScope::Write, "\"write\"")]
+        {
+            let json = serde_json::to_string(&scope).unwrap();
+            assert_eq!(json, expected_json);
+            let parsed: KeyScope = serde_json::from_str(&json).unwrap();
+            assert_eq!(parsed, scope);
+        }
+    }
+
+    #[test]
+    fn test_auth_config_defaults_to_no_keys() {
+        let cfg = AuthConfig::default();
+        assert!(cfg.keys.is_empty());
+    }
+
+    #[test]
+    fn test_deep_merge_preserves_unknown_keys() {
+        let base = serde_json::json!({"version": "0.1.0", "custom_key": "keep me"});
+        let overlay = serde_json::json!({"version": "0.2.0"});
+        let merged = deep_merge(&base, &overlay);
+        assert_eq!(merged["version"], serde_json::json!("0.2.0"));
+        assert_eq!(merged["custom_key"], serde_json::json!("keep me"));
+    }
+
+    #[test]
+    fn test_deep_merge_nested_objects_merge_by_key() {
+        let base = serde_json::json!({"enforce": {"type_hints": true, "docstrings": true}});
+        let overlay = serde_json::json!({"enforce": {"type_hints": false}});
+        let merged = deep_merge(&base, &overlay);
+        assert_eq!(merged["enforce"]["type_hints"], serde_json::json!(false));
+        assert_eq!(merged["enforce"]["docstrings"], serde_json::json!(true));
+    }
+
+    #[test]
+    fn test_deep_merge_scalars_and_arrays_replace() {
+        let base = serde_json::json!({"tier": "free", "ignore_patterns": ["a", "b"]});
+        let overlay = serde_json::json!({"tier": "team", "ignore_patterns": ["c"]});
+        let merged = deep_merge(&base, &overlay);
+        assert_eq!(merged["tier"], serde_json::json!("team"));
+        assert_eq!(merged["ignore_patterns"], serde_json::json!(["c"]));
+    }
+
+    #[test]
+    fn test_nested_set_creates_nested_objects() {
+        let mut map = serde_json::Map::new();
+        nested_set(&mut map, "enforce.type_hints", serde_json::json!(false));
+        assert_eq!(
+            serde_json::Value::Object(map),
+            serde_json::json!({"enforce": {"type_hints": false}})
+        );
+    }
+
+    #[test]
+    fn test_nested_set_extends_existing_object() {
+        let mut map = serde_json::Map::new();
+        nested_set(&mut map, "enforce.type_hints", serde_json::json!(false));
+        nested_set(&mut map, "enforce.docstrings", serde_json::json!(false));
+        assert_eq!(
+            serde_json::Value::Object(map),
+            serde_json::json!({"enforce": {"type_hints": false, "docstrings": false}})
+        );
+    }
+
+    #[test]
+    fn test_parse_config_arg_inline_json() {
+        let value = parse_config_arg(r#"{"tier": "enterprise"}"#).unwrap();
+        assert_eq!(value, serde_json::json!({"tier": "enterprise"}));
+    }
+
+    #[test]
+    fn test_parse_config_arg_key_value_pairs() {
+        let value = parse_config_arg("enforce.type_hints=false,tier=team").unwrap();
+        assert_eq!(
+            value,
+            serde_json::json!({"enforce": {"type_hints": false}, "tier": "team"})
+        );
+    }
+
+    #[test]
+    fn test_parse_config_arg_file_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("override.json");
+        fs::write(&path, r#"{"version": "9.9.9"}"#).unwrap();
+        let value = parse_config_arg(path.to_str().unwrap()).unwrap();
+        assert_eq!(value, serde_json::json!({"version": "9.9.9"}));
+    }
+
+    #[test]
+    fn test_parse_config_arg_invalid_falls_back_to_error() {
+        assert!(parse_config_arg("not a valid config").is_err());
+    }
+
+    #[test]
+    fn test_resolve_layers_project_env_and_inline() {
+        let dir = tempfile::tempdir().unwrap();
+        let keel_dir = dir.path().join(".keel");
+        fs::create_dir_all(&keel_dir).unwrap();
+        fs::write(
+            keel_dir.join("keel.json"),
+            serde_json::json!({
+                "version": "0.1.0",
+                "languages": ["python"],
+                "tier": "team",
+            })
+            .to_string(),
+        )
+        .unwrap();
+
+        // SAFETY: test-only, single-threaded w.r.t. this env var within the test.
+        std::env::set_var("KEEL_ENFORCE_TYPE_HINTS", "false");
+        let resolved = KeelConfig::resolve(&keel_dir, Some("tier=enterprise"));
+        std::env::remove_var("KEEL_ENFORCE_TYPE_HINTS");
+
+        // Project layer applied over defaults.
+        assert_eq!(resolved.languages, vec!["python"]);
+        // Env layer applied over project.
+        assert!(!resolved.enforce.type_hints);
+        // Inline --config wins over everything, including the project's tier.
+        assert_eq!(resolved.tier, Tier::Enterprise);
+        // Fields untouched by any layer keep their built-in defaults.
+        assert_eq!(resolved.batch.timeout_seconds, 60);
     }
 
     #[test]
@@ -0,0 +1,323 @@
+//! rkyv-backed archive of the whole graph for zero-copy warm starts.
+//!
+//! `keel map` reloads the entire graph from SQLite on every invocation,
+//! which dominates latency once a repo has tens of thousands of nodes.
+//! [`GraphSnapshot::write`] serializes the graph (nodes, edges, and the
+//! [`ModuleProfile`] table) into an rkyv archive next to `graph.db`;
+//! [`GraphSnapshot::open`] `mmap`s that archive and validates it with
+//! rkyv's `bytecheck` before trusting the bytes, so a corrupt or
+//! truncated file can never cause UB. [`GraphSnapshot`] itself implements
+//! [`GraphStore`], so `discover`/`explain` can drop it into
+//! `Box<dyn GraphStore + Send>` exactly where they'd otherwise box a
+//! [`crate::sqlite::SqliteGraphStore`] -- callers only need to try
+//! `GraphSnapshot::open` first and fall back to SQLite on `None`.
+//!
+//! Staleness is the caller's job: `open` takes a `content_hash` computed
+//! over the current source tree and refuses the archive (returning
+//! `None`, same as a missing or corrupt file) unless it matches the hash
+//! stamped into the header at `write` time. This crate has no opinion on
+//! how that hash is computed -- `keel-cli` hashes file paths/sizes/mtimes
+//! from the same walk `keel map` already does.
+//!
+//! Gated behind the `rkyv-snapshot` feature, off by default, the same way
+//! `postgres` gates [`crate::postgres`].
+
+use std::fs::File;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use memmap2::Mmap;
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+use crate::store::GraphStore;
+use crate::types::{
+    ArchivedNodeKind, EdgeChange, EdgeDirection, GraphEdge, GraphError, GraphNode, ModuleProfile,
+    NodeChange,
+};
+
+/// Bumped whenever [`SnapshotGraph`]'s archived layout changes. A mismatch
+/// between this and the header means the running binary can't trust the
+/// archive and must fall back to SQLite.
+const SNAPSHOT_FORMAT_VERSION: u32 = 1;
+
+const SNAPSHOT_MAGIC: &[u8; 4] = b"KGS1";
+
+/// `4` magic bytes + `4` format-version bytes + `4` content-hash length
+/// bytes, all little-endian.
+const HEADER_PREFIX_LEN: usize = 12;
+
+/// The whole graph, archived in one shot.
+#[derive(Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+struct SnapshotGraph {
+    nodes: Vec<GraphNode>,
+    edges: Vec<GraphEdge>,
+    module_profiles: Vec<ModuleProfile>,
+}
+
+/// An rkyv archive of the graph, mmap'd read-only.
+///
+/// Implements [`GraphStore`] directly over the archived bytes: reads
+/// deserialize just the matched node/edge (never the whole archive), and
+/// mutating methods return [`GraphError::Internal`] since a snapshot is
+/// never written to -- `keel map` regenerates it wholesale instead.
+pub struct GraphSnapshot {
+    mmap: Mmap,
+    payload_offset: usize,
+}
+
+impl GraphSnapshot {
+    /// Path the archive lives at, next to `graph.db` in `.keel/`.
+    pub fn path(keel_dir: &Path) -> PathBuf {
+        keel_dir.join("graph.snapshot")
+    }
+
+    /// Serialize `store`'s full graph into an rkyv archive at
+    /// `.keel/graph.snapshot`, stamped with `content_hash`. Written to a
+    /// `.tmp` sibling and renamed into place so a reader never observes a
+    /// half-written file.
+    pub fn write(
+        keel_dir: &Path,
+        store: &dyn GraphStore,
+        content_hash: &str,
+    ) -> Result<(), GraphError> {
+        let graph = collect_graph(store);
+        let bytes = rkyv::to_bytes::<_, 4096>(&graph)
+            .map_err(|e| GraphError::Internal(format!("failed to archive graph snapshot: {e}")))?;
+
+        let path = Self::path(keel_dir);
+        let tmp_path = path.with_extension("snapshot.tmp");
+        let mut file = File::create(&tmp_path)
+            .map_err(|e| GraphError::Internal(format!("failed to create {}: {e}", tmp_path.display())))?;
+
+        let hash_bytes = content_hash.as_bytes();
+        file.write_all(SNAPSHOT_MAGIC)
+            .and_then(|_| file.write_all(&SNAPSHOT_FORMAT_VERSION.to_le_bytes()))
+            .and_then(|_| file.write_all(&(hash_bytes.len() as u32).to_le_bytes()))
+            .and_then(|_| file.write_all(hash_bytes))
+            .and_then(|_| file.write_all(&bytes))
+            .map_err(|e| GraphError::Internal(format!("failed to write graph snapshot: {e}")))?;
+        drop(file);
+
+        std::fs::rename(&tmp_path, &path)
+            .map_err(|e| GraphError::Internal(format!("failed to finalize graph snapshot: {e}")))?;
+        Ok(())
+    }
+
+    /// Open and validate the archive at `.keel/graph.snapshot`.
+    ///
+    /// Returns `None` -- never an error -- for anything that should fall
+    /// back to SQLite: the file doesn't exist, its format version or
+    /// content hash doesn't match, or `bytecheck` rejects the bytes as
+    /// corrupt. A mismatched or corrupt snapshot is also deleted so it
+    /// doesn't keep getting tried.
+    pub fn open(keel_dir: &Path, expected_content_hash: &str) -> Option<Self> {
+        let path = Self::path(keel_dir);
+        let file = File::open(&path).ok()?;
+        // SAFETY: the file isn't mutated by this process while mapped, and
+        // the bytes are validated with bytecheck below before any
+        // `Archived<T>` is ever trusted.
+        let mmap = unsafe { Mmap::map(&file) }.ok()?;
+
+        if mmap.len() < HEADER_PREFIX_LEN || &mmap[0..4] != SNAPSHOT_MAGIC.as_slice() {
+            return None;
+        }
+        let version = u32::from_le_bytes(mmap[4..8].try_into().ok()?);
+        if version != SNAPSHOT_FORMAT_VERSION {
+            let _ = std::fs::remove_file(&path);
+            return None;
+        }
+        let hash_len = u32::from_le_bytes(mmap[8..12].try_into().ok()?) as usize;
+        let hash_end = HEADER_PREFIX_LEN.checked_add(hash_len)?;
+        if mmap.len() < hash_end {
+            return None;
+        }
+        let stored_hash = std::str::from_utf8(&mmap[HEADER_PREFIX_LEN..hash_end]).ok()?;
+        if stored_hash != expected_content_hash {
+            let _ = std::fs::remove_file(&path);
+            return None;
+        }
+
+        if rkyv::check_archived_root::<SnapshotGraph>(&mmap[hash_end..]).is_err() {
+            let _ = std::fs::remove_file(&path);
+            return None;
+        }
+
+        Some(GraphSnapshot {
+            mmap,
+            payload_offset: hash_end,
+        })
+    }
+
+    /// The validated archive. Only called after `open`'s `check_archived_root`
+    /// has already succeeded on these exact bytes.
+    fn graph(&self) -> &ArchivedSnapshotGraph {
+        // SAFETY: `open` ran `check_archived_root` over this same byte
+        // range before constructing `Self`.
+        unsafe { rkyv::archived_root::<SnapshotGraph>(&self.mmap[self.payload_offset..]) }
+    }
+}
+
+fn collect_graph(store: &dyn GraphStore) -> SnapshotGraph {
+    let modules = store.get_all_modules();
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut module_profiles = Vec::new();
+    let mut seen_edges = std::collections::HashSet::new();
+
+    for module in &modules {
+        if let Some(profile) = store.get_module_profile(module.id) {
+            module_profiles.push(profile);
+        }
+
+        let mut file_nodes = store.get_nodes_in_file(&module.file_path);
+        if !file_nodes.iter().any(|n| n.id == module.id) {
+            file_nodes.push(module.clone());
+        }
+
+        for node in file_nodes {
+            for edge in store.get_edges(node.id, EdgeDirection::Outgoing) {
+                if seen_edges.insert(edge.id) {
+                    edges.push(edge);
+                }
+            }
+            nodes.push(node);
+        }
+    }
+
+    SnapshotGraph {
+        nodes,
+        edges,
+        module_profiles,
+    }
+}
+
+fn deserialize_node(node: &ArchivedGraphNode) -> GraphNode {
+    node.deserialize(&mut rkyv::Infallible)
+        .expect("infallible deserializer")
+}
+
+fn deserialize_edge(edge: &ArchivedGraphEdge) -> GraphEdge {
+    edge.deserialize(&mut rkyv::Infallible)
+        .expect("infallible deserializer")
+}
+
+fn deserialize_profile(profile: &ArchivedModuleProfile) -> ModuleProfile {
+    profile
+        .deserialize(&mut rkyv::Infallible)
+        .expect("infallible deserializer")
+}
+
+const READ_ONLY: &str =
+    "graph snapshot is read-only; run `keel map` to regenerate it instead of mutating it directly";
+
+impl GraphStore for GraphSnapshot {
+    fn get_node(&self, hash: &str) -> Option<GraphNode> {
+        self.graph()
+            .nodes
+            .iter()
+            .find(|n| n.hash.as_str() == hash)
+            .map(deserialize_node)
+    }
+
+    fn get_node_by_id(&self, id: u64) -> Option<GraphNode> {
+        self.graph()
+            .nodes
+            .iter()
+            .find(|n| n.id == id)
+            .map(deserialize_node)
+    }
+
+    fn get_edges(&self, node_id: u64, direction: EdgeDirection) -> Vec<GraphEdge> {
+        self.graph()
+            .edges
+            .iter()
+            .filter(|e| match direction {
+                EdgeDirection::Incoming => e.target_id == node_id,
+                EdgeDirection::Outgoing => e.source_id == node_id,
+                EdgeDirection::Both => e.source_id == node_id || e.target_id == node_id,
+            })
+            .map(deserialize_edge)
+            .collect()
+    }
+
+    fn get_module_profile(&self, module_id: u64) -> Option<ModuleProfile> {
+        self.graph()
+            .module_profiles
+            .iter()
+            .find(|p| p.module_id == module_id)
+            .map(deserialize_profile)
+    }
+
+    fn get_nodes_in_file(&self, file_path: &str) -> Vec<GraphNode> {
+        self.graph()
+            .nodes
+            .iter()
+            .filter(|n| n.file_path.as_str() == file_path)
+            .map(deserialize_node)
+            .collect()
+    }
+
+    fn get_all_modules(&self) -> Vec<GraphNode> {
+        self.graph()
+            .nodes
+            .iter()
+            .filter(|n| matches!(n.kind, ArchivedNodeKind::Module))
+            .map(deserialize_node)
+            .collect()
+    }
+
+    fn update_nodes(&mut self, _changes: Vec<NodeChange>) -> Result<(), GraphError> {
+        Err(GraphError::Internal(READ_ONLY.to_string()))
+    }
+
+    fn update_edges(&mut self, _changes: Vec<EdgeChange>) -> Result<(), GraphError> {
+        Err(GraphError::Internal(READ_ONLY.to_string()))
+    }
+
+    fn get_previous_hashes(&self, node_id: u64) -> Vec<String> {
+        self.graph()
+            .nodes
+            .iter()
+            .find(|n| n.id == node_id)
+            .map(|n| n.previous_hashes.iter().map(|h| h.as_str().to_string()).collect())
+            .unwrap_or_default()
+    }
+
+    fn find_modules_by_prefix(&self, prefix: &str, exclude_file: &str) -> Vec<ModuleProfile> {
+        self.graph()
+            .module_profiles
+            .iter()
+            .filter(|p| {
+                p.path.as_str() != exclude_file
+                    && p.function_name_prefixes
+                        .iter()
+                        .any(|fp| fp.as_str() == prefix)
+            })
+            .map(deserialize_profile)
+            .collect()
+    }
+
+    fn find_nodes_by_name(&self, name: &str, kind: &str, exclude_file: &str) -> Vec<GraphNode> {
+        self.graph()
+            .nodes
+            .iter()
+            .filter(|n| {
+                n.name.as_str() == name
+                    && n.file_path.as_str() != exclude_file
+                    && (kind.is_empty() || archived_node_kind_str(&n.kind) == kind)
+            })
+            .map(deserialize_node)
+            .collect()
+    }
+}
+
+/// Mirrors [`crate::types::NodeKind::as_str`] for the archived enum, which
+/// the derive macro doesn't generate a method for.
+fn archived_node_kind_str(kind: &ArchivedNodeKind) -> &'static str {
+    match kind {
+        ArchivedNodeKind::Module => "module",
+        ArchivedNodeKind::Class => "class",
+        ArchivedNodeKind::Function => "function",
+    }
+}
@@ -0,0 +1,140 @@
+//! rkyv-backed cache for [`SymbolIndex`], so a fresh `keel` process doesn't
+//! have to rebuild it from a full graph walk.
+//!
+//! Mirrors [`crate::graph_snapshot`]: [`IndexCache::write`] archives the
+//! FST's raw byte layout -- rebuilding a `fst::Map` from those bytes is
+//! O(1), not a re-insert pass -- alongside the name -> ids side table, and
+//! stamps the file with a content hash. [`IndexCache::open`] `mmap`s the
+//! file, validates it with bytecheck, and hands back a [`SymbolIndex`]
+//! without touching the graph store at all: no module walk, no per-file
+//! SQL, no FST re-insert. The side table still goes through one
+//! deserialize pass (it's a flat `Vec`, not worth chasing zero-copy for),
+//! which is still far cheaper than [`SymbolIndex::from_store`] on a large
+//! repo.
+//!
+//! Staleness is the caller's job, same as `graph_snapshot`: `open` takes a
+//! content hash and refuses (returning `None`) unless it matches what
+//! `write` stamped in.
+//!
+//! Gated behind the `rkyv-snapshot` feature, same as `graph_snapshot`.
+
+use std::fs::File;
+use std::io::Write as _;
+use std::path::{Path, PathBuf};
+
+use memmap2::Mmap;
+use rkyv::{Archive, Deserialize as RkyvDeserialize, Serialize as RkyvSerialize};
+
+use crate::symbol_index::{SymbolIndex, SymbolMatch};
+
+/// Bumped whenever [`IndexEntries`]'s archived layout changes.
+const INDEX_FORMAT_VERSION: u32 = 1;
+
+const INDEX_MAGIC: &[u8; 4] = b"KIX1";
+
+/// `4` magic bytes + `4` format-version bytes + `4` content-hash length
+/// bytes, all little-endian.
+const HEADER_PREFIX_LEN: usize = 12;
+
+/// The FST's side table, archived on its own; the FST bytes themselves are
+/// stored raw (they're already a serialized format, no need to wrap them).
+#[derive(Archive, RkyvSerialize, RkyvDeserialize)]
+#[archive(check_bytes)]
+struct IndexEntries {
+    entries: Vec<SymbolMatch>,
+}
+
+/// An rkyv-cached [`SymbolIndex`], persisted at `.keel/index.rkyv`.
+pub struct IndexCache;
+
+impl IndexCache {
+    /// Path the archive lives at, next to `graph.db` in `.keel/`.
+    pub fn path(keel_dir: &Path) -> PathBuf {
+        keel_dir.join("index.rkyv")
+    }
+
+    /// Serialize `index` to `.keel/index.rkyv`, stamped with
+    /// `content_hash`. Written to a `.tmp` sibling and renamed into place
+    /// so a reader never observes a half-written file.
+    pub fn write(keel_dir: &Path, index: &SymbolIndex, content_hash: &str) -> Result<(), String> {
+        let fst_bytes = index.as_fst_bytes();
+        let side_table = IndexEntries {
+            entries: index.entries().to_vec(),
+        };
+        let entries_bytes = rkyv::to_bytes::<_, 4096>(&side_table)
+            .map_err(|e| format!("failed to archive symbol index: {e}"))?;
+
+        let path = Self::path(keel_dir);
+        let tmp_path = path.with_extension("rkyv.tmp");
+        let mut file = File::create(&tmp_path)
+            .map_err(|e| format!("failed to create {}: {e}", tmp_path.display()))?;
+
+        let hash_bytes = content_hash.as_bytes();
+        file.write_all(INDEX_MAGIC)
+            .and_then(|_| file.write_all(&INDEX_FORMAT_VERSION.to_le_bytes()))
+            .and_then(|_| file.write_all(&(hash_bytes.len() as u32).to_le_bytes()))
+            .and_then(|_| file.write_all(hash_bytes))
+            .and_then(|_| file.write_all(&(fst_bytes.len() as u64).to_le_bytes()))
+            .and_then(|_| file.write_all(fst_bytes))
+            .and_then(|_| file.write_all(&entries_bytes))
+            .map_err(|e| format!("failed to write symbol index cache: {e}"))?;
+        drop(file);
+
+        std::fs::rename(&tmp_path, &path)
+            .map_err(|e| format!("failed to finalize symbol index cache: {e}"))?;
+        Ok(())
+    }
+
+    /// Open and validate the archive at `.keel/index.rkyv`.
+    ///
+    /// Returns `None` -- never an error -- for anything that should fall
+    /// back to [`SymbolIndex::from_store`]: the file doesn't exist, its
+    /// format version or content hash doesn't match, or `bytecheck`
+    /// rejects the bytes as corrupt. A mismatched or corrupt cache is also
+    /// deleted so it doesn't keep getting tried.
+    pub fn open(keel_dir: &Path, expected_content_hash: &str) -> Option<SymbolIndex> {
+        let path = Self::path(keel_dir);
+        let file = File::open(&path).ok()?;
+        // SAFETY: the file isn't mutated by this process while mapped, and
+        // the archived side table is validated with bytecheck below before
+        // any `Archived<T>` is ever trusted. The raw FST bytes are handed
+        // to `fst::Map::new`, which does its own internal validation.
+        let mmap = unsafe { Mmap::map(&file) }.ok()?;
+
+        if mmap.len() < HEADER_PREFIX_LEN || &mmap[0..4] != INDEX_MAGIC.as_slice() {
+            return None;
+        }
+        let version = u32::from_le_bytes(mmap[4..8].try_into().ok()?);
+        if version != INDEX_FORMAT_VERSION {
+            let _ = std::fs::remove_file(&path);
+            return None;
+        }
+        let hash_len = u32::from_le_bytes(mmap[8..12].try_into().ok()?) as usize;
+        let hash_end = HEADER_PREFIX_LEN.checked_add(hash_len)?;
+        if mmap.len() < hash_end + 8 {
+            return None;
+        }
+        let stored_hash = std::str::from_utf8(&mmap[HEADER_PREFIX_LEN..hash_end]).ok()?;
+        if stored_hash != expected_content_hash {
+            let _ = std::fs::remove_file(&path);
+            return None;
+        }
+
+        let fst_len_start = hash_end;
+        let fst_start = fst_len_start + 8;
+        let fst_len = u64::from_le_bytes(mmap[fst_len_start..fst_start].try_into().ok()?) as usize;
+        let fst_end = fst_start.checked_add(fst_len)?;
+        if mmap.len() < fst_end {
+            return None;
+        }
+        let fst_bytes = mmap[fst_start..fst_end].to_vec();
+
+        let Ok(side_table) = rkyv::check_archived_root::<IndexEntries>(&mmap[fst_end..]) else {
+            let _ = std::fs::remove_file(&path);
+            return None;
+        };
+        let entries: Vec<SymbolMatch> = side_table.entries.deserialize(&mut rkyv::Infallible).ok()?;
+
+        SymbolIndex::from_raw_parts(fst_bytes, entries).ok()
+    }
+}
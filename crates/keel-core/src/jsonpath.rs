@@ -0,0 +1,363 @@
+//! A minimal JSONPath evaluator for querying a [`crate::snapshot`] graph
+//! view.
+//!
+//! Supports the subset of JSONPath needed to slice a graph snapshot:
+//! `$`, `.field`, `[*]`, `[N]`, and `[?(<filter>)]` where `<filter>` is a
+//! `&&`/`||` combination of `@.field OP literal` comparisons (`==`, `!=`,
+//! `<`, `<=`, `>`, `>=`). This is not a general JSONPath implementation —
+//! it covers exactly the shapes a caller needs to slice definitions,
+//! imports, and references out of the snapshot.
+
+use serde_json::Value;
+
+/// Evaluate a JSONPath expression against `root`, returning every matching
+/// value. Returns `Err` with a human-readable message if `expr` doesn't
+/// parse.
+pub fn evaluate(root: &Value, expr: &str) -> Result<Vec<Value>, String> {
+    let segments = parse_path(expr)?;
+    let mut current = vec![root.clone()];
+    for segment in &segments {
+        current = apply_segment(&current, segment);
+    }
+    Ok(current)
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Field(String),
+    Wildcard,
+    Index(usize),
+    Filter(FilterExpr),
+}
+
+fn apply_segment(current: &[Value], segment: &Segment) -> Vec<Value> {
+    match segment {
+        Segment::Field(name) => current
+            .iter()
+            .filter_map(|v| v.get(name).cloned())
+            .collect(),
+        Segment::Wildcard => current
+            .iter()
+            .flat_map(|v| match v {
+                Value::Array(items) => items.clone(),
+                Value::Object(map) => map.values().cloned().collect(),
+                _ => Vec::new(),
+            })
+            .collect(),
+        Segment::Index(idx) => current
+            .iter()
+            .filter_map(|v| v.as_array().and_then(|a| a.get(*idx).cloned()))
+            .collect(),
+        Segment::Filter(filter) => current
+            .iter()
+            .flat_map(|v| match v.as_array() {
+                Some(items) => items
+                    .iter()
+                    .filter(|item| eval_filter(filter, item))
+                    .cloned()
+                    .collect(),
+                None => Vec::new(),
+            })
+            .collect(),
+    }
+}
+
+fn parse_path(expr: &str) -> Result<Vec<Segment>, String> {
+    let expr = expr.trim();
+    let rest = expr
+        .strip_prefix('$')
+        .ok_or_else(|| format!("JSONPath expression must start with '$': {expr}"))?;
+
+    let chars: Vec<char> = rest.chars().collect();
+    let mut segments = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '.' => {
+                i += 1;
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                if start == i {
+                    return Err(format!(
+                        "expected a field name after '.' at position {start}"
+                    ));
+                }
+                segments.push(Segment::Field(chars[start..i].iter().collect()));
+            }
+            '[' => {
+                let close = find_matching_bracket(&chars, i)?;
+                let inner: String = chars[i + 1..close].iter().collect();
+                let inner = inner.trim();
+                if let Some(filter_src) = inner.strip_prefix("?(").and_then(|s| s.strip_suffix(')'))
+                {
+                    segments.push(Segment::Filter(parse_filter(filter_src)?));
+                } else if inner == "*" {
+                    segments.push(Segment::Wildcard);
+                } else if let Ok(idx) = inner.parse::<usize>() {
+                    segments.push(Segment::Index(idx));
+                } else {
+                    let name = inner.trim_matches(|c| c == '\'' || c == '"');
+                    segments.push(Segment::Field(name.to_string()));
+                }
+                i = close + 1;
+            }
+            other => return Err(format!("unexpected character '{other}' at position {i}")),
+        }
+    }
+    Ok(segments)
+}
+
+/// Find the `]` matching the `[` at `open`, skipping over brackets inside
+/// quoted string literals (a filter's string values may contain `]`).
+fn find_matching_bracket(chars: &[char], open: usize) -> Result<usize, String> {
+    let mut depth = 0i32;
+    let mut in_single = false;
+    let mut in_double = false;
+    for (i, &c) in chars.iter().enumerate().skip(open) {
+        if in_single {
+            if c == '\'' {
+                in_single = false;
+            }
+            continue;
+        }
+        if in_double {
+            if c == '"' {
+                in_double = false;
+            }
+            continue;
+        }
+        match c {
+            '\'' => in_single = true,
+            '"' => in_double = true,
+            '[' => depth += 1,
+            ']' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err("unterminated '[' in JSONPath expression".to_string())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone)]
+enum Literal {
+    Str(String),
+    Num(f64),
+    Bool(bool),
+    Null,
+}
+
+#[derive(Debug, Clone)]
+struct Comparison {
+    field: Vec<String>,
+    op: Op,
+    value: Literal,
+}
+
+/// A filter predicate: an OR of AND-groups of comparisons, mirroring
+/// `&&`/`||` precedence (`&&` binds tighter).
+#[derive(Debug, Clone)]
+struct FilterExpr {
+    groups: Vec<Vec<Comparison>>,
+}
+
+fn parse_filter(src: &str) -> Result<FilterExpr, String> {
+    let groups = src
+        .split("||")
+        .map(|and_group| {
+            and_group
+                .split("&&")
+                .map(|cmp| parse_comparison(cmp.trim()))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(FilterExpr { groups })
+}
+
+const COMPARISON_OPS: &[(&str, Op)] = &[
+    ("==", Op::Eq),
+    ("!=", Op::Ne),
+    ("<=", Op::Le),
+    (">=", Op::Ge),
+    ("<", Op::Lt),
+    (">", Op::Gt),
+];
+
+fn parse_comparison(s: &str) -> Result<Comparison, String> {
+    for (token, op) in COMPARISON_OPS {
+        if let Some(idx) = s.find(token) {
+            let field = parse_field_path(s[..idx].trim())?;
+            let value = parse_literal(s[idx + token.len()..].trim())?;
+            return Ok(Comparison {
+                field,
+                op: *op,
+                value,
+            });
+        }
+    }
+    Err(format!("unsupported filter comparison: {s}"))
+}
+
+fn parse_field_path(s: &str) -> Result<Vec<String>, String> {
+    let rest = s
+        .strip_prefix('@')
+        .ok_or_else(|| format!("filter left-hand side must start with '@': {s}"))?;
+    if rest.is_empty() {
+        return Ok(Vec::new());
+    }
+    let rest = rest
+        .strip_prefix('.')
+        .ok_or_else(|| format!("expected '.' after '@' in: {s}"))?;
+    Ok(rest.split('.').map(|p| p.to_string()).collect())
+}
+
+fn parse_literal(s: &str) -> Result<Literal, String> {
+    let is_quoted = |q: char| s.len() >= 2 && s.starts_with(q) && s.ends_with(q);
+    if is_quoted('\'') || is_quoted('"') {
+        return Ok(Literal::Str(s[1..s.len() - 1].to_string()));
+    }
+    match s {
+        "true" => Ok(Literal::Bool(true)),
+        "false" => Ok(Literal::Bool(false)),
+        "null" => Ok(Literal::Null),
+        _ => s
+            .parse::<f64>()
+            .map(Literal::Num)
+            .map_err(|_| format!("invalid filter literal: {s}")),
+    }
+}
+
+fn eval_filter(filter: &FilterExpr, item: &Value) -> bool {
+    filter
+        .groups
+        .iter()
+        .any(|group| group.iter().all(|cmp| eval_comparison(cmp, item)))
+}
+
+fn eval_comparison(cmp: &Comparison, item: &Value) -> bool {
+    let mut current = item;
+    for key in &cmp.field {
+        match current.get(key) {
+            Some(v) => current = v,
+            None => return false,
+        }
+    }
+    compare(current, cmp.op, &cmp.value)
+}
+
+fn compare(actual: &Value, op: Op, expected: &Literal) -> bool {
+    match (actual, expected) {
+        (Value::String(a), Literal::Str(b)) => compare_ord(a.as_str(), b.as_str(), op),
+        (Value::Number(a), Literal::Num(b)) => {
+            a.as_f64().map(|a| compare_ord(a, *b, op)).unwrap_or(false)
+        }
+        (Value::Bool(a), Literal::Bool(b)) => match op {
+            Op::Eq => a == b,
+            Op::Ne => a != b,
+            _ => false,
+        },
+        (Value::Null, Literal::Null) => matches!(op, Op::Eq),
+        (_, Literal::Null) => matches!(op, Op::Ne),
+        _ => false,
+    }
+}
+
+fn compare_ord<T: PartialOrd>(a: T, b: T, op: Op) -> bool {
+    match op {
+        Op::Eq => a == b,
+        Op::Ne => a != b,
+        Op::Lt => a < b,
+        Op::Le => a <= b,
+        Op::Gt => a > b,
+        Op::Ge => a >= b,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn sample() -> Value {
+        json!({
+            "definitions": [
+                {"name": "greet", "kind": "Function", "is_public": true, "type_hints_present": false},
+                {"name": "helper", "kind": "Function", "is_public": false, "type_hints_present": true},
+                {"name": "Widget", "kind": "Class", "is_public": true, "type_hints_present": true},
+            ]
+        })
+    }
+
+    #[test]
+    fn test_field_and_wildcard() {
+        let root = sample();
+        let result = evaluate(&root, "$.definitions[*]").unwrap();
+        assert_eq!(result.len(), 3);
+    }
+
+    #[test]
+    fn test_index_segment() {
+        let root = sample();
+        let result = evaluate(&root, "$.definitions[0]").unwrap();
+        assert_eq!(result, vec![root["definitions"][0].clone()]);
+    }
+
+    #[test]
+    fn test_filter_single_comparison() {
+        let root = sample();
+        let result = evaluate(&root, "$.definitions[?(@.kind=='Class')]").unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0]["name"], "Widget");
+    }
+
+    #[test]
+    fn test_filter_conjunction() {
+        let root = sample();
+        let result = evaluate(
+            &root,
+            "$.definitions[?(@.kind=='Function' && @.is_public==true && @.type_hints_present==false)]",
+        )
+        .unwrap();
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0]["name"], "greet");
+    }
+
+    #[test]
+    fn test_filter_disjunction() {
+        let root = sample();
+        let result = evaluate(
+            &root,
+            "$.definitions[?(@.kind=='Class' || @.name=='helper')]",
+        )
+        .unwrap();
+        let names: Vec<_> = result.iter().map(|v| v["name"].clone()).collect();
+        assert_eq!(names, vec![json!("helper"), json!("Widget")]);
+    }
+
+    #[test]
+    fn test_invalid_expression_missing_root() {
+        let root = sample();
+        assert!(evaluate(&root, "definitions[*]").is_err());
+    }
+
+    #[test]
+    fn test_invalid_filter_operator() {
+        let root = sample();
+        assert!(evaluate(&root, "$.definitions[?(@.kind ~= 'Function')]").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+//! Classic dynamic-programming Levenshtein edit distance, shared by two
+//! "did you mean" style features: fuzzy keyword scoring in
+//! `keel-enforce`'s naming suggestions, and unknown-subcommand hints in
+//! `keel-cli`.
+//!
+//! [`distance_within`] bails out early once an entire DP row already
+//! exceeds the threshold -- every cell in the next row can only be ≥ the
+//! corresponding cell above it minus one, so once a whole row clears the
+//! threshold no later row can bring the final answer back under it.
+
+/// Levenshtein edit distance between `a` and `b`.
+pub fn distance(a: &str, b: &str) -> usize {
+    distance_within(a, b, usize::MAX).unwrap_or(usize::MAX)
+}
+
+/// Edit distance between `a` and `b`, or `None` if it's more than
+/// `threshold`.
+pub fn distance_within(a: &str, b: &str, threshold: usize) -> Option<usize> {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (m, n) = (a.len(), b.len());
+
+    if m.abs_diff(n) > threshold {
+        return None;
+    }
+
+    let mut prev: Vec<usize> = (0..=n).collect();
+    let mut curr = vec![0usize; n + 1];
+
+    for i in 1..=m {
+        curr[0] = i;
+        let mut row_min = curr[0];
+        for j in 1..=n {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+            row_min = row_min.min(curr[j]);
+        }
+        if row_min > threshold {
+            return None;
+        }
+        std::mem::swap(&mut prev, &mut curr);
+    }
+
+    Some(prev[n]).filter(|d| *d <= threshold)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn identical_strings_have_zero_distance() {
+        assert_eq!(distance("discover", "discover"), 0);
+    }
+
+    #[test]
+    fn counts_substitutions_insertions_deletions() {
+        assert_eq!(distance("kitten", "sitting"), 3);
+        assert_eq!(distance("authentcate", "authenticate"), 1);
+    }
+
+    #[test]
+    fn distance_within_returns_none_past_threshold() {
+        assert_eq!(distance_within("discover", "discover", 0), Some(0));
+        assert_eq!(distance_within("discover", "dicsover", 1), None);
+        assert_eq!(distance_within("discover", "dicsover", 2), Some(2));
+        assert_eq!(distance_within("compile", "serve", 1), None);
+    }
+}
@@ -7,13 +7,32 @@
 //! - [`config`] — Configuration loading from `.keel/keel.json`
 //! - [`hash`] — Deterministic content hashing (base62 of xxhash64)
 //! - [`telemetry`] — Privacy-safe telemetry storage
+//! - [`backend`] — Startup-selectable [`GraphStore`](store::GraphStore) backend
+//! - [`snapshot`] — Whole-graph JSON snapshot builder for [`jsonpath`] queries
+//! - [`jsonpath`] — Minimal JSONPath evaluator used by the `query` command
+//! - [`symbol_index`] — FST-backed name index for fuzzy/prefix symbol lookup
+//! - [`graph_snapshot`] — rkyv archive of the graph for zero-copy warm starts (feature `rkyv-snapshot`)
+//! - [`index_cache`] — rkyv archive of the symbol index for warm starts (feature `rkyv-snapshot`)
+//! - [`levenshtein`] — edit-distance helper for fuzzy name/keyword matching
 
+pub mod backend;
 pub mod config;
+#[cfg(feature = "rkyv-snapshot")]
+pub mod graph_snapshot;
 pub mod hash;
+#[cfg(feature = "rkyv-snapshot")]
+pub mod index_cache;
+pub mod levenshtein;
+pub mod jsonpath;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+pub mod snapshot;
 pub mod sqlite;
 pub mod sqlite_batch;
 pub mod sqlite_helpers;
 pub mod sqlite_queries;
+pub mod sqlite_query;
 pub mod store;
+pub mod symbol_index;
 pub mod telemetry;
 pub mod types;
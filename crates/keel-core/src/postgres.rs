@@ -0,0 +1,764 @@
+//! Postgres-backed implementation of [`GraphStore`], feature-gated behind
+//! `postgres` (off by default) the same way `keel_parsers::tier3` gates its
+//! LSP/SCIP providers. Unlike [`crate::sqlite::SqliteGraphStore`]'s single
+//! `rusqlite::Connection` behind the caller's own `Mutex`, this backend
+//! pools connections internally (`r2d2`), so concurrent readers don't
+//! serialize on one lock and a single connection failure can't poison the
+//! whole store.
+//!
+//! Schema mirrors `SqliteGraphStore::initialize_schema` table-for-table
+//! (`nodes`, `edges`, `external_endpoints`, `previous_hashes`,
+//! `module_profiles`), translated to Postgres types (`BIGINT` in place of
+//! SQLite's untyped `INTEGER`, `TIMESTAMPTZ` in place of `datetime('now')`
+//! text columns).
+
+use std::collections::HashMap;
+
+use postgres::NoTls;
+use r2d2::Pool;
+use r2d2_postgres::PostgresConnectionManager;
+
+use crate::store::GraphStore;
+use crate::types::{
+    EdgeChange, EdgeDirection, EdgeKind, ExternalEndpoint, GraphEdge, GraphError, GraphNode,
+    ModuleProfile, NodeChange, NodeKind,
+};
+
+const SCHEMA_VERSION: u32 = 1;
+
+/// Postgres-backed implementation of the GraphStore trait.
+pub struct PostgresGraphStore {
+    pool: Pool<PostgresConnectionManager<NoTls>>,
+}
+
+impl PostgresGraphStore {
+    /// Connect to `database_url` (a standard `postgres://` connection
+    /// string), run the schema migration, and return a pooled store.
+    pub fn open(database_url: &str) -> Result<Self, GraphError> {
+        let manager = PostgresConnectionManager::new(database_url.parse()?, NoTls);
+        let pool = Pool::new(manager)?;
+        let store = PostgresGraphStore { pool };
+        store.initialize_schema()?;
+        Ok(store)
+    }
+
+    fn initialize_schema(&self) -> Result<(), GraphError> {
+        let mut conn = self.pool.get()?;
+        conn.batch_execute(
+            "
+            CREATE TABLE IF NOT EXISTS keel_meta (
+                key TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS nodes (
+                id BIGINT PRIMARY KEY,
+                hash TEXT NOT NULL UNIQUE,
+                kind TEXT NOT NULL CHECK (kind IN ('module', 'class', 'function')),
+                name TEXT NOT NULL,
+                signature TEXT NOT NULL DEFAULT '',
+                file_path TEXT NOT NULL,
+                line_start INTEGER NOT NULL,
+                line_end INTEGER NOT NULL,
+                docstring TEXT,
+                is_public BOOLEAN NOT NULL DEFAULT FALSE,
+                type_hints_present BOOLEAN NOT NULL DEFAULT FALSE,
+                has_docstring BOOLEAN NOT NULL DEFAULT FALSE,
+                module_id BIGINT REFERENCES nodes(id),
+                package TEXT,
+                created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+                updated_at TIMESTAMPTZ NOT NULL DEFAULT now()
+            );
+            CREATE INDEX IF NOT EXISTS idx_nodes_hash ON nodes(hash);
+            CREATE INDEX IF NOT EXISTS idx_nodes_file ON nodes(file_path);
+            CREATE INDEX IF NOT EXISTS idx_nodes_module ON nodes(module_id);
+            CREATE INDEX IF NOT EXISTS idx_nodes_kind ON nodes(kind);
+
+            CREATE TABLE IF NOT EXISTS previous_hashes (
+                node_id BIGINT NOT NULL REFERENCES nodes(id) ON DELETE CASCADE,
+                hash TEXT NOT NULL,
+                created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+                PRIMARY KEY (node_id, hash)
+            );
+
+            CREATE TABLE IF NOT EXISTS external_endpoints (
+                id BIGSERIAL PRIMARY KEY,
+                node_id BIGINT NOT NULL REFERENCES nodes(id) ON DELETE CASCADE,
+                kind TEXT NOT NULL,
+                method TEXT NOT NULL DEFAULT '',
+                path TEXT NOT NULL,
+                direction TEXT NOT NULL CHECK (direction IN ('serves', 'calls'))
+            );
+            CREATE INDEX IF NOT EXISTS idx_endpoints_node ON external_endpoints(node_id);
+
+            CREATE TABLE IF NOT EXISTS edges (
+                id BIGINT PRIMARY KEY,
+                source_id BIGINT NOT NULL REFERENCES nodes(id) ON DELETE CASCADE,
+                target_id BIGINT NOT NULL REFERENCES nodes(id) ON DELETE CASCADE,
+                kind TEXT NOT NULL CHECK (kind IN ('calls', 'imports', 'inherits', 'contains')),
+                file_path TEXT NOT NULL,
+                line INTEGER NOT NULL,
+                confidence DOUBLE PRECISION NOT NULL DEFAULT 1.0
+            );
+            CREATE INDEX IF NOT EXISTS idx_edges_source ON edges(source_id);
+            CREATE INDEX IF NOT EXISTS idx_edges_target ON edges(target_id);
+            CREATE INDEX IF NOT EXISTS idx_edges_kind ON edges(kind);
+
+            CREATE TABLE IF NOT EXISTS module_profiles (
+                module_id BIGINT PRIMARY KEY REFERENCES nodes(id) ON DELETE CASCADE,
+                path TEXT NOT NULL,
+                function_count INTEGER NOT NULL DEFAULT 0,
+                class_count INTEGER NOT NULL DEFAULT 0,
+                line_count INTEGER NOT NULL DEFAULT 0,
+                function_name_prefixes TEXT NOT NULL DEFAULT '[]',
+                primary_types TEXT NOT NULL DEFAULT '[]',
+                import_sources TEXT NOT NULL DEFAULT '[]',
+                export_targets TEXT NOT NULL DEFAULT '[]',
+                external_endpoint_count INTEGER NOT NULL DEFAULT 0,
+                responsibility_keywords TEXT NOT NULL DEFAULT '[]'
+            );
+            ",
+        )?;
+
+        conn.execute(
+            "INSERT INTO keel_meta (key, value) VALUES ('schema_version', $1)
+             ON CONFLICT (key) DO NOTHING",
+            &[&SCHEMA_VERSION.to_string()],
+        )?;
+
+        Ok(())
+    }
+
+    /// Get the current schema version.
+    pub fn schema_version(&self) -> Result<u32, GraphError> {
+        let mut conn = self.pool.get()?;
+        let row = conn.query_one(
+            "SELECT value FROM keel_meta WHERE key = 'schema_version'",
+            &[],
+        )?;
+        let version: String = row.get(0);
+        version
+            .parse()
+            .map_err(|e| GraphError::Internal(format!("Invalid schema version: {}", e)))
+    }
+
+    fn row_to_node(row: &postgres::Row) -> GraphNode {
+        let kind_str: String = row.get("kind");
+        let kind = match kind_str.as_str() {
+            "module" => NodeKind::Module,
+            "class" => NodeKind::Class,
+            "function" => NodeKind::Function,
+            _ => NodeKind::Function,
+        };
+        let id: i64 = row.get("id");
+        let module_id: Option<i64> = row.get("module_id");
+        GraphNode {
+            id: id as u64,
+            hash: row.get("hash"),
+            kind,
+            name: row.get("name"),
+            signature: row.get("signature"),
+            file_path: row.get("file_path"),
+            line_start: row.get::<_, i32>("line_start") as u32,
+            line_end: row.get::<_, i32>("line_end") as u32,
+            docstring: row.get("docstring"),
+            is_public: row.get("is_public"),
+            type_hints_present: row.get("type_hints_present"),
+            has_docstring: row.get("has_docstring"),
+            external_endpoints: Vec::new(), // loaded separately
+            previous_hashes: Vec::new(),    // loaded separately
+            module_id: module_id.unwrap_or(0) as u64,
+            package: row.get("package"),
+        }
+    }
+
+    fn row_to_edge(row: &postgres::Row) -> GraphEdge {
+        let kind_str: String = row.get("kind");
+        let kind = match kind_str.as_str() {
+            "calls" => EdgeKind::Calls,
+            "imports" => EdgeKind::Imports,
+            "inherits" => EdgeKind::Inherits,
+            "contains" => EdgeKind::Contains,
+            _ => EdgeKind::Calls,
+        };
+        let id: i64 = row.get("id");
+        let source_id: i64 = row.get("source_id");
+        let target_id: i64 = row.get("target_id");
+        GraphEdge {
+            id: id as u64,
+            source_id: source_id as u64,
+            target_id: target_id as u64,
+            kind,
+            file_path: row.get("file_path"),
+            line: row.get::<_, i32>("line") as u32,
+            confidence: row.get("confidence"),
+        }
+    }
+
+    fn node_with_relations(&self, mut node: GraphNode) -> GraphNode {
+        let endpoints = self.batch_load_endpoints(&[node.id]);
+        let hashes = self.batch_load_previous_hashes(&[node.id]);
+        node.external_endpoints = endpoints.get(&node.id).cloned().unwrap_or_default();
+        node.previous_hashes = hashes.get(&node.id).cloned().unwrap_or_default();
+        node
+    }
+
+    /// Batch-load endpoints for multiple nodes in a single query, mirroring
+    /// `SqliteGraphStore::load_endpoints_batch`.
+    fn batch_load_endpoints(&self, node_ids: &[u64]) -> HashMap<u64, Vec<ExternalEndpoint>> {
+        let mut grouped: HashMap<u64, Vec<ExternalEndpoint>> = HashMap::new();
+        if node_ids.is_empty() {
+            return grouped;
+        }
+        let ids: Vec<i64> = node_ids.iter().map(|&id| id as i64).collect();
+        let Ok(mut conn) = self.pool.get() else {
+            return grouped;
+        };
+        let Ok(rows) = conn.query(
+            "SELECT node_id, kind, method, path, direction FROM external_endpoints
+             WHERE node_id = ANY($1)",
+            &[&ids],
+        ) else {
+            return grouped;
+        };
+        for row in rows {
+            let node_id: i64 = row.get("node_id");
+            grouped
+                .entry(node_id as u64)
+                .or_default()
+                .push(ExternalEndpoint {
+                    kind: row.get("kind"),
+                    method: row.get("method"),
+                    path: row.get("path"),
+                    direction: row.get("direction"),
+                });
+        }
+        grouped
+    }
+
+    /// Batch-load previous hashes for multiple nodes in a single query,
+    /// mirroring `SqliteGraphStore::load_previous_hashes_batch`.
+    fn batch_load_previous_hashes(&self, node_ids: &[u64]) -> HashMap<u64, Vec<String>> {
+        let mut grouped: HashMap<u64, Vec<String>> = HashMap::new();
+        if node_ids.is_empty() {
+            return grouped;
+        }
+        let ids: Vec<i64> = node_ids.iter().map(|&id| id as i64).collect();
+        let Ok(mut conn) = self.pool.get() else {
+            return grouped;
+        };
+        let Ok(rows) = conn.query(
+            "SELECT node_id, hash FROM previous_hashes WHERE node_id = ANY($1)
+             ORDER BY created_at DESC",
+            &[&ids],
+        ) else {
+            return grouped;
+        };
+        for row in rows {
+            let node_id: i64 = row.get("node_id");
+            let hash: String = row.get("hash");
+            let entry = grouped.entry(node_id as u64).or_default();
+            if entry.len() < 3 {
+                entry.push(hash);
+            }
+        }
+        grouped
+    }
+}
+
+impl GraphStore for PostgresGraphStore {
+    fn get_node(&self, hash: &str) -> Option<GraphNode> {
+        let mut conn = self.pool.get().ok()?;
+        let row = conn
+            .query_opt("SELECT * FROM nodes WHERE hash = $1", &[&hash])
+            .ok()?;
+        let node = match row {
+            Some(row) => Self::row_to_node(&row),
+            None => {
+                let row = conn
+                    .query_opt(
+                        "SELECT n.* FROM nodes n
+                         JOIN previous_hashes ph ON ph.node_id = n.id
+                         WHERE ph.hash = $1
+                         LIMIT 1",
+                        &[&hash],
+                    )
+                    .ok()??;
+                Self::row_to_node(&row)
+            }
+        };
+        Some(self.node_with_relations(node))
+    }
+
+    fn get_node_by_id(&self, id: u64) -> Option<GraphNode> {
+        let mut conn = self.pool.get().ok()?;
+        let row = conn
+            .query_opt("SELECT * FROM nodes WHERE id = $1", &[&(id as i64)])
+            .ok()??;
+        Some(self.node_with_relations(Self::row_to_node(&row)))
+    }
+
+    fn get_edges(&self, node_id: u64, direction: EdgeDirection) -> Vec<GraphEdge> {
+        let Ok(mut conn) = self.pool.get() else {
+            return Vec::new();
+        };
+        let query = match direction {
+            EdgeDirection::Incoming => "SELECT * FROM edges WHERE target_id = $1",
+            EdgeDirection::Outgoing => "SELECT * FROM edges WHERE source_id = $1",
+            EdgeDirection::Both => "SELECT * FROM edges WHERE source_id = $1 OR target_id = $1",
+        };
+        match conn.query(query, &[&(node_id as i64)]) {
+            Ok(rows) => rows.iter().map(Self::row_to_edge).collect(),
+            Err(e) => {
+                eprintln!("[keel] get_edges: query failed: {e}");
+                Vec::new()
+            }
+        }
+    }
+
+    fn get_module_profile(&self, module_id: u64) -> Option<ModuleProfile> {
+        let mut conn = self.pool.get().ok()?;
+        let row = conn
+            .query_opt(
+                "SELECT * FROM module_profiles WHERE module_id = $1",
+                &[&(module_id as i64)],
+            )
+            .ok()??;
+        let prefixes: String = row.get("function_name_prefixes");
+        let types: String = row.get("primary_types");
+        let imports: String = row.get("import_sources");
+        let exports: String = row.get("export_targets");
+        let keywords: String = row.get("responsibility_keywords");
+        let module_id: i64 = row.get("module_id");
+        Some(ModuleProfile {
+            module_id: module_id as u64,
+            path: row.get("path"),
+            function_count: row.get::<_, i32>("function_count") as u32,
+            class_count: row.get::<_, i32>("class_count") as u32,
+            line_count: row.get::<_, i32>("line_count") as u32,
+            function_name_prefixes: serde_json::from_str(&prefixes).unwrap_or_default(),
+            primary_types: serde_json::from_str(&types).unwrap_or_default(),
+            import_sources: serde_json::from_str(&imports).unwrap_or_default(),
+            export_targets: serde_json::from_str(&exports).unwrap_or_default(),
+            external_endpoint_count: row.get::<_, i32>("external_endpoint_count") as u32,
+            responsibility_keywords: serde_json::from_str(&keywords).unwrap_or_default(),
+        })
+    }
+
+    fn get_nodes_in_file(&self, file_path: &str) -> Vec<GraphNode> {
+        let Ok(mut conn) = self.pool.get() else {
+            return Vec::new();
+        };
+        let nodes: Vec<GraphNode> =
+            match conn.query("SELECT * FROM nodes WHERE file_path = $1", &[&file_path]) {
+                Ok(rows) => rows.iter().map(Self::row_to_node).collect(),
+                Err(e) => {
+                    eprintln!("[keel] get_nodes_in_file: query failed: {e}");
+                    return Vec::new();
+                }
+            };
+        let ids: Vec<u64> = nodes.iter().map(|n| n.id).collect();
+        let mut endpoints = self.batch_load_endpoints(&ids);
+        let mut hashes = self.batch_load_previous_hashes(&ids);
+        nodes
+            .into_iter()
+            .map(|mut n| {
+                n.external_endpoints = endpoints.remove(&n.id).unwrap_or_default();
+                n.previous_hashes = hashes.remove(&n.id).unwrap_or_default();
+                n
+            })
+            .collect()
+    }
+
+    fn get_all_modules(&self) -> Vec<GraphNode> {
+        let Ok(mut conn) = self.pool.get() else {
+            return Vec::new();
+        };
+        let nodes: Vec<GraphNode> =
+            match conn.query("SELECT * FROM nodes WHERE kind = 'module'", &[]) {
+                Ok(rows) => rows.iter().map(Self::row_to_node).collect(),
+                Err(e) => {
+                    eprintln!("[keel] get_all_modules: query failed: {e}");
+                    return Vec::new();
+                }
+            };
+        let ids: Vec<u64> = nodes.iter().map(|n| n.id).collect();
+        let mut endpoints = self.batch_load_endpoints(&ids);
+        let mut hashes = self.batch_load_previous_hashes(&ids);
+        nodes
+            .into_iter()
+            .map(|mut n| {
+                n.external_endpoints = endpoints.remove(&n.id).unwrap_or_default();
+                n.previous_hashes = hashes.remove(&n.id).unwrap_or_default();
+                n
+            })
+            .collect()
+    }
+
+    fn update_nodes(&mut self, changes: Vec<NodeChange>) -> Result<(), GraphError> {
+        let mut conn = self.pool.get()?;
+        let mut tx = conn.transaction()?;
+        for change in changes {
+            match change {
+                NodeChange::Add(node) => {
+                    let existing: Option<String> = tx
+                        .query_opt("SELECT name FROM nodes WHERE hash = $1", &[&node.hash])?
+                        .map(|row| row.get(0));
+                    if let Some(existing_name) = existing {
+                        if existing_name != node.name {
+                            return Err(GraphError::HashCollision {
+                                hash: node.hash.clone(),
+                                existing: existing_name,
+                                new_fn: node.name.clone(),
+                            });
+                        }
+                    }
+                    tx.execute(
+                        "INSERT INTO nodes (id, hash, kind, name, signature, file_path, line_start, line_end, docstring, is_public, type_hints_present, has_docstring, module_id, package)
+                         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+                         ON CONFLICT (hash) DO UPDATE SET
+                            kind = excluded.kind,
+                            name = excluded.name,
+                            signature = excluded.signature,
+                            file_path = excluded.file_path,
+                            line_start = excluded.line_start,
+                            line_end = excluded.line_end,
+                            docstring = excluded.docstring,
+                            is_public = excluded.is_public,
+                            type_hints_present = excluded.type_hints_present,
+                            has_docstring = excluded.has_docstring,
+                            module_id = excluded.module_id,
+                            package = excluded.package,
+                            updated_at = now()",
+                        &[
+                            &(node.id as i64),
+                            &node.hash,
+                            &node.kind.as_str(),
+                            &node.name,
+                            &node.signature,
+                            &node.file_path,
+                            &(node.line_start as i32),
+                            &(node.line_end as i32),
+                            &node.docstring,
+                            &node.is_public,
+                            &node.type_hints_present,
+                            &node.has_docstring,
+                            &if node.module_id == 0 {
+                                None
+                            } else {
+                                Some(node.module_id as i64)
+                            },
+                            &node.package,
+                        ],
+                    )?;
+                }
+                NodeChange::Update(node) => {
+                    let existing: Option<(i64, String)> = tx
+                        .query_opt("SELECT id, name FROM nodes WHERE hash = $1", &[&node.hash])?
+                        .map(|row| (row.get(0), row.get(1)));
+                    if let Some((existing_id, existing_name)) = existing {
+                        if existing_id as u64 != node.id {
+                            return Err(GraphError::HashCollision {
+                                hash: node.hash.clone(),
+                                existing: existing_name,
+                                new_fn: node.name.clone(),
+                            });
+                        }
+                    }
+                    tx.execute(
+                        "UPDATE nodes SET hash = $1, kind = $2, name = $3, signature = $4, file_path = $5, line_start = $6, line_end = $7, docstring = $8, is_public = $9, type_hints_present = $10, has_docstring = $11, module_id = $12, package = $13, updated_at = now() WHERE id = $14",
+                        &[
+                            &node.hash,
+                            &node.kind.as_str(),
+                            &node.name,
+                            &node.signature,
+                            &node.file_path,
+                            &(node.line_start as i32),
+                            &(node.line_end as i32),
+                            &node.docstring,
+                            &node.is_public,
+                            &node.type_hints_present,
+                            &node.has_docstring,
+                            &if node.module_id == 0 {
+                                None
+                            } else {
+                                Some(node.module_id as i64)
+                            },
+                            &node.package,
+                            &(node.id as i64),
+                        ],
+                    )?;
+                }
+                NodeChange::Remove(id) => {
+                    tx.execute("DELETE FROM nodes WHERE id = $1", &[&(id as i64)])?;
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn update_edges(&mut self, changes: Vec<EdgeChange>) -> Result<(), GraphError> {
+        let mut conn = self.pool.get()?;
+        let mut tx = conn.transaction()?;
+        for change in changes {
+            match change {
+                EdgeChange::Add(edge) => {
+                    tx.execute(
+                        "INSERT INTO edges (id, source_id, target_id, kind, file_path, line, confidence) VALUES ($1, $2, $3, $4, $5, $6, $7)
+                         ON CONFLICT DO NOTHING",
+                        &[
+                            &(edge.id as i64),
+                            &(edge.source_id as i64),
+                            &(edge.target_id as i64),
+                            &edge.kind.as_str(),
+                            &edge.file_path,
+                            &(edge.line as i32),
+                            &edge.confidence,
+                        ],
+                    )?;
+                }
+                EdgeChange::Remove(id) => {
+                    tx.execute("DELETE FROM edges WHERE id = $1", &[&(id as i64)])?;
+                }
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    fn get_previous_hashes(&self, node_id: u64) -> Vec<String> {
+        self.batch_load_previous_hashes(&[node_id])
+            .remove(&node_id)
+            .unwrap_or_default()
+    }
+
+    fn find_modules_by_prefix(&self, prefix: &str, exclude_file: &str) -> Vec<ModuleProfile> {
+        let Ok(mut conn) = self.pool.get() else {
+            return Vec::new();
+        };
+        let pattern = format!("%\"{}\"%", prefix);
+        let rows = match conn.query(
+            "SELECT mp.* FROM module_profiles mp
+             JOIN nodes n ON n.id = mp.module_id
+             WHERE n.file_path != $1 AND mp.function_name_prefixes LIKE $2",
+            &[&exclude_file, &pattern],
+        ) {
+            Ok(rows) => rows,
+            Err(e) => {
+                eprintln!("[keel] find_modules_by_prefix: query failed: {e}");
+                return Vec::new();
+            }
+        };
+        rows.iter()
+            .map(|row| {
+                let prefixes: String = row.get("function_name_prefixes");
+                let types: String = row.get("primary_types");
+                let imports: String = row.get("import_sources");
+                let exports: String = row.get("export_targets");
+                let keywords: String = row.get("responsibility_keywords");
+                let module_id: i64 = row.get("module_id");
+                ModuleProfile {
+                    module_id: module_id as u64,
+                    path: row.get("path"),
+                    function_count: row.get::<_, i32>("function_count") as u32,
+                    class_count: row.get::<_, i32>("class_count") as u32,
+                    line_count: row.get::<_, i32>("line_count") as u32,
+                    function_name_prefixes: serde_json::from_str(&prefixes).unwrap_or_default(),
+                    primary_types: serde_json::from_str(&types).unwrap_or_default(),
+                    import_sources: serde_json::from_str(&imports).unwrap_or_default(),
+                    export_targets: serde_json::from_str(&exports).unwrap_or_default(),
+                    external_endpoint_count: row.get::<_, i32>("external_endpoint_count") as u32,
+                    responsibility_keywords: serde_json::from_str(&keywords).unwrap_or_default(),
+                }
+            })
+            .collect()
+    }
+
+    fn find_nodes_by_name(&self, name: &str, kind: &str, exclude_file: &str) -> Vec<GraphNode> {
+        let sql = match (kind.is_empty(), exclude_file.is_empty()) {
+            (true, true) => "SELECT * FROM nodes WHERE name = $1",
+            (true, false) => "SELECT * FROM nodes WHERE name = $1 AND file_path != $2",
+            (false, true) => "SELECT * FROM nodes WHERE name = $1 AND kind = $2",
+            (false, false) => {
+                "SELECT * FROM nodes WHERE name = $1 AND kind = $2 AND file_path != $3"
+            }
+        };
+        let Ok(mut conn) = self.pool.get() else {
+            return Vec::new();
+        };
+        let result = match (kind.is_empty(), exclude_file.is_empty()) {
+            (true, true) => conn.query(sql, &[&name]),
+            (true, false) => conn.query(sql, &[&name, &exclude_file]),
+            (false, true) => conn.query(sql, &[&name, &kind]),
+            (false, false) => conn.query(sql, &[&name, &kind, &exclude_file]),
+        };
+        match result {
+            Ok(rows) => rows.iter().map(Self::row_to_node).collect(),
+            Err(e) => {
+                eprintln!("[keel] find_nodes_by_name: query failed: {e}");
+                Vec::new()
+            }
+        }
+    }
+
+    fn nodes_with_relations_batch(&self, hashes: &[String]) -> HashMap<String, GraphNode> {
+        if hashes.is_empty() {
+            return HashMap::new();
+        }
+        let Ok(mut conn) = self.pool.get() else {
+            return HashMap::new();
+        };
+        let nodes: Vec<GraphNode> =
+            match conn.query("SELECT * FROM nodes WHERE hash = ANY($1)", &[&hashes]) {
+                Ok(rows) => rows.iter().map(Self::row_to_node).collect(),
+                Err(_) => return HashMap::new(),
+            };
+        let ids: Vec<u64> = nodes.iter().map(|n| n.id).collect();
+        let mut endpoints = self.batch_load_endpoints(&ids);
+        let mut prev_hashes = self.batch_load_previous_hashes(&ids);
+        nodes
+            .into_iter()
+            .map(|mut n| {
+                n.external_endpoints = endpoints.remove(&n.id).unwrap_or_default();
+                n.previous_hashes = prev_hashes.remove(&n.id).unwrap_or_default();
+                (n.hash.clone(), n)
+            })
+            .collect()
+    }
+
+    fn nodes_by_ids_batch(&self, ids: &[u64]) -> HashMap<u64, GraphNode> {
+        if ids.is_empty() {
+            return HashMap::new();
+        }
+        let pg_ids: Vec<i64> = ids.iter().map(|&id| id as i64).collect();
+        let Ok(mut conn) = self.pool.get() else {
+            return HashMap::new();
+        };
+        match conn.query("SELECT * FROM nodes WHERE id = ANY($1)", &[&pg_ids]) {
+            Ok(rows) => rows
+                .iter()
+                .map(Self::row_to_node)
+                .map(|n| (n.id, n))
+                .collect(),
+            Err(_) => HashMap::new(),
+        }
+    }
+
+    fn edges_batch(
+        &self,
+        node_ids: &[u64],
+        direction: EdgeDirection,
+    ) -> HashMap<u64, Vec<GraphEdge>> {
+        let mut grouped: HashMap<u64, Vec<GraphEdge>> = HashMap::new();
+        if node_ids.is_empty() {
+            return grouped;
+        }
+        let pg_ids: Vec<i64> = node_ids.iter().map(|&id| id as i64).collect();
+        let Ok(mut conn) = self.pool.get() else {
+            return grouped;
+        };
+        let query = match direction {
+            EdgeDirection::Incoming => "SELECT * FROM edges WHERE target_id = ANY($1)",
+            EdgeDirection::Outgoing => "SELECT * FROM edges WHERE source_id = ANY($1)",
+            EdgeDirection::Both => {
+                "SELECT * FROM edges WHERE source_id = ANY($1) OR target_id = ANY($1)"
+            }
+        };
+        let Ok(rows) = conn.query(query, &[&pg_ids]) else {
+            return grouped;
+        };
+        for row in rows {
+            let edge = Self::row_to_edge(&row);
+            let key = match direction {
+                EdgeDirection::Incoming => edge.target_id,
+                EdgeDirection::Outgoing => edge.source_id,
+                EdgeDirection::Both => {
+                    if node_ids.contains(&edge.target_id) {
+                        edge.target_id
+                    } else {
+                        edge.source_id
+                    }
+                }
+            };
+            grouped.entry(key).or_default().push(edge);
+        }
+        grouped
+    }
+}
+
+/// Integration tests against a real Postgres instance. Unlike
+/// `SqliteGraphStore`'s tests, which run against an in-memory connection
+/// with no setup, these need a live database -- so they're gated behind
+/// `KEEL_TEST_POSTGRES_URL` and skip (rather than fail) when it's unset,
+/// the same way CI-only checks elsewhere in the project are opted into
+/// rather than run by default.
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::NodeKind;
+
+    fn test_store() -> Option<PostgresGraphStore> {
+        let url = std::env::var("KEEL_TEST_POSTGRES_URL").ok()?;
+        Some(PostgresGraphStore::open(&url).expect("failed to open KEEL_TEST_POSTGRES_URL"))
+    }
+
+    fn test_node(id: u64, hash: &str, name: &str) -> GraphNode {
+        GraphNode {
+            id,
+            hash: hash.to_string(),
+            kind: NodeKind::Function,
+            name: name.to_string(),
+            signature: format!("fn {}()", name),
+            file_path: "src/test.rs".to_string(),
+            line_start: 1,
+            line_end: 10,
+            docstring: None,
+            is_public: true,
+            type_hints_present: true,
+            has_docstring: false,
+            external_endpoints: vec![],
+            previous_hashes: vec![],
+            module_id: 0,
+            package: None,
+        }
+    }
+
+    #[test]
+    fn test_create_and_read_node() {
+        let Some(mut store) = test_store() else { return };
+        let node = test_node(1, "pgabc1234567", "test_fn");
+        store.update_nodes(vec![NodeChange::Add(node)]).unwrap();
+
+        let retrieved = store.get_node("pgabc1234567").unwrap();
+        assert_eq!(retrieved.name, "test_fn");
+    }
+
+    #[test]
+    fn test_get_node_by_id() {
+        let Some(mut store) = test_store() else { return };
+        let node = test_node(2, "pgdef1234567", "lookup_fn");
+        store.update_nodes(vec![NodeChange::Add(node)]).unwrap();
+
+        let retrieved = store.get_node_by_id(2).unwrap();
+        assert_eq!(retrieved.name, "lookup_fn");
+    }
+
+    #[test]
+    fn test_get_node_missing_hash_returns_none() {
+        let Some(store) = test_store() else { return };
+        assert!(store.get_node("does-not-exist").is_none());
+    }
+
+    #[test]
+    fn test_nodes_by_ids_batch_empty_input_returns_empty() {
+        let Some(store) = test_store() else { return };
+        assert!(store.nodes_by_ids_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_edges_batch_empty_input_returns_empty() {
+        let Some(store) = test_store() else { return };
+        assert!(store.edges_batch(&[], EdgeDirection::Both).is_empty());
+    }
+}
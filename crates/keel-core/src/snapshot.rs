@@ -0,0 +1,142 @@
+//! Builds a `serde_json::Value` snapshot of the whole graph for
+//! [`crate::jsonpath`] queries to run against.
+
+use std::collections::HashSet;
+
+use crate::store::GraphStore;
+use crate::types::{EdgeDirection, EdgeKind, GraphNode};
+
+/// Build a JSON snapshot of the graph: `definitions` (every node, modules
+/// included), `imports` (edges of kind [`EdgeKind::Imports`]), and
+/// `references` (every other edge kind). Mirrors the iterate-modules /
+/// nodes-in-file / edges-per-node walk used by `keel stats` and
+/// `keel/map`. Only each node's outgoing edges are walked; edges are still
+/// deduped by id so a node reached through more than one module's file
+/// doesn't list its edges twice.
+pub fn build_graph_snapshot(store: &dyn GraphStore) -> serde_json::Value {
+    let modules = store.get_all_modules();
+
+    let mut definitions = Vec::new();
+    let mut imports = Vec::new();
+    let mut references = Vec::new();
+    let mut seen_edges = HashSet::new();
+
+    for module in &modules {
+        let mut nodes = store.get_nodes_in_file(&module.file_path);
+        if !nodes.iter().any(|n| n.id == module.id) {
+            nodes.push(module.clone());
+        }
+
+        for node in &nodes {
+            definitions.push(node_to_json(node));
+
+            for edge in store.get_edges(node.id, EdgeDirection::Outgoing) {
+                if !seen_edges.insert(edge.id) {
+                    continue;
+                }
+                let entry = serde_json::to_value(&edge).unwrap_or(serde_json::Value::Null);
+                if edge.kind == EdgeKind::Imports {
+                    imports.push(entry);
+                } else {
+                    references.push(entry);
+                }
+            }
+        }
+    }
+
+    serde_json::json!({
+        "definitions": definitions,
+        "imports": imports,
+        "references": references,
+    })
+}
+
+fn node_to_json(node: &GraphNode) -> serde_json::Value {
+    serde_json::to_value(node).unwrap_or(serde_json::Value::Null)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sqlite::SqliteGraphStore;
+    use crate::types::{EdgeChange, GraphEdge, NodeChange, NodeKind};
+
+    fn test_node(id: u64, hash: &str, name: &str, kind: NodeKind, module_id: u64) -> GraphNode {
+        GraphNode {
+            id,
+            hash: hash.to_string(),
+            kind,
+            name: name.to_string(),
+            signature: String::new(),
+            file_path: "src/lib.rs".to_string(),
+            line_start: 1,
+            line_end: 1,
+            docstring: None,
+            is_public: true,
+            type_hints_present: false,
+            has_docstring: false,
+            external_endpoints: Vec::new(),
+            previous_hashes: Vec::new(),
+            module_id,
+            package: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_includes_definitions_and_imports() {
+        let mut store = SqliteGraphStore::in_memory().unwrap();
+        let module = test_node(1, "mod-hash", "lib", NodeKind::Module, 1);
+        let func = test_node(2, "func-hash", "greet", NodeKind::Function, 1);
+        store
+            .update_nodes(vec![
+                NodeChange::Add(module.clone()),
+                NodeChange::Add(func.clone()),
+            ])
+            .unwrap();
+        store
+            .update_edges(vec![EdgeChange::Add(GraphEdge {
+                id: 1,
+                source_id: 1,
+                target_id: 2,
+                kind: EdgeKind::Imports,
+                file_path: "src/lib.rs".to_string(),
+                line: 1,
+                confidence: 1.0,
+            })])
+            .unwrap();
+
+        let snapshot = build_graph_snapshot(&store);
+        assert_eq!(snapshot["definitions"].as_array().unwrap().len(), 2);
+        assert_eq!(snapshot["imports"].as_array().unwrap().len(), 1);
+        assert_eq!(snapshot["references"].as_array().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_snapshot_dedupes_shared_edges() {
+        let mut store = SqliteGraphStore::in_memory().unwrap();
+        let module = test_node(1, "mod-hash", "lib", NodeKind::Module, 1);
+        let a = test_node(2, "a-hash", "a", NodeKind::Function, 1);
+        let b = test_node(3, "b-hash", "b", NodeKind::Function, 1);
+        store
+            .update_nodes(vec![
+                NodeChange::Add(module),
+                NodeChange::Add(a),
+                NodeChange::Add(b),
+            ])
+            .unwrap();
+        store
+            .update_edges(vec![EdgeChange::Add(GraphEdge {
+                id: 1,
+                source_id: 2,
+                target_id: 3,
+                kind: EdgeKind::Calls,
+                file_path: "src/lib.rs".to_string(),
+                line: 1,
+                confidence: 1.0,
+            })])
+            .unwrap();
+
+        let snapshot = build_graph_snapshot(&store);
+        assert_eq!(snapshot["references"].as_array().unwrap().len(), 1);
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use rusqlite::{params, Connection, Result as SqlResult};
 
 use crate::store::GraphStore;
@@ -215,6 +217,219 @@ impl SqliteGraphStore {
         node
     }
 
+    fn load_endpoints_batch(&self, node_ids: &[u64]) -> HashMap<u64, Vec<ExternalEndpoint>> {
+        let mut grouped: HashMap<u64, Vec<ExternalEndpoint>> = HashMap::new();
+        if node_ids.is_empty() {
+            return grouped;
+        }
+
+        let placeholders = vec!["?"; node_ids.len()].join(",");
+        let query = format!(
+            "SELECT node_id, kind, method, path, direction FROM external_endpoints WHERE node_id IN ({placeholders})"
+        );
+        let Ok(mut stmt) = self.conn.prepare(&query) else {
+            return grouped;
+        };
+        let params: Vec<&dyn rusqlite::ToSql> = node_ids
+            .iter()
+            .map(|id| id as &dyn rusqlite::ToSql)
+            .collect();
+        let rows = stmt.query_map(params.as_slice(), |row| {
+            let node_id: u64 = row.get(0)?;
+            Ok((
+                node_id,
+                ExternalEndpoint {
+                    kind: row.get(1)?,
+                    method: row.get(2)?,
+                    path: row.get(3)?,
+                    direction: row.get(4)?,
+                },
+            ))
+        });
+        if let Ok(rows) = rows {
+            for (node_id, endpoint) in rows.filter_map(|r| r.ok()) {
+                grouped.entry(node_id).or_default().push(endpoint);
+            }
+        }
+        grouped
+    }
+
+    fn load_previous_hashes_batch(&self, node_ids: &[u64]) -> HashMap<u64, Vec<String>> {
+        let mut grouped: HashMap<u64, Vec<String>> = HashMap::new();
+        if node_ids.is_empty() {
+            return grouped;
+        }
+
+        let placeholders = vec!["?"; node_ids.len()].join(",");
+        let query = format!(
+            "SELECT node_id, hash FROM previous_hashes WHERE node_id IN ({placeholders}) ORDER BY created_at DESC"
+        );
+        let Ok(mut stmt) = self.conn.prepare(&query) else {
+            return grouped;
+        };
+        let params: Vec<&dyn rusqlite::ToSql> = node_ids
+            .iter()
+            .map(|id| id as &dyn rusqlite::ToSql)
+            .collect();
+        let rows = stmt.query_map(params.as_slice(), |row| {
+            let node_id: u64 = row.get(0)?;
+            let hash: String = row.get(1)?;
+            Ok((node_id, hash))
+        });
+        if let Ok(rows) = rows {
+            for (node_id, hash) in rows.filter_map(|r| r.ok()) {
+                // Keep only last 3, matching `load_previous_hashes`'s LIMIT 3.
+                let entry = grouped.entry(node_id).or_default();
+                if entry.len() < 3 {
+                    entry.push(hash);
+                }
+            }
+        }
+        grouped
+    }
+
+    /// Resolve many hashes to their fully-populated nodes (endpoints and
+    /// previous-hashes included) in a fixed number of queries, instead of
+    /// the per-node queries `get_node` would cost if called once per hash.
+    /// Unknown hashes are simply absent from the returned map -- callers
+    /// report those as individual not-founds rather than failing the
+    /// whole batch.
+    pub fn nodes_with_relations_batch(&self, hashes: &[String]) -> HashMap<String, GraphNode> {
+        if hashes.is_empty() {
+            return HashMap::new();
+        }
+
+        let placeholders = vec!["?"; hashes.len()].join(",");
+        let query = format!("SELECT * FROM nodes WHERE hash IN ({placeholders})");
+        let Ok(mut stmt) = self.conn.prepare(&query) else {
+            return HashMap::new();
+        };
+        let params: Vec<&dyn rusqlite::ToSql> =
+            hashes.iter().map(|h| h as &dyn rusqlite::ToSql).collect();
+        let nodes: Vec<GraphNode> = match stmt.query_map(params.as_slice(), Self::row_to_node) {
+            Ok(rows) => rows.filter_map(|r| r.ok()).collect(),
+            Err(_) => return HashMap::new(),
+        };
+        drop(stmt);
+
+        if nodes.is_empty() {
+            return HashMap::new();
+        }
+
+        let ids: Vec<u64> = nodes.iter().map(|n| n.id).collect();
+        let mut endpoints_by_node = self.load_endpoints_batch(&ids);
+        let mut previous_hashes_by_node = self.load_previous_hashes_batch(&ids);
+
+        nodes
+            .into_iter()
+            .map(|mut node| {
+                node.external_endpoints = endpoints_by_node.remove(&node.id).unwrap_or_default();
+                node.previous_hashes = previous_hashes_by_node.remove(&node.id).unwrap_or_default();
+                (node.hash.clone(), node)
+            })
+            .collect()
+    }
+
+    /// Batched `get_node_by_id`: load many nodes (without relations -- see
+    /// `nodes_with_relations_batch` for that) in one query instead of one
+    /// per id. Used to resolve edge endpoints in bulk.
+    pub fn nodes_by_ids_batch(&self, ids: &[u64]) -> HashMap<u64, GraphNode> {
+        if ids.is_empty() {
+            return HashMap::new();
+        }
+
+        let placeholders = vec!["?"; ids.len()].join(",");
+        let query = format!("SELECT * FROM nodes WHERE id IN ({placeholders})");
+        let Ok(mut stmt) = self.conn.prepare(&query) else {
+            return HashMap::new();
+        };
+        let params: Vec<&dyn rusqlite::ToSql> =
+            ids.iter().map(|id| id as &dyn rusqlite::ToSql).collect();
+        match stmt.query_map(params.as_slice(), Self::row_to_node) {
+            Ok(rows) => rows.filter_map(|r| r.ok()).map(|n| (n.id, n)).collect(),
+            Err(_) => HashMap::new(),
+        }
+    }
+
+    /// Batched `get_edges`: load edges for many node ids in one query
+    /// instead of one query per id, grouped by the node id each edge is
+    /// keyed on for the requested `direction`.
+    pub fn edges_batch(
+        &self,
+        node_ids: &[u64],
+        direction: EdgeDirection,
+    ) -> HashMap<u64, Vec<GraphEdge>> {
+        let mut grouped: HashMap<u64, Vec<GraphEdge>> = HashMap::new();
+        if node_ids.is_empty() {
+            return grouped;
+        }
+
+        let placeholders = vec!["?"; node_ids.len()].join(",");
+        let query = match direction {
+            EdgeDirection::Incoming => {
+                format!("SELECT * FROM edges WHERE target_id IN ({placeholders})")
+            }
+            EdgeDirection::Outgoing => {
+                format!("SELECT * FROM edges WHERE source_id IN ({placeholders})")
+            }
+            EdgeDirection::Both => format!(
+                "SELECT * FROM edges WHERE source_id IN ({placeholders}) OR target_id IN ({placeholders})"
+            ),
+        };
+        let Ok(mut stmt) = self.conn.prepare(&query) else {
+            return grouped;
+        };
+
+        let mut query_params: Vec<&dyn rusqlite::ToSql> = Vec::with_capacity(node_ids.len() * 2);
+        for id in node_ids {
+            query_params.push(id);
+        }
+        if direction == EdgeDirection::Both {
+            for id in node_ids {
+                query_params.push(id);
+            }
+        }
+
+        let rows = stmt.query_map(query_params.as_slice(), |row| {
+            let kind_str: String = row.get("kind")?;
+            let kind = match kind_str.as_str() {
+                "calls" => EdgeKind::Calls,
+                "imports" => EdgeKind::Imports,
+                "inherits" => EdgeKind::Inherits,
+                "contains" => EdgeKind::Contains,
+                _ => EdgeKind::Calls,
+            };
+            Ok(GraphEdge {
+                id: row.get("id")?,
+                source_id: row.get("source_id")?,
+                target_id: row.get("target_id")?,
+                kind,
+                file_path: row.get("file_path")?,
+                line: row.get("line")?,
+                confidence: row.get("confidence").unwrap_or(1.0),
+            })
+        });
+
+        if let Ok(rows) = rows {
+            for edge in rows.filter_map(|r| r.ok()) {
+                let key = match direction {
+                    EdgeDirection::Incoming => edge.target_id,
+                    EdgeDirection::Outgoing => edge.source_id,
+                    EdgeDirection::Both => {
+                        if node_ids.contains(&edge.target_id) {
+                            edge.target_id
+                        } else {
+                            edge.source_id
+                        }
+                    }
+                };
+                grouped.entry(key).or_default().push(edge);
+            }
+        }
+
+        grouped
+    }
+
     pub fn insert_node(&self, node: &GraphNode) -> Result<(), GraphError> {
         self.conn.execute(
             "INSERT OR REPLACE INTO nodes (id, hash, kind, name, signature, file_path, line_start, line_end, docstring, is_public, type_hints_present, has_docstring, module_id)
@@ -535,6 +750,7 @@ mod tests {
             external_endpoints: vec![],
             previous_hashes: vec![],
             module_id: 0,
+            package: None,
         }
     }
 
@@ -645,6 +861,70 @@ mod tests {
         assert_eq!(store.get_edges(1, EdgeDirection::Outgoing).len(), 1);
     }
 
+    #[test]
+    fn test_nodes_with_relations_batch_loads_multiple_hashes() {
+        let mut store = SqliteGraphStore::in_memory().unwrap();
+        let n1 = test_node(1, "aaa12345678", "one");
+        let n2 = test_node(2, "bbb12345678", "two");
+        store.update_nodes(vec![NodeChange::Add(n1), NodeChange::Add(n2)]).unwrap();
+
+        let batch = store.nodes_with_relations_batch(&[
+            "aaa12345678".to_string(),
+            "bbb12345678".to_string(),
+        ]);
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch["aaa12345678"].name, "one");
+        assert_eq!(batch["bbb12345678"].name, "two");
+    }
+
+    #[test]
+    fn test_nodes_with_relations_batch_omits_unknown_hashes() {
+        let mut store = SqliteGraphStore::in_memory().unwrap();
+        let n1 = test_node(1, "aaa12345678", "one");
+        store.update_nodes(vec![NodeChange::Add(n1)]).unwrap();
+
+        let batch = store.nodes_with_relations_batch(&[
+            "aaa12345678".to_string(),
+            "nonexistent".to_string(),
+        ]);
+        assert_eq!(batch.len(), 1);
+        assert!(batch.contains_key("aaa12345678"));
+    }
+
+    #[test]
+    fn test_edges_batch_groups_by_requested_direction() {
+        let mut store = SqliteGraphStore::in_memory().unwrap();
+        let n1 = test_node(1, "aaa12345678", "caller");
+        let n2 = test_node(2, "bbb12345678", "callee");
+        store.update_nodes(vec![NodeChange::Add(n1), NodeChange::Add(n2)]).unwrap();
+        let edge = GraphEdge {
+            id: 1, source_id: 1, target_id: 2, kind: EdgeKind::Calls,
+            file_path: "src/test.rs".to_string(), line: 5, confidence: 1.0,
+        };
+        store.update_edges(vec![EdgeChange::Add(edge)]).unwrap();
+
+        let outgoing = store.edges_batch(&[1, 2], EdgeDirection::Outgoing);
+        assert_eq!(outgoing.get(&1).map(|v| v.len()), Some(1));
+        assert!(outgoing.get(&2).is_none());
+
+        let incoming = store.edges_batch(&[1, 2], EdgeDirection::Incoming);
+        assert_eq!(incoming.get(&2).map(|v| v.len()), Some(1));
+        assert!(incoming.get(&1).is_none());
+    }
+
+    #[test]
+    fn test_nodes_by_ids_batch_loads_multiple_ids() {
+        let mut store = SqliteGraphStore::in_memory().unwrap();
+        let n1 = test_node(1, "aaa12345678", "one");
+        let n2 = test_node(2, "bbb12345678", "two");
+        store.update_nodes(vec![NodeChange::Add(n1), NodeChange::Add(n2)]).unwrap();
+
+        let batch = store.nodes_by_ids_batch(&[1, 2]);
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch[&1].name, "one");
+        assert_eq!(batch[&2].name, "two");
+    }
+
     #[test]
     fn test_hash_collision_different_names_still_errors() {
         let mut store = SqliteGraphStore::in_memory().unwrap();
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use rusqlite::params;
 
 use crate::sqlite::SqliteGraphStore;
@@ -370,4 +372,22 @@ impl GraphStore for SqliteGraphStore {
             }
         }
     }
+
+    // Override the default (N-query) batch methods with the batched
+    // versions (a fixed number of queries, whatever the input size) already defined as inherent methods in `sqlite.rs`.
+    fn nodes_with_relations_batch(&self, hashes: &[String]) -> HashMap<String, GraphNode> {
+        SqliteGraphStore::nodes_with_relations_batch(self, hashes)
+    }
+
+    fn nodes_by_ids_batch(&self, ids: &[u64]) -> HashMap<u64, GraphNode> {
+        SqliteGraphStore::nodes_by_ids_batch(self, ids)
+    }
+
+    fn edges_batch(
+        &self,
+        node_ids: &[u64],
+        direction: EdgeDirection,
+    ) -> HashMap<u64, Vec<GraphEdge>> {
+        SqliteGraphStore::edges_batch(self, node_ids, direction)
+    }
 }
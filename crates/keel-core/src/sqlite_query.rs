@@ -0,0 +1,154 @@
+//! Raw tuple access for the datalog-style query evaluator in
+//! `keel_enforce::query`. Each method here evaluates one predicate --
+//! `node/4`, `calls/3`, `in_module/2` -- against SQLite directly, translating
+//! bound arguments (`Some`) into `WHERE` conditions and leaving unbound
+//! arguments (`None`) as wildcard columns. Unlike the rest of this crate's
+//! query surface, results are plain string tuples rather than `GraphNode`s:
+//! the evaluator only needs column values to bind and join on, not full
+//! node objects.
+
+use crate::sqlite::SqliteGraphStore;
+
+impl SqliteGraphStore {
+    /// Evaluate `node(Hash, Name, File, Kind)`.
+    pub fn query_node_tuples(
+        &self,
+        hash: Option<&str>,
+        name: Option<&str>,
+        file: Option<&str>,
+        kind: Option<&str>,
+    ) -> Vec<[String; 4]> {
+        let mut conditions = Vec::new();
+        let mut params: Vec<&dyn rusqlite::ToSql> = Vec::new();
+        if let Some(h) = &hash {
+            conditions.push("hash = ?");
+            params.push(h);
+        }
+        if let Some(n) = &name {
+            conditions.push("name = ?");
+            params.push(n);
+        }
+        if let Some(f) = &file {
+            conditions.push("file_path = ?");
+            params.push(f);
+        }
+        if let Some(k) = &kind {
+            conditions.push("kind = ?");
+            params.push(k);
+        }
+
+        let sql = if conditions.is_empty() {
+            "SELECT hash, name, file_path, kind FROM nodes".to_string()
+        } else {
+            format!(
+                "SELECT hash, name, file_path, kind FROM nodes WHERE {}",
+                conditions.join(" AND ")
+            )
+        };
+
+        let mut stmt = match self.conn.prepare(&sql) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("[keel] query_node_tuples: prepare failed: {e}");
+                return Vec::new();
+            }
+        };
+        match stmt.query_map(params.as_slice(), |row| {
+            Ok([row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?])
+        }) {
+            Ok(rows) => rows.filter_map(|r| r.ok()).collect(),
+            Err(e) => {
+                eprintln!("[keel] query_node_tuples: query failed: {e}");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Evaluate `calls(Caller, Callee, Line)`. `Caller`/`Callee` are hashes,
+    /// translated from `edges.source_id`/`target_id` via a join against
+    /// `nodes` so they unify with the `Hash` column of `node/4`.
+    pub fn query_calls_tuples(
+        &self,
+        caller: Option<&str>,
+        callee: Option<&str>,
+    ) -> Vec<(String, String, u32)> {
+        let mut conditions = vec!["e.kind = 'calls'".to_string()];
+        let mut params: Vec<&dyn rusqlite::ToSql> = Vec::new();
+        if let Some(c) = &caller {
+            conditions.push("sn.hash = ?".to_string());
+            params.push(c);
+        }
+        if let Some(c) = &callee {
+            conditions.push("tn.hash = ?".to_string());
+            params.push(c);
+        }
+
+        let sql = format!(
+            "SELECT sn.hash, tn.hash, e.line FROM edges e
+             JOIN nodes sn ON sn.id = e.source_id
+             JOIN nodes tn ON tn.id = e.target_id
+             WHERE {}",
+            conditions.join(" AND ")
+        );
+
+        let mut stmt = match self.conn.prepare(&sql) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("[keel] query_calls_tuples: prepare failed: {e}");
+                return Vec::new();
+            }
+        };
+        match stmt.query_map(params.as_slice(), |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        }) {
+            Ok(rows) => rows.filter_map(|r| r.ok()).collect(),
+            Err(e) => {
+                eprintln!("[keel] query_calls_tuples: query failed: {e}");
+                Vec::new()
+            }
+        }
+    }
+
+    /// Evaluate `in_module(Hash, ModuleId)`. Nodes with no module (`module_id`
+    /// is `NULL`, i.e. stored as `0`) are excluded -- the predicate only
+    /// relates nodes that actually belong to a module.
+    pub fn query_in_module_tuples(
+        &self,
+        hash: Option<&str>,
+        module_id: Option<&str>,
+    ) -> Vec<(String, String)> {
+        let mut conditions = vec!["module_id IS NOT NULL".to_string()];
+        let mut params: Vec<&dyn rusqlite::ToSql> = Vec::new();
+        if let Some(h) = &hash {
+            conditions.push("hash = ?".to_string());
+            params.push(h);
+        }
+        if let Some(m) = &module_id {
+            conditions.push("module_id = ?".to_string());
+            params.push(m);
+        }
+
+        let sql = format!(
+            "SELECT hash, module_id FROM nodes WHERE {}",
+            conditions.join(" AND ")
+        );
+
+        let mut stmt = match self.conn.prepare(&sql) {
+            Ok(s) => s,
+            Err(e) => {
+                eprintln!("[keel] query_in_module_tuples: prepare failed: {e}");
+                return Vec::new();
+            }
+        };
+        match stmt.query_map(params.as_slice(), |row| {
+            let module_id: u64 = row.get(1)?;
+            Ok((row.get::<_, String>(0)?, module_id.to_string()))
+        }) {
+            Ok(rows) => rows.filter_map(|r| r.ok()).collect(),
+            Err(e) => {
+                eprintln!("[keel] query_in_module_tuples: query failed: {e}");
+                Vec::new()
+            }
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use crate::types::{
     EdgeChange, EdgeDirection, GraphEdge, GraphError, GraphNode, ModuleProfile, NodeChange,
 };
@@ -41,4 +43,43 @@ pub trait GraphStore {
     /// Find nodes with the given name and kind, excluding a specific file.
     /// Used by W002 duplicate_name check.
     fn find_nodes_by_name(&self, name: &str, kind: &str, exclude_file: &str) -> Vec<GraphNode>;
+
+    // --- Batch extensions ---
+    //
+    // Additive to the frozen contract above: new methods with default
+    // implementations expressed in terms of the primitives above, so every
+    // existing implementor keeps compiling unchanged. A backend overrides
+    // one only to replace N round trips with a single batched query --
+    // the default here is correct, just not batched. This is what lets
+    // `keel-server` stay generic over `dyn GraphStore` (SQLite today,
+    // Postgres in `keel_core::postgres`) instead of hardcoding one backend.
+
+    /// Resolve many hashes to their nodes in one call instead of N.
+    /// Unknown hashes are simply absent from the returned map.
+    fn nodes_with_relations_batch(&self, hashes: &[String]) -> HashMap<String, GraphNode> {
+        hashes
+            .iter()
+            .filter_map(|h| self.get_node(h).map(|n| (h.clone(), n)))
+            .collect()
+    }
+
+    /// Resolve many node IDs to their nodes in one call instead of N.
+    fn nodes_by_ids_batch(&self, ids: &[u64]) -> HashMap<u64, GraphNode> {
+        ids.iter()
+            .filter_map(|&id| self.get_node_by_id(id).map(|n| (id, n)))
+            .collect()
+    }
+
+    /// Load edges for many node IDs in one call instead of N, grouped by
+    /// the node ID each edge is keyed on for the requested `direction`.
+    fn edges_batch(
+        &self,
+        node_ids: &[u64],
+        direction: EdgeDirection,
+    ) -> HashMap<u64, Vec<GraphEdge>> {
+        node_ids
+            .iter()
+            .map(|&id| (id, self.get_edges(id, direction)))
+            .collect()
+    }
 }
@@ -0,0 +1,197 @@
+//! FST-backed index from symbol name to node id, built the way
+//! rust-analyzer's `import_map` builds its import index: collect every
+//! `(name, id)` pair, sort names lexicographically, and stream them into
+//! an [`fst::MapBuilder`]. The resulting [`fst::Map`] answers exact,
+//! prefix, and fuzzy (edit-distance) queries in time proportional to the
+//! query rather than the number of symbols, which matters once a graph or
+//! a Go package's import set grows into the thousands.
+//!
+//! Names are matched case-insensitively: the fst key is the lowercased
+//! name, and the original spelling plus every id that normalizes to it
+//! live in a side table keyed by the fst's output value (`MapBuilder`
+//! requires unique, sorted keys, so duplicate normalized names collapse
+//! into one fst entry with a multi-id side table slot).
+
+use std::collections::BTreeMap;
+
+use fst::automaton::{Automaton, Levenshtein, Str};
+use fst::{IntoStreamer, Map, MapBuilder, Streamer};
+
+use crate::store::GraphStore;
+
+/// One normalized-name bucket: its original-case spelling and every node
+/// id that shares it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "rkyv-snapshot",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
+pub struct SymbolMatch {
+    pub name: String,
+    pub ids: Vec<u64>,
+}
+
+/// A queryable `name -> node ids` index over an FST.
+pub struct SymbolIndex {
+    map: Map<Vec<u8>>,
+    // Side table: fst output value -> bucket. Populated in the same sorted
+    // order the keys were inserted, so the value doubles as the index.
+    entries: Vec<SymbolMatch>,
+}
+
+impl SymbolIndex {
+    /// Build an index over every node in `store`, walking modules the same
+    /// way [`crate::snapshot::build_graph_snapshot`] does.
+    pub fn from_store(store: &dyn GraphStore) -> Self {
+        let modules = store.get_all_modules();
+        let mut pairs = Vec::new();
+        for module in &modules {
+            let mut nodes = store.get_nodes_in_file(&module.file_path);
+            if !nodes.iter().any(|n| n.id == module.id) {
+                nodes.push(module.clone());
+            }
+            pairs.extend(nodes.into_iter().map(|n| (n.name, n.id)));
+        }
+        Self::build(pairs)
+    }
+
+    /// Build an index from explicit `(name, id)` pairs.
+    pub fn build(pairs: Vec<(String, u64)>) -> Self {
+        let mut buckets: BTreeMap<String, SymbolMatch> = BTreeMap::new();
+        for (name, id) in pairs {
+            let key = name.to_lowercase();
+            let bucket = buckets.entry(key).or_insert_with(|| SymbolMatch {
+                name,
+                ids: Vec::new(),
+            });
+            bucket.ids.push(id);
+        }
+
+        // BTreeMap iterates keys in sorted order, which is exactly what
+        // MapBuilder::insert requires.
+        let mut builder = MapBuilder::memory();
+        let mut entries = Vec::with_capacity(buckets.len());
+        for (key, bucket) in buckets {
+            builder
+                .insert(&key, entries.len() as u64)
+                .expect("BTreeMap yields keys in sorted order");
+            entries.push(bucket);
+        }
+        let map = builder.into_map().expect("in-memory fst build cannot fail");
+
+        SymbolIndex { map, entries }
+    }
+
+    /// Exact, case-insensitive lookup.
+    pub fn lookup(&self, name: &str) -> Option<&SymbolMatch> {
+        let value = self.map.get(name.to_lowercase())?;
+        self.entries.get(value as usize)
+    }
+
+    /// All entries whose normalized name starts with `prefix`.
+    pub fn prefix(&self, prefix: &str) -> Vec<&SymbolMatch> {
+        let automaton = Str::new(&prefix.to_lowercase()).starts_with();
+        self.search(automaton)
+    }
+
+    /// All entries within `max_distance` edits of `name` (Damerau-free
+    /// Levenshtein, as implemented by `fst::automaton::Levenshtein`). Used
+    /// for `keel discover`'s "did you mean" suggestions.
+    pub fn fuzzy(&self, name: &str, max_distance: u32) -> Vec<&SymbolMatch> {
+        let automaton = match Levenshtein::new(&name.to_lowercase(), max_distance) {
+            Ok(a) => a,
+            Err(_) => return Vec::new(),
+        };
+        self.search(automaton)
+    }
+
+    fn search<A: Automaton>(&self, automaton: A) -> Vec<&SymbolMatch> {
+        let mut stream = self.map.search(automaton).into_stream();
+        let mut out = Vec::new();
+        while let Some((_, value)) = stream.next() {
+            if let Some(bucket) = self.entries.get(value as usize) {
+                out.push(bucket);
+            }
+        }
+        out
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The FST's raw, already-serialized byte layout -- rebuilding a
+    /// [`Map`] from these via [`Self::from_raw_parts`] is O(1) (no
+    /// streaming re-insert), which is what makes
+    /// [`crate::index_cache::IndexCache`] a warm-start win over
+    /// [`Self::from_store`]. Used only by that cache writer.
+    #[cfg(feature = "rkyv-snapshot")]
+    pub(crate) fn as_fst_bytes(&self) -> &[u8] {
+        self.map.as_fst().as_bytes()
+    }
+
+    /// The name -> ids side table, for the cache writer to archive
+    /// alongside the FST bytes.
+    #[cfg(feature = "rkyv-snapshot")]
+    pub(crate) fn entries(&self) -> &[SymbolMatch] {
+        &self.entries
+    }
+
+    /// Rebuild an index from a previously-serialized FST byte layout and
+    /// side table, skipping the sort-and-stream build `Self::build` does.
+    #[cfg(feature = "rkyv-snapshot")]
+    pub(crate) fn from_raw_parts(
+        fst_bytes: Vec<u8>,
+        entries: Vec<SymbolMatch>,
+    ) -> Result<Self, fst::Error> {
+        Ok(SymbolIndex {
+            map: Map::new(fst_bytes)?,
+            entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn index() -> SymbolIndex {
+        SymbolIndex::build(vec![
+            ("GetUser".to_string(), 1),
+            ("getUser".to_string(), 2),
+            ("GetUsers".to_string(), 3),
+            ("SetUser".to_string(), 4),
+        ])
+    }
+
+    #[test]
+    fn exact_lookup_is_case_insensitive_and_collects_duplicates() {
+        let idx = index();
+        let m = idx.lookup("getuser").unwrap();
+        assert_eq!(m.ids.len(), 2);
+        assert!(m.ids.contains(&1) && m.ids.contains(&2));
+        assert!(idx.lookup("nope").is_none());
+    }
+
+    #[test]
+    fn prefix_matches_all_sharing_a_prefix() {
+        let idx = index();
+        let mut names: Vec<&str> =
+            idx.prefix("getuser").iter().map(|m| m.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["GetUser", "GetUsers"]);
+    }
+
+    #[test]
+    fn fuzzy_matches_within_edit_distance() {
+        let idx = index();
+        let hits = idx.fuzzy("getusr", 1);
+        assert!(hits.iter().any(|m| m.name == "GetUser"));
+        assert!(!hits.iter().any(|m| m.name == "SetUser"));
+    }
+}
@@ -52,6 +52,200 @@ pub struct TelemetryAggregate {
     pub language_percentages: HashMap<String, f64>,
     pub top_error_codes: HashMap<String, u64>,
     pub agent_stats: HashMap<String, AgentStats>,
+    pub latency_regressions: Vec<LatencyRegression>,
+    pub error_clusters: Vec<ErrorCluster>,
+}
+
+/// A command whose recent `duration_ms` mean has drifted well above its
+/// own baseline -- see [`detect_latency_regressions`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LatencyRegression {
+    pub command: String,
+    pub baseline_ms: f64,
+    pub recent_ms: f64,
+    pub z_score: f64,
+}
+
+/// A set of error codes that tend to appear together in the same event --
+/// see [`cluster_error_codes`].
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ErrorCluster {
+    pub codes: Vec<String>,
+    /// The weakest (lowest) pairwise co-occurrence count backing this
+    /// cluster -- how many events support its least-supported pair.
+    pub support: u64,
+}
+
+/// Minimum number of baseline samples a command needs before it's even
+/// considered for regression detection -- below this, mean/stddev are too
+/// noisy to trust.
+const REGRESSION_MIN_BASELINE_SAMPLES: usize = 5;
+
+/// How many of a command's most recent events form the "recent" window
+/// that gets compared against everything before it (the baseline).
+const REGRESSION_RECENT_WINDOW: usize = 5;
+
+/// Recent mean must exceed `baseline_mean + k * baseline_stddev` to flag a
+/// regression.
+const REGRESSION_K: f64 = 3.0;
+
+/// Jaccard-like co-occurrence score above which two error codes are
+/// merged into the same cluster.
+const CLUSTER_SCORE_THRESHOLD: f64 = 0.5;
+
+/// For each command with enough history, split its `duration_ms` samples
+/// (oldest first) into a baseline window and a trailing recent window,
+/// and flag it when the recent mean exceeds `baseline_mean + k *
+/// baseline_stddev`.
+fn detect_latency_regressions(durations_by_command: &HashMap<String, Vec<u64>>) -> Vec<LatencyRegression> {
+    let mut regressions = Vec::new();
+    for (command, durations) in durations_by_command {
+        if durations.len() <= REGRESSION_RECENT_WINDOW {
+            continue;
+        }
+        let split = durations.len() - REGRESSION_RECENT_WINDOW;
+        let baseline = &durations[..split];
+        let recent = &durations[split..];
+        if baseline.len() < REGRESSION_MIN_BASELINE_SAMPLES {
+            continue;
+        }
+
+        let baseline_mean = mean(baseline);
+        let baseline_stddev = stddev(baseline, baseline_mean);
+        let recent_mean = mean(recent);
+
+        let threshold = baseline_mean + REGRESSION_K * baseline_stddev;
+        if recent_mean > threshold {
+            let z_score = if baseline_stddev > 0.0 {
+                (recent_mean - baseline_mean) / baseline_stddev
+            } else {
+                f64::MAX
+            };
+            regressions.push(LatencyRegression {
+                command: command.clone(),
+                baseline_ms: baseline_mean,
+                recent_ms: recent_mean,
+                z_score,
+            });
+        }
+    }
+    regressions.sort_by(|a, b| b.z_score.partial_cmp(&a.z_score).unwrap_or(std::cmp::Ordering::Equal));
+    regressions
+}
+
+fn mean(samples: &[u64]) -> f64 {
+    samples.iter().map(|&d| d as f64).sum::<f64>() / samples.len() as f64
+}
+
+fn stddev(samples: &[u64], mean: f64) -> f64 {
+    let variance = samples
+        .iter()
+        .map(|&d| {
+            let diff = d as f64 - mean;
+            diff * diff
+        })
+        .sum::<f64>()
+        / samples.len() as f64;
+    variance.sqrt()
+}
+
+/// Build a symmetric code-pair co-occurrence matrix from each event's
+/// `error_codes`, then greedily union-find codes together whenever their
+/// Jaccard-like score (co-occurrence / union of individual frequencies)
+/// exceeds [`CLUSTER_SCORE_THRESHOLD`]. Singleton groups (a code that
+/// never clusters with anything) are dropped.
+fn cluster_error_codes(events_codes: &[HashMap<String, u32>]) -> Vec<ErrorCluster> {
+    let mut code_freq: HashMap<String, u64> = HashMap::new();
+    let mut co_occurrence: HashMap<(String, String), u64> = HashMap::new();
+
+    for codes in events_codes {
+        let present: Vec<&String> = codes.keys().collect();
+        for code in &present {
+            *code_freq.entry((*code).clone()).or_default() += 1;
+        }
+        for i in 0..present.len() {
+            for j in (i + 1)..present.len() {
+                let key = pair_key(present[i], present[j]);
+                *co_occurrence.entry(key).or_default() += 1;
+            }
+        }
+    }
+
+    let mut parent: HashMap<String, String> = code_freq.keys().map(|c| (c.clone(), c.clone())).collect();
+
+    let mut pairs: Vec<(&(String, String), &u64)> = co_occurrence.iter().collect();
+    // Merge strongest co-occurrences first so the union-find settles on
+    // the most tightly-bound grouping rather than an arbitrary one.
+    pairs.sort_by(|a, b| b.1.cmp(a.1));
+
+    for ((a, b), &count) in pairs {
+        let freq_a = *code_freq.get(a).unwrap_or(&0) as f64;
+        let freq_b = *code_freq.get(b).unwrap_or(&0) as f64;
+        let union = freq_a + freq_b - count as f64;
+        if union <= 0.0 {
+            continue;
+        }
+        let score = count as f64 / union;
+        if score > CLUSTER_SCORE_THRESHOLD {
+            union_codes(&mut parent, a, b);
+        }
+    }
+
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+    let codes: Vec<String> = code_freq.keys().cloned().collect();
+    for code in &codes {
+        let root = find_root(&mut parent, code);
+        groups.entry(root).or_default().push(code.clone());
+    }
+
+    let mut clusters: Vec<ErrorCluster> = groups
+        .into_values()
+        .filter(|codes| codes.len() > 1)
+        .map(|mut codes| {
+            codes.sort();
+            let support = codes
+                .iter()
+                .enumerate()
+                .flat_map(|(i, a)| codes[(i + 1)..].iter().map(move |b| pair_key(a, b)))
+                .filter_map(|key| co_occurrence.get(&key).copied())
+                .min()
+                .unwrap_or(0);
+            ErrorCluster { codes, support }
+        })
+        .collect();
+    clusters.sort_by(|a, b| b.support.cmp(&a.support));
+    clusters
+}
+
+fn pair_key(a: &str, b: &str) -> (String, String) {
+    if a < b {
+        (a.to_string(), b.to_string())
+    } else {
+        (b.to_string(), a.to_string())
+    }
+}
+
+fn find_root(parent: &mut HashMap<String, String>, code: &str) -> String {
+    let mut root = code.to_string();
+    while parent.get(&root).is_some_and(|p| p != &root) {
+        root = parent[&root].clone();
+    }
+    // Path compression.
+    let mut cur = code.to_string();
+    while cur != root {
+        let next = parent[&cur].clone();
+        parent.insert(cur, root.clone());
+        cur = next;
+    }
+    root
+}
+
+fn union_codes(parent: &mut HashMap<String, String>, a: &str, b: &str) {
+    let root_a = find_root(parent, a);
+    let root_b = find_root(parent, b);
+    if root_a != root_b {
+        parent.insert(root_a, root_b);
+    }
 }
 
 /// SQLite-backed telemetry store (separate from graph.db).
@@ -285,6 +479,37 @@ impl TelemetryStore {
             }
         }
 
+        // Per-command duration history (chronological), for regression detection.
+        let mut durations_by_command: HashMap<String, Vec<u64>> = HashMap::new();
+        let mut duration_stmt = self.conn.prepare(&format!(
+            "SELECT command, duration_ms FROM events WHERE timestamp >= {cutoff} ORDER BY id ASC"
+        ))?;
+        let duration_rows = duration_stmt.query_map([], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, u64>(1)?))
+        })?;
+        for row in duration_rows {
+            let (command, duration_ms) = row?;
+            durations_by_command.entry(command).or_default().push(duration_ms);
+        }
+        let latency_regressions = detect_latency_regressions(&durations_by_command);
+
+        // Per-event error-code sets, for co-occurrence clustering.
+        let mut all_codes_stmt = self.conn.prepare(&format!(
+            "SELECT error_codes FROM events WHERE timestamp >= {cutoff}"
+        ))?;
+        let mut events_codes: Vec<HashMap<String, u32>> = Vec::new();
+        let all_codes_rows = all_codes_stmt.query_map([], |row| row.get::<_, Option<String>>(0))?;
+        for row in all_codes_rows {
+            if let Some(json_str) = row? {
+                if let Ok(map) = serde_json::from_str::<HashMap<String, u32>>(&json_str) {
+                    if !map.is_empty() {
+                        events_codes.push(map);
+                    }
+                }
+            }
+        }
+        let error_clusters = cluster_error_codes(&events_codes);
+
         // Agent stats aggregation
         let mut agent_stats: HashMap<String, AgentStats> = HashMap::new();
         let mut agent_stmt = self.conn.prepare(&format!(
@@ -325,6 +550,8 @@ impl TelemetryStore {
             language_percentages,
             top_error_codes,
             agent_stats,
+            latency_regressions,
+            error_clusters,
         })
     }
 
@@ -143,6 +143,66 @@ fn test_chrono_utc_now_format() {
     assert_eq!(&ts[10..11], " ");
 }
 
+#[test]
+fn test_latency_regression_detected() {
+    let store = TelemetryStore::in_memory().unwrap();
+    // Baseline: a stable compile time around 100ms.
+    for _ in 0..8 {
+        store.record(&make_event("compile", 100, 0)).unwrap();
+    }
+    // Recent window: compile time has spiked well past baseline mean + 3*stddev.
+    for _ in 0..5 {
+        store.record(&make_event("compile", 900, 0)).unwrap();
+    }
+
+    let agg = store.aggregate(30).unwrap();
+    assert_eq!(agg.latency_regressions.len(), 1);
+    let regression = &agg.latency_regressions[0];
+    assert_eq!(regression.command, "compile");
+    assert!((regression.baseline_ms - 100.0).abs() < 1.0);
+    assert!((regression.recent_ms - 900.0).abs() < 1.0);
+    assert!(regression.z_score > 3.0);
+}
+
+#[test]
+fn test_latency_regression_needs_enough_baseline_samples() {
+    let store = TelemetryStore::in_memory().unwrap();
+    // Too few baseline samples to trust a mean/stddev -- should not flag.
+    for _ in 0..2 {
+        store.record(&make_event("map", 100, 0)).unwrap();
+    }
+    for _ in 0..5 {
+        store.record(&make_event("map", 900, 0)).unwrap();
+    }
+
+    let agg = store.aggregate(30).unwrap();
+    assert!(agg.latency_regressions.is_empty());
+}
+
+#[test]
+fn test_error_cluster_co_occurrence() {
+    let store = TelemetryStore::in_memory().unwrap();
+    // E001 and E002 always fire together -- should cluster.
+    for _ in 0..4 {
+        let mut event = new_event("check", 50, 1);
+        event.error_codes.insert("E001".to_string(), 1);
+        event.error_codes.insert("E002".to_string(), 1);
+        store.record(&event).unwrap();
+    }
+    // E003 fires alone every time -- should not cluster with anything.
+    for _ in 0..4 {
+        let mut event = new_event("check", 50, 1);
+        event.error_codes.insert("E003".to_string(), 1);
+        store.record(&event).unwrap();
+    }
+
+    let agg = store.aggregate(30).unwrap();
+    assert_eq!(agg.error_clusters.len(), 1);
+    let cluster = &agg.error_clusters[0];
+    assert_eq!(cluster.codes, vec!["E001".to_string(), "E002".to_string()]);
+    assert_eq!(cluster.support, 4);
+}
+
 #[test]
 fn test_file_based_store() {
     let dir = tempfile::tempdir().unwrap();
@@ -3,6 +3,11 @@ use serde::{Deserialize, Serialize};
 /// Node types in the structural graph.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(
+    feature = "rkyv-snapshot",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub enum NodeKind {
     Module,
     Class,
@@ -29,6 +34,11 @@ impl std::fmt::Display for NodeKind {
 /// Edge types between graph nodes.
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(
+    feature = "rkyv-snapshot",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub enum EdgeKind {
     Calls,
     Imports,
@@ -56,6 +66,11 @@ impl std::fmt::Display for EdgeKind {
 
 /// A node in the structural graph (function, class, or module).
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv-snapshot",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct GraphNode {
     pub id: u64,
     pub hash: String,
@@ -77,6 +92,11 @@ pub struct GraphNode {
 
 /// An external endpoint (HTTP, gRPC, GraphQL, etc.) associated with a function.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv-snapshot",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct ExternalEndpoint {
     pub kind: String,
     pub method: String,
@@ -86,6 +106,11 @@ pub struct ExternalEndpoint {
 
 /// An edge in the structural graph.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv-snapshot",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct GraphEdge {
     pub id: u64,
     pub source_id: u64,
@@ -106,6 +131,11 @@ fn default_edge_confidence() -> f64 {
 
 /// Module responsibility profile for placement scoring.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "rkyv-snapshot",
+    derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize),
+    archive(check_bytes)
+)]
 pub struct ModuleProfile {
     pub module_id: u64,
     pub path: String,
@@ -179,3 +209,17 @@ impl From<rusqlite::Error> for GraphError {
         GraphError::Database(e.to_string())
     }
 }
+
+#[cfg(feature = "postgres")]
+impl From<postgres::Error> for GraphError {
+    fn from(e: postgres::Error) -> Self {
+        GraphError::Database(e.to_string())
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<r2d2::Error> for GraphError {
+    fn from(e: r2d2::Error) -> Self {
+        GraphError::Database(e.to_string())
+    }
+}
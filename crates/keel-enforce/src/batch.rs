@@ -1,5 +1,9 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
 use std::time::{Duration, Instant};
 
+use serde::{Deserialize, Serialize};
+
 use crate::types::Violation;
 
 /// Codes that are deferrable in batch mode.
@@ -57,6 +61,15 @@ impl BatchState {
         self.deferred
     }
 
+    /// Remove and return the deferred violations accumulated so far,
+    /// without ending batch mode. The in-memory `BatchState` doesn't
+    /// survive a process exiting, so the CLI calls this after every
+    /// `keel compile <file>` to persist the batch's progress to the
+    /// on-disk `BatchJournal` before the process goes away.
+    pub fn take_deferred(&mut self) -> Vec<Violation> {
+        std::mem::take(&mut self.deferred)
+    }
+
     /// Number of deferred violations.
     pub fn deferred_count(&self) -> usize {
         self.deferred.len()
@@ -72,6 +85,132 @@ impl BatchState {
     }
 }
 
+/// Schema version for the on-disk batch journal. Bumped whenever the
+/// format changes; a journal written by a different version is treated the
+/// same as a corrupt one -- discarded with a warning rather than crashing.
+const JOURNAL_VERSION: u32 = 1;
+
+/// One append-only entry: the files a single `keel compile <file>`
+/// invocation checked during an active batch, and the violations it
+/// deferred.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub files: Vec<String>,
+    pub violations: Vec<Violation>,
+}
+
+/// Durable, crash-safe record of an in-progress batch, persisted to
+/// `.keel/batch_journal.json` so deferred violations survive across the
+/// separate process invocations that make up a `--batch-start` /
+/// `keel compile <file>` / ... / `--batch-end` sequence. `--batch-start`
+/// creates it, every intermediate compile appends an entry via `record`,
+/// and `--batch-end` reads it, deduplicates, and deletes it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchJournal {
+    version: u32,
+    pub started_at_unix_ms: u64,
+    pub entries: Vec<JournalEntry>,
+}
+
+impl BatchJournal {
+    fn path(keel_dir: &Path) -> PathBuf {
+        keel_dir.join("batch_journal.json")
+    }
+
+    /// Start a new batch, overwriting any previous journal.
+    pub fn start(keel_dir: &Path) -> Result<(), String> {
+        let journal = BatchJournal {
+            version: JOURNAL_VERSION,
+            started_at_unix_ms: now_unix_ms(),
+            entries: Vec::new(),
+        };
+        journal.save(keel_dir)
+    }
+
+    /// Load the journal, if one exists and is well-formed. A stale/corrupt
+    /// journal -- unreadable JSON, or written by a different schema
+    /// version -- is discarded with a warning and treated as "no active
+    /// batch" rather than surfaced as an error.
+    pub fn load(keel_dir: &Path) -> Option<Self> {
+        let path = Self::path(keel_dir);
+        let content = std::fs::read_to_string(&path).ok()?;
+        match serde_json::from_str::<BatchJournal>(&content) {
+            Ok(journal) if journal.version == JOURNAL_VERSION => Some(journal),
+            _ => {
+                eprintln!(
+                    "keel compile: discarding stale or corrupt batch journal at {}",
+                    path.display()
+                );
+                let _ = std::fs::remove_file(&path);
+                None
+            }
+        }
+    }
+
+    /// Append one invocation's worth of checked files and deferred
+    /// violations. Does not write to disk -- call `save` afterward.
+    pub fn record(&mut self, files: Vec<String>, violations: Vec<Violation>) {
+        self.entries.push(JournalEntry { files, violations });
+    }
+
+    /// Persist this journal to `.keel/batch_journal.json`. Written to a
+    /// `.tmp` sibling and renamed into place so a reader never observes a
+    /// half-written file -- a crash mid-write must not leave `load` with
+    /// truncated JSON it can only discard as corrupt.
+    pub fn save(&self, keel_dir: &Path) -> Result<(), String> {
+        let path = Self::path(keel_dir);
+        let json = serde_json::to_string(self)
+            .map_err(|e| format!("failed to serialize batch journal: {}", e))?;
+
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, json)
+            .map_err(|e| format!("failed to write batch journal to {}: {}", tmp_path.display(), e))?;
+        std::fs::rename(&tmp_path, &path)
+            .map_err(|e| format!("failed to finalize batch journal at {}: {}", path.display(), e))
+    }
+
+    /// Delete the journal from disk. Called once `--batch-end` consumes it.
+    pub fn delete(keel_dir: &Path) {
+        let _ = std::fs::remove_file(Self::path(keel_dir));
+    }
+
+    /// Distinct files that have contributed at least one entry.
+    pub fn files_queued(&self) -> Vec<String> {
+        let mut files: Vec<String> = self
+            .entries
+            .iter()
+            .flat_map(|e| e.files.iter().cloned())
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        files.sort();
+        files
+    }
+
+    /// All deferred violations across every entry, deduplicated by
+    /// `(code, hash)` -- a file can be recompiled several times within one
+    /// batch (e.g. after an edit), and each recompile re-defers it.
+    pub fn deduplicated_violations(&self) -> Vec<Violation> {
+        let mut seen = HashSet::new();
+        let mut out = Vec::new();
+        for entry in &self.entries {
+            for v in &entry.violations {
+                if seen.insert((v.code.clone(), v.hash.clone())) {
+                    out.push(v.clone());
+                }
+            }
+        }
+        out
+    }
+}
+
+fn now_unix_ms() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -169,4 +308,109 @@ mod tests {
     fn test_e005_not_deferrable() {
         assert!(!BatchState::is_deferrable("E005"));
     }
+
+    fn sample_violation(code: &str, hash: &str) -> Violation {
+        Violation {
+            code: code.to_string(),
+            severity: "ERROR".to_string(),
+            category: "missing_type_hints".to_string(),
+            message: "test".to_string(),
+            file: "a.py".to_string(),
+            line: 1,
+            hash: hash.to_string(),
+            confidence: 1.0,
+            resolution_tier: "tree-sitter".to_string(),
+            fix_hint: None,
+            suppressed: false,
+            suppress_hint: None,
+            affected: vec![],
+            suggested_module: None,
+            existing: None,
+        }
+    }
+
+    #[test]
+    fn test_journal_start_load_roundtrip() {
+        let dir = tempfile::tempdir().unwrap();
+        BatchJournal::start(dir.path()).unwrap();
+        let journal = BatchJournal::load(dir.path()).expect("journal should load");
+        assert_eq!(journal.version, JOURNAL_VERSION);
+        assert!(journal.entries.is_empty());
+    }
+
+    #[test]
+    fn test_journal_missing_returns_none() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(BatchJournal::load(dir.path()).is_none());
+    }
+
+    #[test]
+    fn test_journal_corrupt_is_discarded_with_warning() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(BatchJournal::path(dir.path()), "not json").unwrap();
+        assert!(BatchJournal::load(dir.path()).is_none());
+        assert!(!BatchJournal::path(dir.path()).exists());
+    }
+
+    #[test]
+    fn test_journal_stale_version_is_discarded() {
+        let dir = tempfile::tempdir().unwrap();
+        let stale = BatchJournal {
+            version: JOURNAL_VERSION + 1,
+            started_at_unix_ms: 0,
+            entries: Vec::new(),
+        };
+        stale.save(dir.path()).unwrap();
+        assert!(BatchJournal::load(dir.path()).is_none());
+    }
+
+    #[test]
+    fn test_journal_record_and_save_accumulates_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        BatchJournal::start(dir.path()).unwrap();
+        let mut journal = BatchJournal::load(dir.path()).unwrap();
+        journal.record(vec!["a.py".to_string()], vec![sample_violation("E002", "h1")]);
+        journal.save(dir.path()).unwrap();
+
+        let mut journal = BatchJournal::load(dir.path()).unwrap();
+        journal.record(vec!["b.py".to_string()], vec![sample_violation("E002", "h2")]);
+        journal.save(dir.path()).unwrap();
+
+        let journal = BatchJournal::load(dir.path()).unwrap();
+        assert_eq!(journal.entries.len(), 2);
+        assert_eq!(journal.files_queued(), vec!["a.py".to_string(), "b.py".to_string()]);
+        assert_eq!(journal.deduplicated_violations().len(), 2);
+    }
+
+    #[test]
+    fn test_journal_deduplicates_by_code_and_hash() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut journal = BatchJournal {
+            version: JOURNAL_VERSION,
+            started_at_unix_ms: 0,
+            entries: Vec::new(),
+        };
+        journal.record(vec!["a.py".to_string()], vec![sample_violation("E002", "h1")]);
+        journal.record(vec!["a.py".to_string()], vec![sample_violation("E002", "h1")]);
+        let deduped = journal.deduplicated_violations();
+        assert_eq!(deduped.len(), 1);
+        let _ = dir;
+    }
+
+    #[test]
+    fn test_journal_save_leaves_no_tmp_file_behind() {
+        let dir = tempfile::tempdir().unwrap();
+        BatchJournal::start(dir.path()).unwrap();
+        assert!(BatchJournal::path(dir.path()).exists());
+        assert!(!BatchJournal::path(dir.path()).with_extension("json.tmp").exists());
+    }
+
+    #[test]
+    fn test_journal_delete_removes_file() {
+        let dir = tempfile::tempdir().unwrap();
+        BatchJournal::start(dir.path()).unwrap();
+        assert!(BatchJournal::load(dir.path()).is_some());
+        BatchJournal::delete(dir.path());
+        assert!(BatchJournal::load(dir.path()).is_none());
+    }
 }
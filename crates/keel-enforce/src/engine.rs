@@ -1,3 +1,4 @@
+use keel_core::config::KeelConfig;
 use keel_core::store::GraphStore;
 use keel_core::types::{EdgeDirection, EdgeKind, NodeKind};
 use keel_parsers::resolver::FileIndex;
@@ -7,25 +8,42 @@ use crate::circuit_breaker::{BreakerAction, CircuitBreaker};
 use crate::suppress::SuppressionManager;
 use crate::types::{
     CalleeInfo, CallerInfo, CompileInfo, CompileResult, DiscoverResult, ExplainResult,
-    ModuleContext, NodeInfo, ResolutionStep, Violation,
+    FileRuleCoverage, ModuleContext, NodeInfo, ResolutionStep, RuleCoverage, Violation,
 };
 use crate::violations;
 
+/// Every rule name `compile` evaluates, in the order they run. Kept in one
+/// place so `rule_coverage` always reports every rule -- including ones
+/// that flagged nothing this run -- not just the ones that happened to fire.
+const RULE_CODES: &[&str] = &[
+    "E001", "E002", "E003", "E004", "E005", "E006", "W001", "W002",
+];
+
 /// Core enforcement engine. Owns a GraphStore and orchestrates validation.
 pub struct EnforcementEngine {
     store: Box<dyn GraphStore + Send>,
     circuit_breaker: CircuitBreaker,
     batch_state: Option<BatchState>,
     suppressions: SuppressionManager,
+    last_rule_coverage: Vec<RuleCoverage>,
+    config: KeelConfig,
 }
 
 impl EnforcementEngine {
     pub fn new(store: Box<dyn GraphStore + Send>) -> Self {
+        Self::with_config(store, &KeelConfig::default())
+    }
+
+    /// Like `new`, but honors `config.enforce`'s rule toggles and
+    /// `config.monorepo.layers`'s layering policy.
+    pub fn with_config(store: Box<dyn GraphStore + Send>, config: &KeelConfig) -> Self {
         Self {
             store,
             circuit_breaker: CircuitBreaker::new(),
             batch_state: None,
             suppressions: SuppressionManager::new(),
+            last_rule_coverage: Vec::new(),
+            config: config.clone(),
         }
     }
 
@@ -38,23 +56,59 @@ impl EnforcementEngine {
         let edges_updated: u32 = 0;
         let file_paths: Vec<String> = files.iter().map(|f| f.file_path.clone()).collect();
 
+        let mut coverage: Vec<RuleCoverage> = RULE_CODES
+            .iter()
+            .map(|code| RuleCoverage {
+                rule: code.to_string(),
+                evaluated: 0,
+                flagged: 0,
+                per_file: Vec::new(),
+            })
+            .collect();
+
         for file in files {
             let mut file_violations = Vec::new();
+            let evaluated = file.definitions.len() as u32;
 
             // E001: broken callers
-            file_violations.extend(violations::check_broken_callers(file, &*self.store));
+            let e001 = violations::check_broken_callers(file, &*self.store);
+            record_rule_coverage(&mut coverage, 0, file, evaluated, e001.len() as u32);
+            file_violations.extend(e001);
             // E002: missing type hints
-            file_violations.extend(violations::check_missing_type_hints(file));
+            if self.config.enforce.type_hints {
+                let e002 = violations::check_missing_type_hints(file);
+                record_rule_coverage(&mut coverage, 1, file, evaluated, e002.len() as u32);
+                file_violations.extend(e002);
+            }
             // E003: missing docstring
-            file_violations.extend(violations::check_missing_docstring(file));
+            if self.config.enforce.docstrings {
+                let e003 = violations::check_missing_docstring(file);
+                record_rule_coverage(&mut coverage, 2, file, evaluated, e003.len() as u32);
+                file_violations.extend(e003);
+            }
             // E004: function removed
-            file_violations.extend(violations::check_removed_functions(file, &*self.store));
+            let e004 = violations::check_removed_functions(file, &*self.store);
+            record_rule_coverage(&mut coverage, 3, file, evaluated, e004.len() as u32);
+            file_violations.extend(e004);
             // E005: arity mismatch
-            file_violations.extend(violations::check_arity_mismatch(file, &*self.store));
+            let e005 = violations::check_arity_mismatch(file, &*self.store);
+            record_rule_coverage(&mut coverage, 4, file, evaluated, e005.len() as u32);
+            file_violations.extend(e005);
+            // E006: layer violation (cross-package edge forbidden by monorepo.layers)
+            let e006 =
+                violations::check_layer_violations(file, &*self.store, &self.config.monorepo.layers);
+            record_rule_coverage(&mut coverage, 5, file, evaluated, e006.len() as u32);
+            file_violations.extend(e006);
             // W001: placement
-            file_violations.extend(violations::check_placement(file, &*self.store));
+            if self.config.enforce.placement {
+                let w001 = violations::check_placement(file, &*self.store);
+                record_rule_coverage(&mut coverage, 6, file, evaluated, w001.len() as u32);
+                file_violations.extend(w001);
+            }
             // W002: duplicate names
-            file_violations.extend(violations::check_duplicate_names(file, &*self.store));
+            let w002 = violations::check_duplicate_names(file, &*self.store);
+            record_rule_coverage(&mut coverage, 7, file, evaluated, w002.len() as u32);
+            file_violations.extend(w002);
 
             // Apply circuit breaker
             file_violations = self.apply_circuit_breaker(file_violations);
@@ -120,6 +174,8 @@ impl EnforcementEngine {
             "ok"
         };
 
+        self.last_rule_coverage = coverage;
+
         CompileResult {
             version: "0.1.0".to_string(),
             command: "compile".to_string(),
@@ -209,6 +265,36 @@ impl EnforcementEngine {
         self.batch_state = Some(BatchState::new());
     }
 
+    /// Returns true if this engine is currently in batch mode.
+    pub fn is_batch_active(&self) -> bool {
+        self.batch_state.is_some()
+    }
+
+    /// Remove and return the violations deferred so far by `compile()`
+    /// calls on this engine, without ending batch mode. The CLI calls this
+    /// after every `keel compile <file>` to persist progress to the
+    /// on-disk `BatchJournal`, since this in-memory engine does not
+    /// survive the process exiting. See `BatchState::take_deferred`.
+    pub fn take_deferred_violations(&mut self) -> Vec<Violation> {
+        match &mut self.batch_state {
+            Some(batch) => batch.take_deferred(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Merge violations deferred by another engine's batch run (e.g. a
+    /// `--jobs` worker compiling its own chunk with its own engine) into
+    /// this engine's batch queue, a no-op if this engine isn't in batch
+    /// mode. Lets callers that shard compilation across several engines
+    /// still present one coherent `take_deferred_violations()` result.
+    pub fn merge_deferred_violations(&mut self, violations: Vec<Violation>) {
+        if let Some(batch) = &mut self.batch_state {
+            for v in violations {
+                batch.defer(v);
+            }
+        }
+    }
+
     /// End batch mode: fire all deferred violations.
     pub fn batch_end(&mut self) -> CompileResult {
         let deferred = match self.batch_state.take() {
@@ -248,6 +334,15 @@ impl EnforcementEngine {
         self.suppressions.suppress(code);
     }
 
+    /// Per-rule evaluation counts from the most recent `compile` call: how
+    /// many definitions each rule was evaluated against, how many it
+    /// flagged, broken down per file. Empty until the first `compile` call.
+    /// Not updated by `batch_end`, which only re-partitions violations
+    /// `compile` already evaluated.
+    pub fn rule_coverage(&self) -> &[RuleCoverage] {
+        &self.last_rule_coverage
+    }
+
     // -- Private helpers --
 
     fn apply_circuit_breaker(&mut self, violations: Vec<Violation>) -> Vec<Violation> {
@@ -371,6 +466,26 @@ impl EnforcementEngine {
     }
 }
 
+/// Record one rule's result for `file` into `coverage[rule_index]`, both
+/// the running total and a per-file breakdown. `coverage` is always built
+/// from `RULE_CODES` in order, so `rule_index` indexes directly into it.
+fn record_rule_coverage(
+    coverage: &mut [RuleCoverage],
+    rule_index: usize,
+    file: &FileIndex,
+    evaluated: u32,
+    flagged: u32,
+) {
+    let entry = &mut coverage[rule_index];
+    entry.evaluated += evaluated;
+    entry.flagged += flagged;
+    entry.per_file.push(FileRuleCoverage {
+        file: file.file_path.clone(),
+        evaluated,
+        flagged,
+    });
+}
+
 fn node_to_info(node: &keel_core::types::GraphNode) -> NodeInfo {
     NodeInfo {
         hash: node.hash.clone(),
@@ -390,7 +505,7 @@ mod tests {
     use super::*;
     use keel_core::sqlite::SqliteGraphStore;
     use keel_core::types::{EdgeChange, GraphEdge, GraphNode};
-    use keel_parsers::resolver::Definition;
+    use keel_parsers::resolver::{Definition, Namespace};
 
     fn make_node(id: u64, hash: &str, name: &str, sig: &str, file: &str) -> GraphNode {
         GraphNode {
@@ -409,6 +524,7 @@ mod tests {
             external_endpoints: vec![],
             previous_hashes: vec![],
             module_id: 0,
+            package: None,
         }
     }
 
@@ -435,6 +551,7 @@ mod tests {
             is_public: true,
             type_hints_present: true,
             body_text: body.to_string(),
+            namespace: Namespace::Value,
         }
     }
 
@@ -961,4 +1078,73 @@ mod tests {
         assert_eq!(e001_errors, 0, "E001 should be downgraded after 3 failures");
         assert!(e001_warnings > 0, "E001 should appear as WARNING after downgrade");
     }
+
+    #[test]
+    fn test_rule_coverage_reports_every_rule() {
+        let store = SqliteGraphStore::in_memory().unwrap();
+        let mut engine = EnforcementEngine::new(Box::new(store));
+        assert!(engine.rule_coverage().is_empty(), "no coverage before first compile");
+
+        let mut def = make_definition("process", "def process(x)", "pass", "app.py");
+        def.type_hints_present = false;
+
+        let file = FileIndex {
+            file_path: "app.py".to_string(),
+            content_hash: 0,
+            definitions: vec![def],
+            references: vec![],
+            imports: vec![],
+            external_endpoints: vec![],
+            parse_duration_us: 0,
+        };
+
+        engine.compile(&[file]);
+        let coverage = engine.rule_coverage();
+        assert_eq!(coverage.len(), RULE_CODES.len(), "every rule should be reported, even dead ones");
+
+        let e002 = coverage.iter().find(|c| c.rule == "E002").unwrap();
+        assert_eq!(e002.evaluated, 1);
+        assert_eq!(e002.flagged, 1, "E002 should flag the type-hint-less def");
+        assert_eq!(e002.per_file.len(), 1);
+        assert_eq!(e002.per_file[0].file, "app.py");
+
+        let e003 = coverage.iter().find(|c| c.rule == "E003").unwrap();
+        assert_eq!(e003.evaluated, 1);
+        assert_eq!(e003.flagged, 0, "E003 shouldn't flag a def that has a docstring");
+    }
+
+    #[test]
+    fn test_rule_coverage_accumulates_across_files() {
+        let store = SqliteGraphStore::in_memory().unwrap();
+        let mut engine = EnforcementEngine::new(Box::new(store));
+
+        let clean = make_definition("clean", "fn clean()", "{}", "a.rs");
+        let mut missing_doc = make_definition("loud", "fn loud()", "{}", "b.rs");
+        missing_doc.docstring = None;
+
+        let file_a = FileIndex {
+            file_path: "a.rs".to_string(),
+            content_hash: 0,
+            definitions: vec![clean],
+            references: vec![],
+            imports: vec![],
+            external_endpoints: vec![],
+            parse_duration_us: 0,
+        };
+        let file_b = FileIndex {
+            file_path: "b.rs".to_string(),
+            content_hash: 0,
+            definitions: vec![missing_doc],
+            references: vec![],
+            imports: vec![],
+            external_endpoints: vec![],
+            parse_duration_us: 0,
+        };
+
+        engine.compile(&[file_a, file_b]);
+        let e003 = engine.rule_coverage().iter().find(|c| c.rule == "E003").unwrap();
+        assert_eq!(e003.evaluated, 2, "E003 should be evaluated against both files' defs");
+        assert_eq!(e003.flagged, 1, "only b.rs's def is missing a docstring");
+        assert_eq!(e003.per_file.len(), 2);
+    }
 }
@@ -547,3 +547,86 @@ fn test_config_defaults_enable_all() {
         "E002 should fire with default config (backward compat)"
     );
 }
+
+#[test]
+fn test_e006_layer_violation_fires_for_denied_package() {
+    use keel_core::config::LayerRule;
+    use keel_parsers::resolver::{Namespace, Reference, ReferenceKind};
+
+    let store = SqliteGraphStore::in_memory().unwrap();
+    let mut caller = make_node(1, "cal1layer001", "main", "fn main()", "src/cli/main.rs");
+    caller.package = Some("cli".to_string());
+    store.insert_node(&caller).unwrap();
+    let mut target = make_node(2, "tgt1layer001", "connect", "fn connect()", "src/core/db.rs");
+    target.package = Some("core".to_string());
+    store.insert_node(&target).unwrap();
+
+    let mut config = keel_core::config::KeelConfig::default();
+    config.monorepo.layers.insert(
+        "cli".to_string(),
+        LayerRule {
+            allow: vec![],
+            deny: vec!["core".to_string()],
+        },
+    );
+    let mut engine = EnforcementEngine::with_config(Box::new(store), &config);
+
+    let file = FileIndex {
+        file_path: "src/cli/main.rs".to_string(),
+        content_hash: 0,
+        definitions: vec![],
+        references: vec![Reference {
+            name: "connect".to_string(),
+            file_path: "src/cli/main.rs".to_string(),
+            line: 5,
+            kind: ReferenceKind::Call,
+            resolved_to: Some("tgt1layer001".to_string()),
+            namespace: Namespace::Value,
+        }],
+        imports: vec![],
+        external_endpoints: vec![],
+        parse_duration_us: 0,
+    };
+
+    let result = engine.compile(&[file]);
+    let e006 = result.errors.iter().find(|v| v.code == "E006");
+    assert!(e006.is_some(), "E006 should fire for a call into a denied package");
+    assert_eq!(e006.unwrap().category, "layer_violation");
+}
+
+#[test]
+fn test_e006_layer_violation_does_not_fire_without_a_rule() {
+    use keel_parsers::resolver::{Namespace, Reference, ReferenceKind};
+
+    let store = SqliteGraphStore::in_memory().unwrap();
+    let mut caller = make_node(1, "cal1layer002", "main", "fn main()", "src/cli/main.rs");
+    caller.package = Some("cli".to_string());
+    store.insert_node(&caller).unwrap();
+    let mut target = make_node(2, "tgt1layer002", "connect", "fn connect()", "src/core/db.rs");
+    target.package = Some("core".to_string());
+    store.insert_node(&target).unwrap();
+
+    // No monorepo.layers entry at all -- the edge is unrestricted.
+    let config = keel_core::config::KeelConfig::default();
+    let mut engine = EnforcementEngine::with_config(Box::new(store), &config);
+
+    let file = FileIndex {
+        file_path: "src/cli/main.rs".to_string(),
+        content_hash: 0,
+        definitions: vec![],
+        references: vec![Reference {
+            name: "connect".to_string(),
+            file_path: "src/cli/main.rs".to_string(),
+            line: 5,
+            kind: ReferenceKind::Call,
+            resolved_to: Some("tgt1layer002".to_string()),
+            namespace: Namespace::Value,
+        }],
+        imports: vec![],
+        external_endpoints: vec![],
+        parse_duration_us: 0,
+    };
+
+    let result = engine.compile(&[file]);
+    assert!(result.errors.iter().all(|v| v.code != "E006"));
+}
@@ -1,48 +1,69 @@
-use crate::types::{FixAction, FixPlan, Violation};
+use crate::types::{ApplyReport, FileSystemEdit, FixAction, FixPlan, SkippedAction, TextRange, Violation};
 use keel_core::store::GraphStore;
 use keel_core::types::EdgeDirection;
+use std::collections::BTreeMap;
 use std::path::Path;
 
 /// Generate fix plans from a set of violations.
 ///
 /// Currently supports plan-only mode (no --apply).
 /// Priority: E001, E004, E005 (caller-propagation), E002/E003 (template stubs).
-pub fn generate_fix_plans(violations: &[&Violation], store: &dyn GraphStore) -> Vec<FixPlan> {
+///
+/// `base_dir` lets E001/E005 re-parse the caller file to find the exact
+/// byte span of the broken call's argument list, so the generated
+/// `FixAction` rewrites that span surgically instead of emitting a
+/// `"foo(...) // comment"` placeholder. When a caller file can't be
+/// read or parsed (e.g. an unsupported language), each falls back to the
+/// old placeholder behavior rather than dropping the action.
+pub fn generate_fix_plans(violations: &[&Violation], store: &dyn GraphStore, base_dir: &Path) -> Vec<FixPlan> {
     let mut plans = Vec::new();
     for v in violations {
-        if let Some(plan) = generate_plan_for_violation(v, store) {
-            plans.push(plan);
-        }
+        plans.extend(generate_plans_for_violation(v, store, base_dir));
     }
     plans
 }
 
-fn generate_plan_for_violation(v: &Violation, store: &dyn GraphStore) -> Option<FixPlan> {
+/// Generate every candidate fix for one violation, most-confident first.
+/// A violation with only one sensible fix still returns a one-element
+/// `Vec` rather than `None`, so callers always iterate rather than match.
+fn generate_plans_for_violation(v: &Violation, store: &dyn GraphStore, base_dir: &Path) -> Vec<FixPlan> {
     match v.code.as_str() {
-        "E001" => generate_broken_caller_fix(v, store),
-        "E004" => generate_removed_function_fix(v, store),
-        "E005" => generate_arity_mismatch_fix(v, store),
-        "E002" => generate_type_hint_fix(v),
-        "E003" => generate_docstring_fix(v),
-        _ => None,
+        "E001" => generate_broken_caller_fix(v, store, base_dir).into_iter().collect(),
+        "E004" => generate_removed_function_fix(v, store, base_dir),
+        "E005" => generate_arity_mismatch_fix(v, store, base_dir).into_iter().collect(),
+        "E002" => generate_type_hint_fix(v, store, base_dir),
+        "E003" => generate_docstring_fix(v).into_iter().collect(),
+        "W001" => generate_module_move_fix(v, base_dir).into_iter().collect(),
+        _ => vec![],
     }
 }
 
+/// Re-parse `file` under `base_dir` and locate the argument-list span of
+/// the call to `callee_name` on `line`. `None` if the file can't be read,
+/// its language isn't supported, or no matching call is found there --
+/// callers should fall back to a line-level placeholder action.
+fn locate_call_arguments(
+    base_dir: &Path,
+    file: &str,
+    line: u32,
+    callee_name: &str,
+) -> Option<keel_parsers::treesitter::CallArgumentSpan> {
+    let path = base_dir.join(file);
+    let lang = keel_parsers::treesitter::detect_language(&path)?;
+    let source = std::fs::read_to_string(&path).ok()?;
+    keel_parsers::treesitter::find_call_argument_span(lang, &source, line, callee_name)
+}
+
 /// E001: broken_caller — signature changed, callers need updating.
-fn generate_broken_caller_fix(v: &Violation, store: &dyn GraphStore) -> Option<FixPlan> {
+fn generate_broken_caller_fix(v: &Violation, store: &dyn GraphStore, base_dir: &Path) -> Option<FixPlan> {
     let node = store.get_node(&v.hash)?;
     let callers = store.get_edges(node.id, EdgeDirection::Incoming);
 
     let mut actions = Vec::new();
     for edge in &callers {
         if let Some(caller_node) = store.get_node_by_id(edge.source_id) {
-            actions.push(FixAction {
-                file: caller_node.file_path.clone(),
-                line: edge.line,
-                old_text: format!("{}(...) // old signature", node.name),
-                new_text: format!("{}(...) // update to: {}", node.name, node.signature),
-                description: format!("Update call to `{}` in `{}`", node.name, caller_node.name),
-            });
+            let caller_file = caller_node.file_path.clone();
+            actions.push(broken_caller_action(caller_file, edge.line, &node, &caller_node, base_dir));
         }
     }
 
@@ -57,25 +78,87 @@ fn generate_broken_caller_fix(v: &Violation, store: &dyn GraphStore) -> Option<F
             callers.len(),
         ),
         actions,
+        kind: "update_callers".to_string(),
+        confidence: v.confidence,
+        fs_edits: vec![],
     })
 }
 
+fn broken_caller_action(
+    caller_file: String,
+    line: u32,
+    node: &keel_core::types::GraphNode,
+    caller_node: &keel_core::types::GraphNode,
+    base_dir: &Path,
+) -> FixAction {
+    if let Some(span) = locate_call_arguments(base_dir, &caller_file, line, &node.name) {
+        let new_text = format!("{} /* TODO: verify against new signature: {} */", span.text, node.signature);
+        return FixAction {
+            file: caller_file,
+            line,
+            old_text: span.text,
+            new_text,
+            description: format!("Update call to `{}` in `{}`", node.name, caller_node.name),
+            column: Some(span.column),
+            range: Some(TextRange { start_byte: span.start_byte, end_byte: span.end_byte }),
+        };
+    }
+
+    FixAction {
+        file: caller_file,
+        line,
+        old_text: format!("{}(...) // old signature", node.name),
+        new_text: format!("{}(...) // update to: {}", node.name, node.signature),
+        description: format!("Update call to `{}` in `{}`", node.name, caller_node.name),
+        column: None,
+        range: None,
+    }
+}
+
 /// E004: function_removed — function no longer exists, callers need updating.
-fn generate_removed_function_fix(v: &Violation, _store: &dyn GraphStore) -> Option<FixPlan> {
-    // For removed functions, we use the affected nodes from the violation
-    let actions: Vec<FixAction> = v
+///
+/// Offers two alternatives: restoring the removed function (always), and
+/// rewriting callers onto `suggested_module`'s replacement when one was
+/// identified for this violation.
+fn generate_removed_function_fix(v: &Violation, store: &dyn GraphStore, base_dir: &Path) -> Vec<FixPlan> {
+    let restore_actions: Vec<FixAction> = v
         .affected
         .iter()
         .map(|a| FixAction {
             file: a.file.clone(),
             line: a.line,
             old_text: format!("call to removed function (hash={})", v.hash),
-            new_text: "// TODO: replace with alternative or restore function".to_string(),
+            new_text: "// TODO: restore function from prior graph snapshot".to_string(),
             description: format!("Caller `{}` references removed function", a.name),
+            column: None,
+            range: None,
         })
         .collect();
 
-    Some(FixPlan {
+    // `store` has normally already been compacted past the point where the
+    // removed node's own row survives (compile deletes it once its file no
+    // longer defines it) -- the call below only succeeds in the rarer case
+    // where a plan is generated against a store snapshot taken before that.
+    // When it does, we can restore a real stub instead of a bare TODO.
+    let restore_fs_edits = store
+        .get_node(&v.hash)
+        .map(|node| {
+            let path = restored_stub_path(&node.file_path);
+            let prefix = comment_prefix_for(base_dir.join(&path).as_path());
+            vec![FileSystemEdit::CreateFile {
+                path: path.clone(),
+                content: format!(
+                    "{prefix} TODO: restore `{name}`, removed from `{origin}`.\n{prefix} prior signature: {sig}\n{prefix} populate the body from version control history.\n",
+                    prefix = prefix,
+                    name = node.name,
+                    origin = node.file_path,
+                    sig = node.signature,
+                ),
+            }]
+        })
+        .unwrap_or_default();
+
+    let mut plans = vec![FixPlan {
         code: v.code.clone(),
         hash: v.hash.clone(),
         category: v.category.clone(),
@@ -84,28 +167,125 @@ fn generate_removed_function_fix(v: &Violation, _store: &dyn GraphStore) -> Opti
             "Function was removed; {} caller(s) still reference it",
             v.affected.len(),
         ),
-        actions,
+        actions: restore_actions,
+        kind: "restore_function".to_string(),
+        confidence: 0.6,
+        fs_edits: restore_fs_edits,
+    }];
+
+    if let Some(module) = &v.suggested_module {
+        let rewrite_actions: Vec<FixAction> = v
+            .affected
+            .iter()
+            .map(|a| FixAction {
+                file: a.file.clone(),
+                line: a.line,
+                old_text: format!("call to removed function (hash={})", v.hash),
+                new_text: format!("// TODO: rewrite call to use replacement in `{}`", module),
+                description: format!("Caller `{}` should call the replacement in `{}`", a.name, module),
+                column: None,
+                range: None,
+            })
+            .collect();
+
+        plans.push(FixPlan {
+            code: v.code.clone(),
+            hash: v.hash.clone(),
+            category: v.category.clone(),
+            target_name: v.message.clone(),
+            cause: format!(
+                "Function was removed; a replacement was found in `{}`",
+                module,
+            ),
+            actions: rewrite_actions,
+            kind: "rewrite_callers".to_string(),
+            confidence: 0.5,
+            fs_edits: vec![],
+        });
+    }
+
+    plans
+}
+
+/// Sibling path for a restored-function stub, e.g. `src/auth.rs` ->
+/// `src/auth_restored.rs`, so the stub never collides with the module the
+/// function was actually removed from.
+fn restored_stub_path(original: &str) -> String {
+    let path = Path::new(original);
+    let ext = path.extension().and_then(|e| e.to_str()).unwrap_or("txt");
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("restored");
+    let name = format!("{stem}_restored.{ext}");
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.join(name).to_string_lossy().to_string(),
+        _ => name,
+    }
+}
+
+/// Line-comment token for a brand-new placeholder file, inferred from its
+/// extension. Defaults to `//`, which covers every language this repo
+/// parses except Python.
+fn comment_prefix_for(path: &Path) -> &'static str {
+    match keel_parsers::treesitter::detect_language(path) {
+        Some("python") => "#",
+        _ => "//",
+    }
+}
+
+/// W001: placement — function may belong in a different module.
+///
+/// When the suggested module doesn't exist yet, offers to create it with
+/// an anchor stub so the move has somewhere to land; when it already
+/// exists, only the informational move action is offered (no fs edit --
+/// `validate_fix_plan` would reject a create over an existing file anyway).
+fn generate_module_move_fix(v: &Violation, base_dir: &Path) -> Option<FixPlan> {
+    let module = v.suggested_module.as_ref()?;
+
+    let action = FixAction {
+        file: v.file.clone(),
+        line: v.line,
+        old_text: String::new(),
+        new_text: format!("// TODO: move this function into `{}`", module),
+        description: format!("Move to suggested module `{}`", module),
+        column: None,
+        range: None,
+    };
+
+    let fs_edits = if base_dir.join(module).exists() {
+        vec![]
+    } else {
+        let prefix = comment_prefix_for(base_dir.join(module).as_path());
+        vec![FileSystemEdit::CreateFile {
+            path: module.clone(),
+            content: format!(
+                "{prefix} Anchor module created for functions moved here by `keel fix`.\n",
+            ),
+        }]
+    };
+
+    Some(FixPlan {
+        code: v.code.clone(),
+        hash: v.hash.clone(),
+        category: v.category.clone(),
+        target_name: v.message.clone(),
+        cause: format!("Function may be better placed in `{}`", module),
+        actions: vec![action],
+        kind: "move_to_suggested_module".to_string(),
+        confidence: v.confidence,
+        fs_edits,
     })
 }
 
 /// E005: arity_mismatch — parameter count changed, callers need updating.
-fn generate_arity_mismatch_fix(v: &Violation, store: &dyn GraphStore) -> Option<FixPlan> {
+fn generate_arity_mismatch_fix(v: &Violation, store: &dyn GraphStore, base_dir: &Path) -> Option<FixPlan> {
     let node = store.get_node(&v.hash)?;
     let callers = store.get_edges(node.id, EdgeDirection::Incoming);
+    let new_params = parse_param_names(&node.signature);
 
     let mut actions = Vec::new();
     for edge in &callers {
         if let Some(caller_node) = store.get_node_by_id(edge.source_id) {
-            actions.push(FixAction {
-                file: caller_node.file_path.clone(),
-                line: edge.line,
-                old_text: format!("{}(...) // wrong arity", node.name),
-                new_text: format!("{}(...) // match new sig: {}", node.name, node.signature),
-                description: format!(
-                    "Update arity of call to `{}` in `{}`",
-                    node.name, caller_node.name,
-                ),
-            });
+            let caller_file = caller_node.file_path.clone();
+            actions.push(arity_mismatch_action(caller_file, edge.line, &node, &caller_node, &new_params, base_dir));
         }
     }
 
@@ -119,11 +299,149 @@ fn generate_arity_mismatch_fix(v: &Violation, store: &dyn GraphStore) -> Option<
             node.name, node.signature,
         ),
         actions,
+        kind: "update_arity".to_string(),
+        confidence: v.confidence,
+        fs_edits: vec![],
     })
 }
 
-/// Validate a fix plan: check that target files exist and lines are in range.
-/// Returns a list of (action_index, error_message) for invalid actions.
+fn arity_mismatch_action(
+    caller_file: String,
+    line: u32,
+    node: &keel_core::types::GraphNode,
+    caller_node: &keel_core::types::GraphNode,
+    new_params: &[String],
+    base_dir: &Path,
+) -> FixAction {
+    let description = format!("Update arity of call to `{}` in `{}`", node.name, caller_node.name);
+
+    if let Some(span) = locate_call_arguments(base_dir, &caller_file, line, &node.name) {
+        let new_text = rewrite_call_args(&span.text, new_params);
+        return FixAction {
+            file: caller_file,
+            line,
+            old_text: span.text,
+            new_text,
+            description,
+            column: Some(span.column),
+            range: Some(TextRange { start_byte: span.start_byte, end_byte: span.end_byte }),
+        };
+    }
+
+    FixAction {
+        file: caller_file,
+        line,
+        old_text: format!("{}(...) // wrong arity", node.name),
+        new_text: format!("{}(...) // match new sig: {}", node.name, node.signature),
+        description,
+        column: None,
+        range: None,
+    }
+}
+
+/// Split a comma-separated list on top-level commas only: `(`/`[`/`{`/`<`
+/// open a nesting level, their matches close it, and commas inside a
+/// `"..."` or `'...'` literal (escapes respected) are never split points.
+/// Used everywhere this module needs to tear apart a parameter list or
+/// call's argument list -- `foo(bar(1, 2), 3)` and `fn f(m: HashMap<String,
+/// i32>)` both have commas that a plain `str::split(',')` would wrongly
+/// treat as top-level.
+fn split_top_level_commas(s: &str) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut depth: i32 = 0;
+    let mut current = String::new();
+    let mut chars = s.chars().peekable();
+    let mut in_string: Option<char> = None;
+
+    while let Some(c) = chars.next() {
+        if let Some(quote) = in_string {
+            current.push(c);
+            if c == '\\' {
+                if let Some(escaped) = chars.next() {
+                    current.push(escaped);
+                }
+            } else if c == quote {
+                in_string = None;
+            }
+            continue;
+        }
+        match c {
+            '"' | '\'' => {
+                in_string = Some(c);
+                current.push(c);
+            }
+            '(' | '[' | '{' | '<' => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' | ']' | '}' | '>' => {
+                depth -= 1;
+                current.push(c);
+            }
+            ',' if depth <= 0 => {
+                parts.push(current.trim().to_string());
+                current = String::new();
+            }
+            _ => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() || !parts.is_empty() {
+        parts.push(current.trim().to_string());
+    }
+    parts
+}
+
+/// Extract parameter names from a `fn name(a: T, b: U) -> R`-shaped
+/// signature (best-effort across languages: splits on top-level commas,
+/// then takes the identifier before the first `:` or whitespace).
+fn parse_param_names(signature: &str) -> Vec<String> {
+    let Some(start) = signature.find('(') else { return vec![] };
+    let Some(end) = signature.rfind(')') else { return vec![] };
+    let inner = signature[start + 1..end].trim();
+    if inner.is_empty() {
+        return vec![];
+    }
+    split_top_level_commas(inner)
+        .into_iter()
+        .map(|param| {
+            param
+                .trim()
+                .split([':', ' '])
+                .next()
+                .unwrap_or("")
+                .trim()
+                .to_string()
+        })
+        .collect()
+}
+
+/// Rewrite a call's existing argument list (`old_args`, parens included)
+/// against the target's current parameter names: trailing params added
+/// since the call was written get a `/* TODO: <param> */` placeholder
+/// argument appended; params the call still passes for now-removed
+/// trailing parameters are dropped.
+fn rewrite_call_args(old_args: &str, new_params: &[String]) -> String {
+    let mut args = split_call_args(old_args);
+
+    if new_params.len() > args.len() {
+        for param in &new_params[args.len()..] {
+            args.push(format!("/* TODO: {} */", param));
+        }
+    } else if new_params.len() < args.len() {
+        args.truncate(new_params.len());
+    }
+
+    format!("({})", args.join(", "))
+}
+
+/// Validate a fix plan: check that target files exist and lines are in
+/// range, that any `range`-bearing action's bytes still match `old_text`
+/// (a staleness guard -- refuses to apply an edit if the file has changed
+/// since the plan was generated), and that any `fs_edits` don't conflict
+/// with what's already on disk. Indices index into `actions` first, then
+/// `fs_edits` right after (i.e. `plan.actions.len() + i` for the i'th
+/// `fs_edits` entry) -- the two lists share one index space since the
+/// apply engine treats them as one ordered batch of edits per plan.
 pub fn validate_fix_plan(plan: &FixPlan, base_dir: &Path) -> Vec<(usize, String)> {
     let mut errors = Vec::new();
     for (i, action) in plan.actions.iter().enumerate() {
@@ -144,16 +462,348 @@ pub fn validate_fix_plan(plan: &FixPlan, base_dir: &Path) -> Vec<(usize, String)
                         ),
                     ));
                 }
+
+                if let Some(range) = action.range {
+                    let bytes = content.as_bytes();
+                    if range.start_byte > range.end_byte || range.end_byte > bytes.len() {
+                        errors.push((
+                            i,
+                            format!(
+                                "byte range {}..{} is outside {} ({} bytes)",
+                                range.start_byte, range.end_byte, action.file, bytes.len()
+                            ),
+                        ));
+                    } else if &bytes[range.start_byte..range.end_byte] != action.old_text.as_bytes() {
+                        errors.push((
+                            i,
+                            format!(
+                                "stale fix: {} has changed since this plan was generated (bytes {}..{} no longer match old_text)",
+                                action.file, range.start_byte, range.end_byte
+                            ),
+                        ));
+                    }
+                }
             }
             Err(e) => errors.push((i, format!("cannot read {}: {}", action.file, e))),
         }
     }
+
+    for (i, edit) in plan.fs_edits.iter().enumerate() {
+        let idx = plan.actions.len() + i;
+        match edit {
+            FileSystemEdit::CreateFile { path, .. } => {
+                if base_dir.join(path).exists() {
+                    errors.push((idx, format!("create target already exists: {}", path)));
+                }
+            }
+            FileSystemEdit::MoveFile { from, to } => {
+                if !base_dir.join(from).exists() {
+                    errors.push((idx, format!("move source not found: {}", from)));
+                }
+                if base_dir.join(to).exists() {
+                    errors.push((idx, format!("move target already exists: {}", to)));
+                }
+            }
+        }
+    }
+
     errors
 }
 
+/// `(file, line)` for the i'th entry of a plan's combined edit list
+/// (`actions` then `fs_edits`), for building a `SkippedAction` when that
+/// entry's index comes back from `validate_fix_plan`. `fs_edits` have no
+/// meaningful line, so they report `0`.
+fn edit_descriptor(plan: &FixPlan, index: usize) -> (String, u32) {
+    if let Some(action) = plan.actions.get(index) {
+        return (action.file.clone(), action.line);
+    }
+    match plan.fs_edits.get(index - plan.actions.len()) {
+        Some(FileSystemEdit::CreateFile { path, .. }) => (path.clone(), 0),
+        Some(FileSystemEdit::MoveFile { from, to }) => (format!("{} -> {}", from, to), 0),
+        None => ("<unknown>".to_string(), 0),
+    }
+}
+
+/// Pick exactly one `FixPlan` per `(code, hash)` violation, keeping the
+/// highest-`confidence` alternative (ties keep whichever was encountered
+/// first). `generate_fix_plans` can return several mutually-exclusive
+/// alternatives for the same violation -- e.g. E004's "restore the removed
+/// function" vs "rewrite callers", E002's inferred vs. stub type hint --
+/// but `apply_fix_plans` applies every action across every plan it's given
+/// with no notion of "pick one", so feeding it two alternatives for the
+/// same violation corrupts the file: the second alternative's `old_text`
+/// no longer matches once the first has already edited that line, and the
+/// apply falls through to an unplanned insertion that shifts every later
+/// line-indexed action. Callers that apply (rather than just display)
+/// plans must run them through this first.
+pub fn select_one_plan_per_violation(plans: &[FixPlan]) -> Vec<FixPlan> {
+    let mut best: Vec<FixPlan> = Vec::new();
+    for plan in plans {
+        match best.iter_mut().find(|p| p.code == plan.code && p.hash == plan.hash) {
+            Some(existing) => {
+                if plan.confidence > existing.confidence {
+                    *existing = plan.clone();
+                }
+            }
+            None => best.push(plan.clone()),
+        }
+    }
+    best
+}
+
+/// Apply a batch of fix plans to files under `base_dir`, all-or-nothing.
+///
+/// Mirrors rust-analyzer's `SourceChange` model: every `FixAction` across
+/// every plan is grouped by file, sorted within a file in reverse source
+/// order (so applying bottom-to-top never shifts the line number of a
+/// still-pending edit), and applied in one pass per file -- each file is
+/// read and written at most once, however many actions target it.
+///
+/// `validate_fix_plan` runs over every plan first; if *any* action in the
+/// batch is invalid, nothing is written and every action comes back
+/// skipped. Once writing starts, each file's original contents are kept
+/// as an in-memory backup -- if a later file in the batch fails to write,
+/// every file already written in this call is restored from its backup,
+/// so the batch is all-or-nothing on disk too.
+pub fn apply_fix_plans(plans: &[FixPlan], base_dir: &Path) -> ApplyReport {
+    let mut invalid: BTreeMap<(usize, usize), String> = BTreeMap::new();
+    for (plan_idx, plan) in plans.iter().enumerate() {
+        for (edit_idx, err) in validate_fix_plan(plan, base_dir) {
+            invalid.insert((plan_idx, edit_idx), err);
+        }
+    }
+
+    if !invalid.is_empty() {
+        return ApplyReport { files_changed: vec![], skipped: all_edits_skipped(plans, &invalid) };
+    }
+
+    // Apply fs_edits first -- they create/move whole files rather than
+    // editing lines, so they're simplest to get out of the way before the
+    // line-oriented pass below. Each successfully applied edit is recorded
+    // so it can be undone if anything later in the batch fails.
+    let mut fs_done: Vec<FsUndo> = Vec::new();
+    for plan in plans {
+        for edit in &plan.fs_edits {
+            match edit {
+                FileSystemEdit::CreateFile { path, content } => {
+                    let full = base_dir.join(path);
+                    if let Some(parent) = full.parent() {
+                        if let Err(e) = std::fs::create_dir_all(parent) {
+                            undo_fs_edits(base_dir, &fs_done);
+                            return ApplyReport {
+                                files_changed: vec![],
+                                skipped: all_actions_skipped(plans, &format!("cannot create directory for {}: {} -- batch rolled back", path, e)),
+                            };
+                        }
+                    }
+                    if let Err(e) = std::fs::write(&full, content) {
+                        undo_fs_edits(base_dir, &fs_done);
+                        return ApplyReport {
+                            files_changed: vec![],
+                            skipped: all_actions_skipped(plans, &format!("cannot create {}: {} -- batch rolled back", path, e)),
+                        };
+                    }
+                    fs_done.push(FsUndo::Remove(path.clone()));
+                }
+                FileSystemEdit::MoveFile { from, to } => {
+                    if let Some(parent) = base_dir.join(to).parent() {
+                        let _ = std::fs::create_dir_all(parent);
+                    }
+                    if let Err(e) = std::fs::rename(base_dir.join(from), base_dir.join(to)) {
+                        undo_fs_edits(base_dir, &fs_done);
+                        return ApplyReport {
+                            files_changed: vec![],
+                            skipped: all_actions_skipped(plans, &format!("cannot move {} to {}: {} -- batch rolled back", from, to, e)),
+                        };
+                    }
+                    fs_done.push(FsUndo::MoveBack { from: to.clone(), to: from.clone() });
+                }
+            }
+        }
+    }
+
+    // Group by file, sorted in reverse source order within each file.
+    let mut by_file: BTreeMap<String, Vec<&FixAction>> = BTreeMap::new();
+    for plan in plans {
+        for action in &plan.actions {
+            by_file.entry(action.file.clone()).or_default().push(action);
+        }
+    }
+    for actions in by_file.values_mut() {
+        actions.sort_by(|a, b| b.line.cmp(&a.line));
+    }
+
+    // Read every file up front -- the original content doubles as the
+    // rollback backup if a later write in this batch fails.
+    let mut backups: Vec<(String, String)> = Vec::new();
+    let mut new_contents: Vec<(String, String)> = Vec::new();
+    for (file, actions) in &by_file {
+        let path = base_dir.join(file);
+        let original = match std::fs::read_to_string(&path) {
+            Ok(c) => c,
+            Err(e) => {
+                undo_fs_edits(base_dir, &fs_done);
+                return ApplyReport {
+                    files_changed: vec![],
+                    skipped: all_actions_skipped(plans, &format!("cannot read {}: {}", file, e)),
+                };
+            }
+        };
+
+        let mut lines: Vec<String> = original.lines().map(str::to_string).collect();
+        for action in actions {
+            apply_action_to_lines(&mut lines, action);
+        }
+        let mut new_content = lines.join("\n");
+        if original.ends_with('\n') && !new_content.ends_with('\n') {
+            new_content.push('\n');
+        }
+
+        backups.push((file.clone(), original));
+        new_contents.push((file.clone(), new_content));
+    }
+
+    let mut written: Vec<&str> = Vec::new();
+    for (file, content) in &new_contents {
+        let path = base_dir.join(file);
+        if let Err(e) = std::fs::write(&path, content) {
+            for (backed_up_file, original) in &backups {
+                if written.contains(&backed_up_file.as_str()) {
+                    let _ = std::fs::write(base_dir.join(backed_up_file), original);
+                }
+            }
+            undo_fs_edits(base_dir, &fs_done);
+            return ApplyReport {
+                files_changed: vec![],
+                skipped: all_actions_skipped(plans, &format!("write failed for {}: {} -- batch rolled back", file, e)),
+            };
+        }
+        written.push(file);
+    }
+
+    let mut files_changed: Vec<String> = by_file.keys().cloned().collect();
+    for plan in plans {
+        for edit in &plan.fs_edits {
+            match edit {
+                FileSystemEdit::CreateFile { path, .. } => files_changed.push(path.clone()),
+                FileSystemEdit::MoveFile { to, .. } => files_changed.push(to.clone()),
+            }
+        }
+    }
+
+    ApplyReport { files_changed, skipped: vec![] }
+}
+
+/// How to undo one already-applied `FileSystemEdit`, for batch rollback.
+enum FsUndo {
+    Remove(String),
+    MoveBack { from: String, to: String },
+}
+
+fn undo_fs_edits(base_dir: &Path, done: &[FsUndo]) {
+    for undo in done.iter().rev() {
+        match undo {
+            FsUndo::Remove(path) => {
+                let _ = std::fs::remove_file(base_dir.join(path));
+            }
+            FsUndo::MoveBack { from, to } => {
+                let _ = std::fs::rename(base_dir.join(from), base_dir.join(to));
+            }
+        }
+    }
+}
+
+fn all_actions_skipped(plans: &[FixPlan], reason: &str) -> Vec<SkippedAction> {
+    plans
+        .iter()
+        .flat_map(|p| p.actions.iter().map(|a| (a.file.clone(), a.line)).chain(
+            p.fs_edits.iter().map(|e| match e {
+                FileSystemEdit::CreateFile { path, .. } => (path.clone(), 0),
+                FileSystemEdit::MoveFile { from, to } => (format!("{} -> {}", from, to), 0),
+            }),
+        ))
+        .map(|(file, line)| SkippedAction { file, line, reason: reason.to_string() })
+        .collect()
+}
+
+fn all_edits_skipped(plans: &[FixPlan], invalid: &BTreeMap<(usize, usize), String>) -> Vec<SkippedAction> {
+    plans
+        .iter()
+        .enumerate()
+        .flat_map(|(plan_idx, plan)| {
+            let total = plan.actions.len() + plan.fs_edits.len();
+            (0..total).map(move |edit_idx| {
+                let (file, line) = edit_descriptor(plan, edit_idx);
+                let reason = invalid.get(&(plan_idx, edit_idx)).cloned().unwrap_or_else(|| {
+                    "batch aborted: another edit in this batch failed validation".to_string()
+                });
+                SkippedAction { file, line, reason }
+            })
+        })
+        .collect()
+}
+
+/// Apply one action's edit to an in-memory line buffer. When `column` is
+/// set (a precise span from the tree-sitter parse, e.g. a call's
+/// argument-list node), the replace is anchored there so multiple actions
+/// on one line never collide. Otherwise: exact match on the target line,
+/// then a nearby (±2 lines) fuzzy search, then a guidance-comment
+/// fallback -- the heuristic `keel-cli`'s `fix --apply` has always used,
+/// just operating on lines already in memory so a file with several
+/// actions is edited in one pass instead of one read/write round-trip per
+/// action.
+fn apply_action_to_lines(lines: &mut Vec<String>, action: &FixAction) {
+    let idx = (action.line as usize).saturating_sub(1);
+
+    if action.old_text.is_empty() {
+        if idx <= lines.len() {
+            lines.insert(idx, action.new_text.clone());
+        } else {
+            lines.push(action.new_text.clone());
+        }
+        return;
+    }
+
+    if let Some(column) = action.column {
+        let col = column as usize;
+        if let Some(line) = lines.get_mut(idx) {
+            if line.get(col..col + action.old_text.len()) == Some(action.old_text.as_str()) {
+                line.replace_range(col..col + action.old_text.len(), &action.new_text);
+                return;
+            }
+        }
+    }
+
+    if idx < lines.len() && lines[idx].contains(&action.old_text) {
+        lines[idx] = lines[idx].replace(&action.old_text, &action.new_text);
+        return;
+    }
+
+    let start = idx.saturating_sub(2);
+    let end = (idx + 3).min(lines.len());
+    for line in &mut lines[start..end] {
+        if line.contains(&action.old_text) {
+            *line = line.replace(&action.old_text, &action.new_text);
+            return;
+        }
+    }
+
+    let comment = format!("// FIX: {}", action.new_text);
+    if idx <= lines.len() {
+        lines.insert(idx, comment);
+    } else {
+        lines.push(comment);
+    }
+}
+
 /// E002: missing_type_hints — generate stub type annotations.
-fn generate_type_hint_fix(v: &Violation) -> Option<FixPlan> {
-    Some(FixPlan {
+///
+/// Offers two alternatives: a type hint inferred from the literal
+/// arguments passed at call sites (when one can be worked out), and a
+/// generic TODO stub that's always available as a fallback.
+fn generate_type_hint_fix(v: &Violation, store: &dyn GraphStore, base_dir: &Path) -> Vec<FixPlan> {
+    let stub = FixPlan {
         code: v.code.clone(),
         hash: v.hash.clone(),
         category: v.category.clone(),
@@ -165,8 +815,145 @@ fn generate_type_hint_fix(v: &Violation) -> Option<FixPlan> {
             old_text: String::new(),
             new_text: "// TODO: Add type annotations to parameters and return type".to_string(),
             description: "Add type hints".to_string(),
+            column: None,
+            range: None,
         }],
-    })
+        kind: "type_hint_stub".to_string(),
+        confidence: 0.5,
+        fs_edits: vec![],
+    };
+
+    let Some(name) = extract_backtick_name(&v.message) else {
+        return vec![stub];
+    };
+    let Some(params) = infer_param_types(store, base_dir, &v.file, v.line, name) else {
+        return vec![stub];
+    };
+
+    let inferred_count = params.iter().filter(|(_, ty)| ty.is_some()).count();
+    let sig = params
+        .iter()
+        .map(|(param, ty)| format!("{}: {}", param, ty.clone().unwrap_or_else(|| "Any".to_string())))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let inferred = FixPlan {
+        code: v.code.clone(),
+        hash: v.hash.clone(),
+        category: v.category.clone(),
+        target_name: v.message.clone(),
+        cause: format!(
+            "Inferred type hints for `{}` from {} call site argument(s)",
+            name,
+            inferred_count,
+        ),
+        actions: vec![FixAction {
+            file: v.file.clone(),
+            line: v.line,
+            old_text: String::new(),
+            new_text: format!("// TODO: inferred from call sites: def {}({}): ...", name, sig),
+            description: "Add inferred type hints".to_string(),
+            column: None,
+            range: None,
+        }],
+        kind: "inferred_type_hint".to_string(),
+        confidence: 0.4 + 0.4 * (inferred_count as f64 / params.len().max(1) as f64),
+        fs_edits: vec![],
+    };
+
+    if inferred.confidence >= stub.confidence {
+        vec![inferred, stub]
+    } else {
+        vec![stub, inferred]
+    }
+}
+
+/// Pull the name out of a `` `name` `` span in a violation message, e.g.
+/// `"Public function \`foo\` lacks type annotations"` -> `"foo"`.
+fn extract_backtick_name(message: &str) -> Option<&str> {
+    let start = message.find('`')? + 1;
+    let end = start + message[start..].find('`')?;
+    Some(&message[start..end])
+}
+
+/// Best-effort type inference for `name`'s parameters from the literal
+/// arguments passed at its call sites. Python-only for now: TS/Go/Rust are
+/// typed by construction, so E002 only fires on languages where this kind
+/// of syntactic inference is the only signal available.
+fn infer_param_types(
+    store: &dyn GraphStore,
+    base_dir: &Path,
+    file: &str,
+    line: u32,
+    name: &str,
+) -> Option<Vec<(String, Option<String>)>> {
+    let path = base_dir.join(file);
+    if keel_parsers::treesitter::detect_language(&path)? != "python" {
+        return None;
+    }
+
+    let node = store
+        .get_nodes_in_file(file)
+        .into_iter()
+        .find(|n| n.name == name && n.line_start == line)?;
+    let param_names = parse_param_names(&node.signature);
+    if param_names.is_empty() {
+        return None;
+    }
+
+    let mut inferred: Vec<Option<String>> = vec![None; param_names.len()];
+    for edge in store.get_edges(node.id, EdgeDirection::Incoming) {
+        let Some(caller) = store.get_node_by_id(edge.source_id) else { continue };
+        let Some(span) = locate_call_arguments(base_dir, &caller.file_path, edge.line, name) else { continue };
+        for (i, arg) in split_call_args(&span.text).iter().enumerate() {
+            if let Some(slot) = inferred.get_mut(i) {
+                if slot.is_none() {
+                    *slot = infer_literal_type(arg);
+                }
+            }
+        }
+    }
+
+    if inferred.iter().all(Option::is_none) {
+        return None;
+    }
+    Some(param_names.into_iter().zip(inferred).collect())
+}
+
+/// Split a call's argument-list text (parens included) into its
+/// top-level comma-separated argument expressions.
+fn split_call_args(call_args: &str) -> Vec<String> {
+    let inner = call_args.trim_start_matches('(').trim_end_matches(')').trim();
+    if inner.is_empty() {
+        vec![]
+    } else {
+        split_top_level_commas(inner)
+    }
+}
+
+/// Guess a Python type name from a literal argument's source text.
+/// `None` when the argument isn't a recognizable literal (e.g. it's an
+/// identifier or expression, which carries no syntactic type info here).
+fn infer_literal_type(arg: &str) -> Option<String> {
+    let a = arg.trim();
+    if a.is_empty() {
+        return None;
+    }
+    if (a.starts_with('"') && a.ends_with('"')) || (a.starts_with('\'') && a.ends_with('\'')) {
+        Some("str".to_string())
+    } else if a == "True" || a == "False" {
+        Some("bool".to_string())
+    } else if a.starts_with('[') && a.ends_with(']') {
+        Some("list".to_string())
+    } else if a.starts_with('{') && a.ends_with('}') {
+        Some("dict".to_string())
+    } else if a.parse::<i64>().is_ok() {
+        Some("int".to_string())
+    } else if a.parse::<f64>().is_ok() {
+        Some("float".to_string())
+    } else {
+        None
+    }
 }
 
 /// E003: missing_docstring — generate docstring template.
@@ -183,7 +970,12 @@ fn generate_docstring_fix(v: &Violation) -> Option<FixPlan> {
             old_text: String::new(),
             new_text: "/// TODO: Add documentation describing this function's purpose".to_string(),
             description: "Add docstring".to_string(),
+            column: None,
+            range: None,
         }],
+        kind: "docstring_stub".to_string(),
+        confidence: 0.5,
+        fs_edits: vec![],
     })
 }
 
@@ -215,10 +1007,13 @@ mod tests {
     #[test]
     fn test_e002_generates_type_hint_stub() {
         let v = make_violation("E002", "h1");
-        let plan = generate_type_hint_fix(&v).unwrap();
-        assert_eq!(plan.code, "E002");
-        assert_eq!(plan.actions.len(), 1);
-        assert!(plan.actions[0].new_text.contains("type annotations"));
+        let store = keel_core::sqlite::SqliteGraphStore::in_memory().unwrap();
+        let plans = generate_type_hint_fix(&v, &store, Path::new("/tmp"));
+        assert_eq!(plans.len(), 1, "no store entry to infer from -- only the stub alternative");
+        assert_eq!(plans[0].code, "E002");
+        assert_eq!(plans[0].kind, "type_hint_stub");
+        assert_eq!(plans[0].actions.len(), 1);
+        assert!(plans[0].actions[0].new_text.contains("type annotations"));
     }
 
     #[test]
@@ -247,14 +1042,36 @@ mod tests {
                 line: 30,
             },
         ];
-        let plan = generate_removed_function_fix(
+        let plans = generate_removed_function_fix(
             &v,
             &keel_core::sqlite::SqliteGraphStore::in_memory().unwrap(),
+            Path::new("/tmp"),
         );
-        assert!(plan.is_some());
-        let plan = plan.unwrap();
-        assert_eq!(plan.actions.len(), 2);
-        assert!(plan.cause.contains("2 caller(s)"));
+        assert_eq!(plans.len(), 1, "no suggested_module -- only the restore alternative");
+        assert_eq!(plans[0].kind, "restore_function");
+        assert_eq!(plans[0].actions.len(), 2);
+        assert!(plans[0].cause.contains("2 caller(s)"));
+    }
+
+    #[test]
+    fn test_e004_offers_rewrite_callers_when_suggested_module_present() {
+        let mut v = make_violation("E004", "h1");
+        v.affected = vec![AffectedNode {
+            hash: "a1".into(),
+            name: "caller1".into(),
+            file: "src/a.rs".into(),
+            line: 20,
+        }];
+        v.suggested_module = Some("src/util.rs".into());
+        let plans = generate_removed_function_fix(
+            &v,
+            &keel_core::sqlite::SqliteGraphStore::in_memory().unwrap(),
+            Path::new("/tmp"),
+        );
+        assert_eq!(plans.len(), 2);
+        assert_eq!(plans[0].kind, "restore_function");
+        assert_eq!(plans[1].kind, "rewrite_callers");
+        assert!(plans[1].cause.contains("src/util.rs"));
     }
 
     #[test]
@@ -271,18 +1088,480 @@ mod tests {
                 old_text: String::new(),
                 new_text: "// fix".into(),
                 description: "test".into(),
+                column: None,
+                range: None,
             }],
+            kind: "test".into(),
+            confidence: 1.0,
+            fs_edits: vec![],
         };
         let errors = validate_fix_plan(&plan, Path::new("/tmp"));
         assert_eq!(errors.len(), 1);
         assert!(errors[0].1.contains("file not found"));
     }
 
+    #[test]
+    fn test_validate_fix_plan_accepts_range_matching_current_bytes() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "one two three\n").unwrap();
+        // "two" occupies bytes 4..7 of "one two three\n".
+        let plan = plan_with(vec![action_with_range("a.rs", 1, "two", "TWO", 4, 7)]);
+        let errors = validate_fix_plan(&plan, dir.path());
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn test_validate_fix_plan_rejects_stale_range() {
+        let dir = tempfile::tempdir().unwrap();
+        // File on disk no longer contains "two" at that range -- e.g. it
+        // was edited by hand since the plan was generated.
+        std::fs::write(dir.path().join("a.rs"), "one TWO three\n").unwrap();
+        let plan = plan_with(vec![action_with_range("a.rs", 1, "two", "THREE", 4, 7)]);
+        let errors = validate_fix_plan(&plan, dir.path());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].1.contains("stale fix"));
+    }
+
+    #[test]
+    fn test_validate_fix_plan_rejects_range_past_end_of_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "short\n").unwrap();
+        let plan = plan_with(vec![action_with_range("a.rs", 1, "short", "SHORT", 0, 500)]);
+        let errors = validate_fix_plan(&plan, dir.path());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].1.contains("outside"));
+    }
+
     #[test]
     fn test_unsupported_code_returns_none() {
         let v = make_violation("W001", "h1");
         let store = keel_core::sqlite::SqliteGraphStore::in_memory().unwrap();
-        let plan = generate_plan_for_violation(&v, &store);
-        assert!(plan.is_none());
+        let plans = generate_plans_for_violation(&v, &store, Path::new("/tmp"));
+        assert!(plans.is_empty());
+    }
+
+    #[test]
+    fn test_parse_param_names_extracts_identifiers() {
+        assert_eq!(
+            parse_param_names("fn connect(host: String, port: u16) -> Conn"),
+            vec!["host".to_string(), "port".to_string()]
+        );
+        assert_eq!(parse_param_names("fn noop()"), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_rewrite_call_args_appends_placeholder_for_added_param() {
+        let rewritten = rewrite_call_args("(a, b)", &["a".into(), "b".into(), "timeout".into()]);
+        assert_eq!(rewritten, "(a, b, /* TODO: timeout */)");
+    }
+
+    #[test]
+    fn test_rewrite_call_args_drops_removed_trailing_arg() {
+        let rewritten = rewrite_call_args("(a, b, c)", &["a".into(), "b".into()]);
+        assert_eq!(rewritten, "(a, b)");
+    }
+
+    #[test]
+    fn test_parse_param_names_ignores_commas_nested_in_generics() {
+        assert_eq!(
+            parse_param_names("fn f(m: HashMap<String, i32>, n: u16) -> R"),
+            vec!["m".to_string(), "n".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_split_call_args_ignores_commas_nested_in_call() {
+        assert_eq!(
+            split_call_args("(bar(1, 2), 3)"),
+            vec!["bar(1, 2)".to_string(), "3".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_split_call_args_ignores_commas_inside_string_literal() {
+        assert_eq!(
+            split_call_args("(\"a, b\", c)"),
+            vec!["\"a, b\"".to_string(), "c".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_extract_backtick_name() {
+        assert_eq!(
+            extract_backtick_name("Public function `connect` lacks type annotations"),
+            Some("connect")
+        );
+        assert_eq!(extract_backtick_name("no backticks here"), None);
+    }
+
+    #[test]
+    fn test_infer_literal_type() {
+        assert_eq!(infer_literal_type("\"hello\""), Some("str".to_string()));
+        assert_eq!(infer_literal_type("42"), Some("int".to_string()));
+        assert_eq!(infer_literal_type("3.14"), Some("float".to_string()));
+        assert_eq!(infer_literal_type("True"), Some("bool".to_string()));
+        assert_eq!(infer_literal_type("[1, 2]"), Some("list".to_string()));
+        assert_eq!(infer_literal_type("some_var"), None);
+    }
+
+    fn action(file: &str, line: u32, old_text: &str, new_text: &str) -> FixAction {
+        FixAction {
+            file: file.into(),
+            line,
+            old_text: old_text.into(),
+            new_text: new_text.into(),
+            description: "test".into(),
+            column: None,
+            range: None,
+        }
+    }
+
+    fn action_with_range(file: &str, line: u32, old_text: &str, new_text: &str, start_byte: usize, end_byte: usize) -> FixAction {
+        let mut a = action(file, line, old_text, new_text);
+        a.range = Some(TextRange { start_byte, end_byte });
+        a
+    }
+
+    fn plan_with(actions: Vec<FixAction>) -> FixPlan {
+        FixPlan {
+            code: "E002".into(),
+            hash: "h1".into(),
+            category: "test".into(),
+            target_name: "foo".into(),
+            cause: "test".into(),
+            actions,
+            kind: "test".into(),
+            confidence: 1.0,
+            fs_edits: vec![],
+        }
+    }
+
+    #[test]
+    fn test_select_one_plan_per_violation_keeps_highest_confidence() {
+        let mut low = plan_with(vec![action("a.rs", 1, "one", "ONE")]);
+        low.confidence = 0.4;
+        low.kind = "stub".into();
+        let mut high = plan_with(vec![action("a.rs", 1, "one", "TWO")]);
+        high.confidence = 0.8;
+        high.kind = "inferred".into();
+
+        let selected = select_one_plan_per_violation(&[low, high]);
+        assert_eq!(selected.len(), 1, "both alternatives share (code, hash) -- only one should survive");
+        assert_eq!(selected[0].kind, "inferred");
+    }
+
+    #[test]
+    fn test_select_one_plan_per_violation_keeps_distinct_violations() {
+        let mut a = plan_with(vec![action("a.rs", 1, "one", "ONE")]);
+        a.hash = "h1".into();
+        let mut b = plan_with(vec![action("b.rs", 1, "two", "TWO")]);
+        b.hash = "h2".into();
+
+        let selected = select_one_plan_per_violation(&[a, b]);
+        assert_eq!(selected.len(), 2, "distinct (code, hash) violations should both survive");
+    }
+
+    #[test]
+    fn test_select_one_plan_before_apply_does_not_corrupt_file_with_two_alternatives_for_same_violation() {
+        // Regression test: two alternatives targeting the same line used
+        // to be passed straight into apply_fix_plans, which has no notion
+        // of "pick one" -- the second plan's old_text no longer matched
+        // after the first's edit landed, so it fell through to the
+        // unplanned-insertion fallback and corrupted the file. Callers
+        // must run plans through select_one_plan_per_violation first.
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "one\ntwo\nthree\n").unwrap();
+
+        let mut alt1 = plan_with(vec![action("a.rs", 1, "one", "ALT_ONE")]);
+        alt1.hash = "h1".into();
+        alt1.confidence = 0.4;
+        let mut alt2 = plan_with(vec![action("a.rs", 1, "one", "ALT_TWO")]);
+        alt2.hash = "h1".into();
+        alt2.confidence = 0.8;
+
+        let selected = select_one_plan_per_violation(&[alt1, alt2]);
+        let report = apply_fix_plans(&selected, dir.path());
+
+        assert!(report.skipped.is_empty());
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("a.rs")).unwrap(),
+            "ALT_TWO\ntwo\nthree\n",
+            "only the higher-confidence alternative should be applied"
+        );
+    }
+
+    #[test]
+    fn test_apply_fix_plans_groups_edits_by_file_in_one_pass() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "one\ntwo\nthree\n").unwrap();
+        std::fs::write(dir.path().join("b.rs"), "alpha\nbeta\n").unwrap();
+
+        let plans = vec![
+            plan_with(vec![action("a.rs", 1, "one", "ONE")]),
+            plan_with(vec![action("b.rs", 2, "beta", "BETA")]),
+        ];
+        let report = apply_fix_plans(&plans, dir.path());
+
+        assert!(report.skipped.is_empty());
+        assert_eq!(report.files_changed, vec!["a.rs".to_string(), "b.rs".to_string()]);
+        assert_eq!(std::fs::read_to_string(dir.path().join("a.rs")).unwrap(), "ONE\ntwo\nthree\n");
+        assert_eq!(std::fs::read_to_string(dir.path().join("b.rs")).unwrap(), "alpha\nBETA\n");
+    }
+
+    #[test]
+    fn test_apply_fix_plans_applies_multiple_edits_to_one_file_in_reverse_order() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "one\ntwo\nthree\n").unwrap();
+
+        // Line 2 is an insertion (old_text empty); if it weren't applied
+        // after line 3's edit, the insert would shift line 3 out from
+        // under its own edit.
+        let plans = vec![plan_with(vec![
+            action("a.rs", 2, "", "// inserted"),
+            action("a.rs", 3, "three", "THREE"),
+        ])];
+        let report = apply_fix_plans(&plans, dir.path());
+
+        assert!(report.skipped.is_empty());
+        assert_eq!(
+            std::fs::read_to_string(dir.path().join("a.rs")).unwrap(),
+            "one\n// inserted\ntwo\nTHREE\n"
+        );
+    }
+
+    #[test]
+    fn test_apply_fix_plans_aborts_whole_batch_on_one_invalid_action() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "one\ntwo\n").unwrap();
+        // b.rs deliberately not created -- makes this action invalid.
+
+        let plans = vec![
+            plan_with(vec![action("a.rs", 1, "one", "ONE")]),
+            plan_with(vec![action("b.rs", 1, "x", "y")]),
+        ];
+        let report = apply_fix_plans(&plans, dir.path());
+
+        assert!(report.files_changed.is_empty());
+        assert_eq!(report.skipped.len(), 2);
+        assert_eq!(std::fs::read_to_string(dir.path().join("a.rs")).unwrap(), "one\ntwo\n");
+        assert!(report.skipped.iter().any(|s| s.file == "b.rs" && s.reason.contains("file not found")));
+        assert!(report.skipped.iter().any(|s| s.file == "a.rs" && s.reason.contains("batch aborted")));
+    }
+
+    /// Root ignores file-permission bits, so the permission-based failure
+    /// this test relies on wouldn't actually fail under a root-run test
+    /// suite (e.g. inside some CI containers). Skip rather than false-fail.
+    fn running_as_root() -> bool {
+        std::fs::read_to_string("/proc/self/status")
+            .map(|s| s.lines().any(|l| l == "Uid:\t0\t0\t0\t0"))
+            .unwrap_or(false)
+    }
+
+    #[test]
+    fn test_apply_fix_plans_rolls_back_already_written_files_on_later_write_failure() {
+        if running_as_root() {
+            return;
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("a.rs"), "one\n").unwrap();
+        std::fs::write(dir.path().join("b.rs"), "two\n").unwrap();
+        // Readable but not writable -- validate_fix_plan's read succeeds,
+        // but the actual apply write fails.
+        let mut perms = std::fs::metadata(dir.path().join("b.rs")).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(dir.path().join("b.rs"), perms).unwrap();
+
+        let plans = vec![
+            plan_with(vec![action("a.rs", 1, "one", "ONE")]),
+            plan_with(vec![action("b.rs", 1, "two", "TWO")]),
+        ];
+        let report = apply_fix_plans(&plans, dir.path());
+
+        let mut perms = std::fs::metadata(dir.path().join("b.rs")).unwrap().permissions();
+        perms.set_readonly(false);
+        std::fs::set_permissions(dir.path().join("b.rs"), perms).unwrap();
+
+        assert!(report.files_changed.is_empty());
+        assert_eq!(std::fs::read_to_string(dir.path().join("a.rs")).unwrap(), "one\n", "a.rs should have been rolled back");
+    }
+
+    fn test_node(hash: &str, name: &str, file_path: &str) -> keel_core::types::GraphNode {
+        keel_core::types::GraphNode {
+            id: 1,
+            hash: hash.to_string(),
+            kind: keel_core::types::NodeKind::Function,
+            name: name.to_string(),
+            signature: format!("fn {}()", name),
+            file_path: file_path.to_string(),
+            line_start: 1,
+            line_end: 10,
+            docstring: None,
+            is_public: true,
+            type_hints_present: true,
+            has_docstring: false,
+            external_endpoints: vec![],
+            previous_hashes: vec![],
+            module_id: 0,
+            package: None,
+        }
+    }
+
+    #[test]
+    fn test_e004_restores_real_stub_when_node_still_resolvable() {
+        let mut v = make_violation("E004", "h1");
+        v.affected = vec![AffectedNode {
+            hash: "a1".into(),
+            name: "caller1".into(),
+            file: "src/a.rs".into(),
+            line: 20,
+        }];
+
+        let mut store = keel_core::sqlite::SqliteGraphStore::in_memory().unwrap();
+        let node = test_node("h1", "connect", "src/auth.rs");
+        store
+            .update_nodes(vec![keel_core::types::NodeChange::Add(node)])
+            .unwrap();
+
+        let plans = generate_removed_function_fix(&v, &store, Path::new("/tmp"));
+        assert_eq!(plans[0].fs_edits.len(), 1);
+        match &plans[0].fs_edits[0] {
+            FileSystemEdit::CreateFile { path, content } => {
+                assert_eq!(path, "src/auth_restored.rs");
+                assert!(content.contains("connect"));
+            }
+            FileSystemEdit::MoveFile { .. } => panic!("expected CreateFile"),
+        }
+    }
+
+    #[test]
+    fn test_e004_no_fs_edits_when_node_not_resolvable() {
+        let mut v = make_violation("E004", "h1");
+        v.affected = vec![AffectedNode {
+            hash: "a1".into(),
+            name: "caller1".into(),
+            file: "src/a.rs".into(),
+            line: 20,
+        }];
+        let store = keel_core::sqlite::SqliteGraphStore::in_memory().unwrap();
+        let plans = generate_removed_function_fix(&v, &store, Path::new("/tmp"));
+        assert!(plans[0].fs_edits.is_empty());
+    }
+
+    #[test]
+    fn test_generate_module_move_fix_creates_stub_when_target_missing() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut v = make_violation("W001", "h1");
+        v.suggested_module = Some("src/util.rs".into());
+        let plan = generate_module_move_fix(&v, dir.path()).unwrap();
+        assert_eq!(plan.kind, "move_to_suggested_module");
+        assert_eq!(plan.fs_edits.len(), 1);
+        assert!(matches!(&plan.fs_edits[0], FileSystemEdit::CreateFile { path, .. } if path == "src/util.rs"));
+    }
+
+    #[test]
+    fn test_generate_module_move_fix_skips_stub_when_target_exists() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("util.rs"), "// already here\n").unwrap();
+        let mut v = make_violation("W001", "h1");
+        v.suggested_module = Some("util.rs".into());
+        let plan = generate_module_move_fix(&v, dir.path()).unwrap();
+        assert!(plan.fs_edits.is_empty());
+    }
+
+    #[test]
+    fn test_validate_fix_plan_rejects_create_over_existing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("existing.rs"), "// here\n").unwrap();
+        let mut plan = plan_with(vec![]);
+        plan.fs_edits = vec![FileSystemEdit::CreateFile {
+            path: "existing.rs".into(),
+            content: "// new\n".into(),
+        }];
+        let errors = validate_fix_plan(&plan, dir.path());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].1.contains("already exists"));
+    }
+
+    #[test]
+    fn test_validate_fix_plan_rejects_move_with_missing_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let mut plan = plan_with(vec![]);
+        plan.fs_edits = vec![FileSystemEdit::MoveFile {
+            from: "gone.rs".into(),
+            to: "new.rs".into(),
+        }];
+        let errors = validate_fix_plan(&plan, dir.path());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].1.contains("move source not found"));
+    }
+
+    #[test]
+    fn test_validate_fix_plan_rejects_move_over_existing_target() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("from.rs"), "// here\n").unwrap();
+        std::fs::write(dir.path().join("to.rs"), "// here too\n").unwrap();
+        let mut plan = plan_with(vec![]);
+        plan.fs_edits = vec![FileSystemEdit::MoveFile {
+            from: "from.rs".into(),
+            to: "to.rs".into(),
+        }];
+        let errors = validate_fix_plan(&plan, dir.path());
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].1.contains("move target already exists"));
+    }
+
+    #[test]
+    fn test_apply_fix_plans_creates_and_moves_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("old.rs"), "// old\n").unwrap();
+
+        let mut plan = plan_with(vec![]);
+        plan.fs_edits = vec![
+            FileSystemEdit::CreateFile {
+                path: "stub.rs".into(),
+                content: "// stub\n".into(),
+            },
+            FileSystemEdit::MoveFile {
+                from: "old.rs".into(),
+                to: "new.rs".into(),
+            },
+        ];
+        let report = apply_fix_plans(&[plan], dir.path());
+
+        assert!(report.skipped.is_empty());
+        assert!(report.files_changed.contains(&"stub.rs".to_string()));
+        assert!(report.files_changed.contains(&"new.rs".to_string()));
+        assert_eq!(std::fs::read_to_string(dir.path().join("stub.rs")).unwrap(), "// stub\n");
+        assert!(!dir.path().join("old.rs").exists());
+        assert_eq!(std::fs::read_to_string(dir.path().join("new.rs")).unwrap(), "// old\n");
+    }
+
+    #[test]
+    fn test_apply_fix_plans_rolls_back_fs_edits_on_later_write_failure() {
+        if running_as_root() {
+            return;
+        }
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("b.rs"), "two\n").unwrap();
+        let mut perms = std::fs::metadata(dir.path().join("b.rs")).unwrap().permissions();
+        perms.set_readonly(true);
+        std::fs::set_permissions(dir.path().join("b.rs"), perms).unwrap();
+
+        let mut create_plan = plan_with(vec![]);
+        create_plan.fs_edits = vec![FileSystemEdit::CreateFile {
+            path: "stub.rs".into(),
+            content: "// stub\n".into(),
+        }];
+        let text_plan = plan_with(vec![action("b.rs", 1, "two", "TWO")]);
+
+        let report = apply_fix_plans(&[create_plan, text_plan], dir.path());
+
+        let mut perms = std::fs::metadata(dir.path().join("b.rs")).unwrap().permissions();
+        perms.set_readonly(false);
+        std::fs::set_permissions(dir.path().join("b.rs"), perms).unwrap();
+
+        assert!(report.files_changed.is_empty());
+        assert!(!dir.path().join("stub.rs").exists(), "create should have been rolled back");
     }
 }
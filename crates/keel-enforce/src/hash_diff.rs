@@ -164,7 +164,7 @@ mod tests {
     use keel_core::sqlite::SqliteGraphStore;
     use keel_core::store::GraphStore;
     use keel_core::types::{EdgeChange, EdgeKind, GraphEdge, GraphNode, NodeKind};
-    use keel_parsers::resolver::{Definition, FileIndex};
+    use keel_parsers::resolver::{Definition, FileIndex, Namespace};
 
     use crate::engine::EnforcementEngine;
 
@@ -185,6 +185,7 @@ mod tests {
             external_endpoints: vec![],
             previous_hashes: vec![],
             module_id: 0,
+            package: None,
         }
     }
 
@@ -211,6 +212,7 @@ mod tests {
             is_public: true,
             type_hints_present: true,
             body_text: body.to_string(),
+            namespace: Namespace::Value,
         }
     }
 
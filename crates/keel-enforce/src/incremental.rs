@@ -0,0 +1,327 @@
+//! Incremental compile: skip files whose content hasn't changed since the
+//! last compile, while still re-checking anything that depends on a file
+//! that did change.
+//!
+//! Every compile persists each file's `content_hash` to
+//! `.keel/file_hashes.json`. On the next compile, a file is only skipped
+//! when its hash matches the cached one *and* none of the files it
+//! transitively imports from changed either -- otherwise cross-file rules
+//! like E001/E004/E005 could miss a caller that's now broken.
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use keel_parsers::resolver::FileIndex;
+
+use crate::engine::EnforcementEngine;
+use crate::types::CompileResult;
+
+/// Persisted content_hash per file from the last compile.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FileHashCache {
+    hashes: HashMap<String, u64>,
+}
+
+impl FileHashCache {
+    /// Load the cache from `.keel/file_hashes.json`. Returns an empty
+    /// cache (everything looks changed) if the file doesn't exist yet.
+    pub fn load(keel_dir: &Path) -> Self {
+        let path = keel_dir.join("file_hashes.json");
+        std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+
+    /// Save the cache to `.keel/file_hashes.json`.
+    pub fn save(&self, keel_dir: &Path) -> Result<(), String> {
+        let path = keel_dir.join("file_hashes.json");
+        let json = serde_json::to_string(self)
+            .map_err(|e| format!("failed to serialize file hash cache: {}", e))?;
+        std::fs::write(&path, json)
+            .map_err(|e| format!("failed to write file hash cache to {}: {}", path.display(), e))
+    }
+
+    /// True if `file`'s content_hash differs from (or is absent from) the
+    /// cache -- i.e. it needs recompiling on its own merits.
+    fn is_changed(&self, file: &FileIndex) -> bool {
+        self.hashes.get(&file.file_path) != Some(&file.content_hash)
+    }
+
+    /// Record the latest content_hash for every file just compiled.
+    fn update(&mut self, files: &[FileIndex]) {
+        for f in files {
+            self.hashes.insert(f.file_path.clone(), f.content_hash);
+        }
+    }
+}
+
+/// Build a reverse-dependency map from the imports/references already
+/// captured in each `FileIndex`: file -> the other files (in this batch)
+/// that import a symbol it defines.
+fn build_reverse_deps(files: &[FileIndex]) -> HashMap<String, Vec<String>> {
+    let mut def_owners: HashMap<&str, Vec<&str>> = HashMap::new();
+    for f in files {
+        for def in &f.definitions {
+            def_owners
+                .entry(def.name.as_str())
+                .or_default()
+                .push(f.file_path.as_str());
+        }
+    }
+
+    let mut reverse: HashMap<String, Vec<String>> = HashMap::new();
+    let mut mark_dependency = |owner: &str, dependent: &str, reverse: &mut HashMap<String, Vec<String>>| {
+        if owner != dependent {
+            reverse
+                .entry(owner.to_string())
+                .or_default()
+                .push(dependent.to_string());
+        }
+    };
+
+    for f in files {
+        for imp in &f.imports {
+            for name in &imp.imported_names {
+                if let Some(owners) = def_owners.get(name.as_str()) {
+                    for owner in owners {
+                        mark_dependency(owner, &f.file_path, &mut reverse);
+                    }
+                }
+            }
+        }
+        for r in &f.references {
+            if let Some(owners) = def_owners.get(r.name.as_str()) {
+                for owner in owners {
+                    mark_dependency(owner, &f.file_path, &mut reverse);
+                }
+            }
+        }
+    }
+
+    for deps in reverse.values_mut() {
+        deps.sort();
+        deps.dedup();
+    }
+    reverse
+}
+
+/// Walk the reverse-dependency graph from `changed` files to find every
+/// file that transitively depends on one of them.
+fn transitive_dependents(
+    changed: &HashSet<String>,
+    reverse_deps: &HashMap<String, Vec<String>>,
+) -> HashSet<String> {
+    let mut dirty = changed.clone();
+    let mut queue: VecDeque<String> = changed.iter().cloned().collect();
+    while let Some(file) = queue.pop_front() {
+        if let Some(dependents) = reverse_deps.get(&file) {
+            for dep in dependents {
+                if dirty.insert(dep.clone()) {
+                    queue.push_back(dep.clone());
+                }
+            }
+        }
+    }
+    dirty
+}
+
+/// Decide which of `files` need (re-)compiling: anything whose content
+/// hash changed, plus anything that transitively depends on a changed
+/// file via imports/references. `force` bypasses the cache and returns
+/// every file.
+fn select_dirty_files<'a>(
+    files: &'a [FileIndex],
+    cache: &FileHashCache,
+    force: bool,
+) -> Vec<&'a FileIndex> {
+    if force {
+        return files.iter().collect();
+    }
+
+    let changed_paths: HashSet<String> = files
+        .iter()
+        .filter(|f| cache.is_changed(f))
+        .map(|f| f.file_path.clone())
+        .collect();
+
+    if changed_paths.is_empty() {
+        return Vec::new();
+    }
+    if changed_paths.len() == files.len() {
+        // Nothing was skippable anyway -- no point walking the dep graph.
+        return files.iter().collect();
+    }
+
+    let reverse_deps = build_reverse_deps(files);
+    let dirty_paths = transitive_dependents(&changed_paths, &reverse_deps);
+
+    files
+        .iter()
+        .filter(|f| dirty_paths.contains(&f.file_path))
+        .collect()
+}
+
+impl EnforcementEngine {
+    /// Incremental variant of `compile`: skips files whose `content_hash`
+    /// is unchanged since the last call, unless they transitively depend
+    /// on a file that *did* change. `files_analyzed` on the result reports
+    /// both directly-changed and dependent-rechecked files; skipped files
+    /// are omitted entirely. `force` bypasses the cache and checks
+    /// everything, same as a non-incremental compile.
+    pub fn compile_incremental(
+        &mut self,
+        files: &[FileIndex],
+        cache: &mut FileHashCache,
+        force: bool,
+    ) -> CompileResult {
+        let dirty: Vec<FileIndex> = select_dirty_files(files, cache, force)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        let result = self.compile(&dirty);
+        cache.update(files);
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use keel_core::sqlite::SqliteGraphStore;
+    use keel_parsers::resolver::{Import, Namespace, Reference, ReferenceKind};
+
+    fn make_file(path: &str, hash: u64, def_names: &[&str]) -> FileIndex {
+        FileIndex {
+            file_path: path.to_string(),
+            content_hash: hash,
+            definitions: def_names
+                .iter()
+                .map(|n| keel_parsers::resolver::Definition {
+                    name: n.to_string(),
+                    kind: keel_core::types::NodeKind::Function,
+                    signature: format!("fn {}()", n),
+                    file_path: path.to_string(),
+                    line_start: 1,
+                    line_end: 2,
+                    docstring: None,
+                    is_public: true,
+                    type_hints_present: true,
+                    body_text: "{}".to_string(),
+                    namespace: Namespace::Value,
+                })
+                .collect(),
+            references: vec![],
+            imports: vec![],
+            external_endpoints: vec![],
+            parse_duration_us: 0,
+        }
+    }
+
+    #[test]
+    fn test_cache_detects_unchanged_file() {
+        let mut cache = FileHashCache::default();
+        let file = make_file("a.rs", 42, &["foo"]);
+        cache.update(&[file.clone()]);
+        assert!(!cache.is_changed(&file));
+
+        let changed = make_file("a.rs", 99, &["foo"]);
+        assert!(cache.is_changed(&changed));
+    }
+
+    #[test]
+    fn test_select_dirty_files_skips_unchanged() {
+        let mut cache = FileHashCache::default();
+        let a = make_file("a.rs", 1, &["foo"]);
+        let b = make_file("b.rs", 2, &["bar"]);
+        cache.update(&[a.clone(), b.clone()]);
+
+        let a_changed = make_file("a.rs", 2, &["foo"]);
+        let files = vec![a_changed, b];
+        let dirty = select_dirty_files(&files, &cache, false);
+        assert_eq!(dirty.len(), 1);
+        assert_eq!(dirty[0].file_path, "a.rs");
+    }
+
+    #[test]
+    fn test_select_dirty_files_includes_dependents() {
+        let mut cache = FileHashCache::default();
+        let lib = make_file("lib.rs", 1, &["helper"]);
+        let mut caller = make_file("caller.rs", 2, &["use_helper"]);
+        caller.imports.push(Import {
+            source: "lib".to_string(),
+            imported_names: vec!["helper".to_string()],
+            file_path: "caller.rs".to_string(),
+            line: 1,
+            is_relative: true,
+        });
+        cache.update(&[lib.clone(), caller.clone()]);
+
+        // Only lib.rs's content changed; caller.rs is untouched but
+        // depends on it, so it must still be rechecked.
+        let lib_changed = make_file("lib.rs", 2, &["helper"]);
+        let files = vec![lib_changed, caller];
+        let dirty = select_dirty_files(&files, &cache, false);
+        let mut paths: Vec<_> = dirty.iter().map(|f| f.file_path.clone()).collect();
+        paths.sort();
+        assert_eq!(paths, vec!["caller.rs".to_string(), "lib.rs".to_string()]);
+    }
+
+    #[test]
+    fn test_select_dirty_files_via_reference() {
+        let mut cache = FileHashCache::default();
+        let lib = make_file("lib.rs", 1, &["helper"]);
+        let mut caller = make_file("caller.rs", 2, &["main"]);
+        caller.references.push(Reference {
+            name: "helper".to_string(),
+            file_path: "caller.rs".to_string(),
+            line: 3,
+            kind: ReferenceKind::Call,
+            resolved_to: None,
+            namespace: Namespace::Value,
+        });
+        cache.update(&[lib.clone(), caller.clone()]);
+
+        let lib_changed = make_file("lib.rs", 2, &["helper"]);
+        let files = vec![lib_changed, caller];
+        let dirty = select_dirty_files(&files, &cache, false);
+        assert_eq!(dirty.len(), 2);
+    }
+
+    #[test]
+    fn test_select_dirty_files_nothing_changed() {
+        let cache_files = vec![make_file("a.rs", 1, &["foo"])];
+        let mut cache = FileHashCache::default();
+        cache.update(&cache_files);
+
+        let dirty = select_dirty_files(&cache_files, &cache, false);
+        assert!(dirty.is_empty());
+    }
+
+    #[test]
+    fn test_force_bypasses_cache() {
+        let files = vec![make_file("a.rs", 1, &["foo"])];
+        let mut cache = FileHashCache::default();
+        cache.update(&files);
+
+        let dirty = select_dirty_files(&files, &cache, true);
+        assert_eq!(dirty.len(), 1);
+    }
+
+    #[test]
+    fn test_compile_incremental_skips_unchanged_file() {
+        let store = SqliteGraphStore::in_memory().unwrap();
+        let mut engine = EnforcementEngine::new(Box::new(store));
+        let mut cache = FileHashCache::default();
+
+        let file = make_file("a.rs", 1, &["foo"]);
+        let r1 = engine.compile_incremental(&[file.clone()], &mut cache, false);
+        assert_eq!(r1.files_analyzed, vec!["a.rs".to_string()]);
+
+        let r2 = engine.compile_incremental(&[file], &mut cache, false);
+        assert!(r2.files_analyzed.is_empty());
+    }
+}
@@ -6,6 +6,7 @@
 //! - E003: missing docstrings on public functions
 //! - E004: function removed (callers reference deleted function)
 //! - E005: arity mismatch (caller passes wrong number of arguments)
+//! - E006: layer violation (cross-package edge forbidden by `monorepo.layers`)
 //! - W001: placement suggestion (function may belong in a different module)
 //! - W002: duplicate name (same function name in multiple modules)
 
@@ -16,7 +17,9 @@ pub mod circuit_breaker;
 pub mod engine;
 pub mod fix_generator;
 pub mod hash_diff;
+pub mod incremental;
 pub mod naming;
+pub mod query;
 pub mod snapshot;
 pub mod suppress;
 pub mod types;
@@ -149,15 +149,39 @@ fn compute_keyword_score(desc_words: &[String], module_keywords: &[String]) -> f
     if desc_words.is_empty() || module_keywords.is_empty() {
         return 0.0;
     }
-    let matches = desc_words
+    let total: f64 = desc_words
         .iter()
-        .filter(|w| {
-            module_keywords
-                .iter()
-                .any(|k| k.contains(w.as_str()) || w.contains(k.as_str()))
+        .map(|w| keyword_match_weight(w, module_keywords))
+        .sum();
+    total / desc_words.len() as f64
+}
+
+/// How strongly `word` matches any of `module_keywords`: `1.0` for a
+/// substring match either way (the original behavior), else a partial
+/// weight of `1 - dist/maxlen` for the closest keyword within edit
+/// distance 2, so a typo like "authentcate" still counts toward
+/// "authenticate" instead of scoring 0, else `0.0`.
+fn keyword_match_weight(word: &str, module_keywords: &[String]) -> f64 {
+    const FUZZY_MAX_DISTANCE: usize = 2;
+
+    if module_keywords
+        .iter()
+        .any(|k| k.contains(word) || word.contains(k.as_str()))
+    {
+        return 1.0;
+    }
+
+    module_keywords
+        .iter()
+        .filter_map(|k| {
+            let maxlen = word.chars().count().max(k.chars().count());
+            if maxlen == 0 {
+                return None;
+            }
+            keel_core::levenshtein::distance_within(word, k, FUZZY_MAX_DISTANCE)
+                .map(|dist| 1.0 - (dist as f64 / maxlen as f64))
         })
-        .count();
-    matches as f64 / desc_words.len() as f64
+        .fold(0.0_f64, f64::max)
 }
 
 /// Fallback scoring when module_profiles have no keywords.
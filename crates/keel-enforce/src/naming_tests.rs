@@ -28,6 +28,14 @@ fn test_keyword_score_no_overlap() {
     assert_eq!(score, 0.0);
 }
 
+#[test]
+fn test_keyword_score_partial_credit_for_near_miss_spelling() {
+    let desc = vec!["authentcate".to_string()]; // missing the 'i'
+    let module_kw = vec!["authenticate".to_string()];
+    let score = compute_keyword_score(&desc, &module_kw);
+    assert!(score > 0.0 && score < 1.0, "typo should get partial, not full or zero, credit: {score}");
+}
+
 #[test]
 fn test_detect_snake_case() {
     let names = vec!["validate_token", "validate_session", "check_auth"];
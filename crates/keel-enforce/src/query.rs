@@ -0,0 +1,392 @@
+//! Datalog-style query evaluator over the code graph.
+//!
+//! Queries are a conjunction of predicate literals followed by a projection
+//! list, e.g.:
+//!
+//! ```text
+//! node(H, Name, File, "function"), in_module(H, M) | H, Name, File
+//! ```
+//!
+//! Supported predicates:
+//! - `node(Hash, Name, File, Kind)`
+//! - `calls(Caller, Callee, Line)`
+//! - `in_module(Hash, ModuleId)`
+//! - `reaches(Start, End)` — transitive closure of `calls`, seeded from a
+//!   bound `Start` and expanded breadth-first up to an optional depth bound
+//!
+//! Evaluation is bottom-up: each literal is turned into a set of tuples by
+//! [`SqliteGraphStore`]'s raw query methods (bound arguments become SQL
+//! `WHERE` conditions, unbound arguments are wildcard columns), and
+//! successive literals' tuples are hash-joined into the running set of
+//! variable bindings on whatever variables they share, the same way a
+//! textbook bottom-up Datalog evaluator joins one relation at a time.
+
+use std::collections::{HashMap, HashSet};
+
+use keel_core::sqlite::SqliteGraphStore;
+
+/// A parsed query: its literals and the variables to project in the result.
+#[derive(Debug, Clone)]
+pub struct ParsedQuery {
+    literals: Vec<Literal>,
+    projection: Vec<String>,
+}
+
+/// One argument of a predicate literal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Term {
+    Var(String),
+    Const(String),
+    Wildcard,
+}
+
+impl Term {
+    fn parse(token: &str) -> Term {
+        let token = token.trim();
+        if token == "_" {
+            Term::Wildcard
+        } else if token.len() >= 2 && token.starts_with('"') && token.ends_with('"') {
+            Term::Const(token[1..token.len() - 1].to_string())
+        } else if token.chars().next().is_some_and(|c| c.is_uppercase()) {
+            Term::Var(token.to_string())
+        } else {
+            Term::Const(token.to_string())
+        }
+    }
+
+    fn bound(&self) -> Option<&str> {
+        match self {
+            Term::Const(c) => Some(c.as_str()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Literal {
+    Node {
+        hash: Term,
+        name: Term,
+        file: Term,
+        kind: Term,
+    },
+    Calls {
+        caller: Term,
+        callee: Term,
+        line: Term,
+    },
+    InModule {
+        hash: Term,
+        module_id: Term,
+    },
+    Reaches {
+        start: Term,
+        end: Term,
+    },
+}
+
+impl Literal {
+    fn build(name: &str, args: Vec<Term>) -> Result<Literal, QueryError> {
+        match (name, args.len()) {
+            ("node", 4) => Ok(Literal::Node {
+                hash: args[0].clone(),
+                name: args[1].clone(),
+                file: args[2].clone(),
+                kind: args[3].clone(),
+            }),
+            ("calls", 3) => Ok(Literal::Calls {
+                caller: args[0].clone(),
+                callee: args[1].clone(),
+                line: args[2].clone(),
+            }),
+            ("in_module", 2) => Ok(Literal::InModule {
+                hash: args[0].clone(),
+                module_id: args[1].clone(),
+            }),
+            ("reaches", 2) => Ok(Literal::Reaches {
+                start: args[0].clone(),
+                end: args[1].clone(),
+            }),
+            (other, arity) => Err(QueryError::UnknownPredicate(format!("{other}/{arity}"))),
+        }
+    }
+
+    /// Positional argument terms, in the order raw tuples are returned.
+    fn arg_terms(&self) -> Vec<Term> {
+        match self {
+            Literal::Node {
+                hash,
+                name,
+                file,
+                kind,
+            } => vec![hash.clone(), name.clone(), file.clone(), kind.clone()],
+            Literal::Calls {
+                caller,
+                callee,
+                line,
+            } => vec![caller.clone(), callee.clone(), line.clone()],
+            Literal::InModule { hash, module_id } => vec![hash.clone(), module_id.clone()],
+            Literal::Reaches { start, end } => vec![start.clone(), end.clone()],
+        }
+    }
+}
+
+/// Errors in the query text itself, reported to the caller as a bad request
+/// rather than surfaced as a 500 -- a malformed query is user error, not a
+/// server fault.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum QueryError {
+    #[error("query syntax error: {0}")]
+    Syntax(String),
+
+    #[error("unknown predicate `{0}`")]
+    UnknownPredicate(String),
+}
+
+/// Parse a query string into its literals and projection list.
+pub fn parse_query(text: &str) -> Result<ParsedQuery, QueryError> {
+    let (body, projection_text) = text
+        .split_once('|')
+        .ok_or_else(|| QueryError::Syntax("missing '|' projection separator".to_string()))?;
+
+    let literals = parse_literals(body)?;
+    if literals.is_empty() {
+        return Err(QueryError::Syntax("query has no literals".to_string()));
+    }
+
+    let projection: Vec<String> = projection_text
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+    if projection.is_empty() {
+        return Err(QueryError::Syntax("projection list is empty".to_string()));
+    }
+
+    Ok(ParsedQuery {
+        literals,
+        projection,
+    })
+}
+
+fn parse_literals(body: &str) -> Result<Vec<Literal>, QueryError> {
+    let mut literals = Vec::new();
+    let mut rest = body.trim();
+
+    while !rest.is_empty() {
+        let open = rest
+            .find('(')
+            .ok_or_else(|| QueryError::Syntax("expected '(' after predicate name".to_string()))?;
+        let pred_name = rest[..open].trim();
+        let close = rest[open..]
+            .find(')')
+            .map(|i| i + open)
+            .ok_or_else(|| QueryError::Syntax("unmatched '('".to_string()))?;
+
+        let args: Vec<Term> = rest[open + 1..close]
+            .split(',')
+            .map(|a| Term::parse(a))
+            .collect();
+        literals.push(Literal::build(pred_name, args)?);
+
+        rest = rest[close + 1..].trim_start();
+        rest = rest.trim_start_matches(',').trim_start();
+    }
+
+    Ok(literals)
+}
+
+/// A fully-evaluated query: the projected column names and one row per
+/// distinct binding that satisfied every literal.
+#[derive(Debug, Clone)]
+pub struct QueryOutcome {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+/// Evaluate `query` against `store`, bottom-up: each literal's tuples are
+/// hash-joined into the running set of bindings on whatever variables they
+/// share with the bindings accumulated so far. `max_depth` bounds any
+/// `reaches` literal's worklist expansion; `None` means unbounded.
+pub fn evaluate_query(
+    store: &SqliteGraphStore,
+    query: &ParsedQuery,
+    max_depth: Option<u32>,
+) -> QueryOutcome {
+    let mut bindings: Vec<HashMap<String, String>> = vec![HashMap::new()];
+
+    for literal in &query.literals {
+        let arg_terms = literal.arg_terms();
+        let tuples = eval_literal(store, literal, max_depth);
+        bindings = join(bindings, &arg_terms, tuples);
+        if bindings.is_empty() {
+            break;
+        }
+    }
+
+    let rows = bindings
+        .iter()
+        .map(|binding| {
+            query
+                .projection
+                .iter()
+                .map(|var| binding.get(var).cloned().unwrap_or_default())
+                .collect()
+        })
+        .collect();
+
+    QueryOutcome {
+        columns: query.projection.clone(),
+        rows,
+    }
+}
+
+fn eval_literal(
+    store: &SqliteGraphStore,
+    literal: &Literal,
+    max_depth: Option<u32>,
+) -> Vec<Vec<String>> {
+    match literal {
+        Literal::Node {
+            hash,
+            name,
+            file,
+            kind,
+        } => store
+            .query_node_tuples(hash.bound(), name.bound(), file.bound(), kind.bound())
+            .into_iter()
+            .map(|row| row.to_vec())
+            .collect(),
+        Literal::Calls { caller, callee, .. } => store
+            .query_calls_tuples(caller.bound(), callee.bound())
+            .into_iter()
+            .map(|(caller, callee, line)| vec![caller, callee, line.to_string()])
+            .collect(),
+        Literal::InModule { hash, module_id } => store
+            .query_in_module_tuples(hash.bound(), module_id.bound())
+            .into_iter()
+            .map(|(hash, module_id)| vec![hash, module_id])
+            .collect(),
+        Literal::Reaches { start, .. } => {
+            let Some(seed) = start.bound() else {
+                // `reaches` needs a bound seed to walk the worklist from --
+                // an unbound start has no tuples to emit.
+                return Vec::new();
+            };
+            expand_reachable(store, seed, max_depth)
+                .into_iter()
+                .map(|reached| vec![seed.to_string(), reached])
+                .collect()
+        }
+    }
+}
+
+/// Breadth-first expansion of `calls` edges from `seed`, up to `max_depth`
+/// hops (unbounded if `None`), deduplicating visited node hashes so cycles
+/// in the call graph terminate the worklist instead of looping forever.
+fn expand_reachable(store: &SqliteGraphStore, seed: &str, max_depth: Option<u32>) -> Vec<String> {
+    let mut visited: HashSet<String> = HashSet::new();
+    visited.insert(seed.to_string());
+
+    let mut frontier = vec![seed.to_string()];
+    let mut reached = Vec::new();
+    let mut depth = 0;
+
+    while !frontier.is_empty() {
+        if let Some(bound) = max_depth {
+            if depth >= bound {
+                break;
+            }
+        }
+        depth += 1;
+
+        let mut next_frontier = Vec::new();
+        for caller in &frontier {
+            for (_, callee, _) in store.query_calls_tuples(Some(caller), None) {
+                if visited.insert(callee.clone()) {
+                    reached.push(callee.clone());
+                    next_frontier.push(callee);
+                }
+            }
+        }
+        frontier = next_frontier;
+    }
+
+    reached
+}
+
+/// Hash-join `bindings` with `tuples`, whose columns correspond positionally
+/// to `arg_terms`. A variable repeated within a single literal's own
+/// arguments (e.g. `calls(H, H, _)`) must agree across its columns in a
+/// tuple before that tuple is even considered; a variable shared with the
+/// bindings accumulated so far must agree with the existing binding.
+fn join(
+    bindings: Vec<HashMap<String, String>>,
+    arg_terms: &[Term],
+    tuples: Vec<Vec<String>>,
+) -> Vec<HashMap<String, String>> {
+    let existing_vars: HashSet<&String> = bindings.iter().flat_map(|b| b.keys()).collect();
+    let shared: Vec<String> = arg_terms
+        .iter()
+        .filter_map(|t| match t {
+            Term::Var(v) if existing_vars.contains(v) => Some(v.clone()),
+            _ => None,
+        })
+        .collect();
+
+    let mut probe: HashMap<Vec<String>, Vec<&HashMap<String, String>>> = HashMap::new();
+    for binding in &bindings {
+        let key: Vec<String> = shared
+            .iter()
+            .map(|v| binding.get(v).cloned().unwrap_or_default())
+            .collect();
+        probe.entry(key).or_default().push(binding);
+    }
+
+    let mut result = Vec::new();
+    for tuple in &tuples {
+        let mut local: HashMap<String, String> = HashMap::new();
+        let mut consistent = true;
+        for (term, value) in arg_terms.iter().zip(tuple.iter()) {
+            if let Term::Const(c) = term {
+                if c != value {
+                    consistent = false;
+                    break;
+                }
+            }
+            if let Term::Var(v) = term {
+                match local.get(v) {
+                    Some(existing) if existing != value => {
+                        consistent = false;
+                        break;
+                    }
+                    Some(_) => {}
+                    None => {
+                        local.insert(v.clone(), value.clone());
+                    }
+                }
+            }
+        }
+        if !consistent {
+            continue;
+        }
+
+        let key: Vec<String> = shared
+            .iter()
+            .map(|v| local.get(v).cloned().unwrap_or_default())
+            .collect();
+        if let Some(matches) = probe.get(&key) {
+            for binding in matches {
+                let mut merged = (*binding).clone();
+                merged.extend(local.clone());
+                result.push(merged);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+#[path = "query_tests.rs"]
+mod tests;
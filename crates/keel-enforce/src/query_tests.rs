@@ -0,0 +1,146 @@
+use super::*;
+use keel_core::sqlite::SqliteGraphStore;
+use keel_core::store::GraphStore;
+use keel_core::types::{EdgeChange, EdgeKind, GraphEdge, GraphNode, NodeKind};
+
+fn make_node(id: u64, hash: &str, name: &str, file: &str, module_id: u64) -> GraphNode {
+    GraphNode {
+        id,
+        hash: hash.to_string(),
+        kind: NodeKind::Function,
+        name: name.to_string(),
+        signature: format!("fn {name}()"),
+        file_path: file.to_string(),
+        line_start: 1,
+        line_end: 5,
+        docstring: None,
+        is_public: true,
+        type_hints_present: true,
+        has_docstring: false,
+        external_endpoints: vec![],
+        previous_hashes: vec![],
+        module_id,
+        package: None,
+    }
+}
+
+fn make_call_edge(id: u64, src: u64, tgt: u64, line: u32) -> GraphEdge {
+    GraphEdge {
+        id,
+        source_id: src,
+        target_id: tgt,
+        kind: EdgeKind::Calls,
+        file_path: "src/lib.rs".to_string(),
+        line,
+        confidence: 1.0,
+    }
+}
+
+fn store_with_chain() -> SqliteGraphStore {
+    // handler -> service -> repo, a 3-hop calls chain.
+    let mut store = SqliteGraphStore::in_memory().unwrap();
+    store
+        .insert_node(&make_node(1, "handlerHash", "handle", "src/handler.rs", 0))
+        .unwrap();
+    store
+        .insert_node(&make_node(2, "serviceHash", "serve", "src/service.rs", 7))
+        .unwrap();
+    store
+        .insert_node(&make_node(3, "repoHash", "query", "src/repo.rs", 7))
+        .unwrap();
+    store
+        .update_edges(vec![
+            EdgeChange::Add(make_call_edge(1, 1, 2, 10)),
+            EdgeChange::Add(make_call_edge(2, 2, 3, 20)),
+        ])
+        .unwrap();
+    store
+}
+
+#[test]
+fn test_parse_simple_node_query() {
+    let parsed = parse_query("node(H, Name, File, \"function\") | H, Name").unwrap();
+    assert_eq!(parsed.projection, vec!["H".to_string(), "Name".to_string()]);
+    assert_eq!(parsed.literals.len(), 1);
+}
+
+#[test]
+fn test_parse_rejects_missing_projection_separator() {
+    let err = parse_query("node(H, Name, File, Kind)").unwrap_err();
+    assert!(matches!(err, QueryError::Syntax(_)));
+}
+
+#[test]
+fn test_parse_rejects_unknown_predicate() {
+    let err = parse_query("bogus(H, Name) | H").unwrap_err();
+    assert!(matches!(err, QueryError::UnknownPredicate(_)));
+}
+
+#[test]
+fn test_evaluate_node_predicate_projects_bound_columns() {
+    let store = store_with_chain();
+    let parsed = parse_query("node(H, Name, File, \"function\") | H, Name").unwrap();
+    let outcome = evaluate_query(&store, &parsed, None);
+
+    assert_eq!(outcome.columns, vec!["H".to_string(), "Name".to_string()]);
+    assert_eq!(outcome.rows.len(), 3);
+    assert!(outcome
+        .rows
+        .iter()
+        .any(|r| r == &vec!["handlerHash".to_string(), "handle".to_string()]));
+}
+
+#[test]
+fn test_evaluate_joins_node_and_calls_on_shared_variable() {
+    let store = store_with_chain();
+    let parsed =
+        parse_query("node(H, Name, _, \"function\"), calls(H, Callee, _) | Name, Callee").unwrap();
+    let outcome = evaluate_query(&store, &parsed, None);
+
+    assert_eq!(
+        outcome.rows,
+        vec![
+            vec!["handle".to_string(), "serviceHash".to_string()],
+            vec!["serve".to_string(), "repoHash".to_string()],
+        ]
+    );
+}
+
+#[test]
+fn test_evaluate_in_module_filters_by_module_id() {
+    let store = store_with_chain();
+    let parsed = parse_query("in_module(H, \"7\") | H").unwrap();
+    let outcome = evaluate_query(&store, &parsed, None);
+
+    let hashes: Vec<String> = outcome.rows.into_iter().flatten().collect();
+    assert_eq!(hashes.len(), 2);
+    assert!(hashes.contains(&"serviceHash".to_string()));
+    assert!(hashes.contains(&"repoHash".to_string()));
+}
+
+#[test]
+fn test_evaluate_reaches_follows_transitive_calls_to_depth_bound() {
+    let store = store_with_chain();
+    let parsed = parse_query("reaches(\"handlerHash\", Reached) | Reached").unwrap();
+
+    let unbounded = evaluate_query(&store, &parsed, None);
+    let mut reached: Vec<String> = unbounded.rows.into_iter().flatten().collect();
+    reached.sort();
+    assert_eq!(
+        reached,
+        vec!["repoHash".to_string(), "serviceHash".to_string()]
+    );
+
+    let one_hop = evaluate_query(&store, &parsed, Some(1));
+    assert_eq!(one_hop.rows, vec![vec!["serviceHash".to_string()]]);
+}
+
+#[test]
+fn test_evaluate_empty_join_short_circuits_to_no_rows() {
+    let store = store_with_chain();
+    let parsed =
+        parse_query("node(H, \"nonexistent\", _, \"function\"), calls(H, Callee, _) | Callee")
+            .unwrap();
+    let outcome = evaluate_query(&store, &parsed, None);
+    assert!(outcome.rows.is_empty());
+}
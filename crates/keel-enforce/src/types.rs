@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -52,6 +54,51 @@ pub struct CompileInfo {
     pub hashes_changed: Vec<String>,
 }
 
+/// Per-rule evaluation counts from the last `compile`, analogous to a
+/// test-coverage report: how many definitions a rule was evaluated
+/// against, and how many of those it flagged. A rule with `evaluated > 0`
+/// and `flagged == 0` across an entire run is a candidate for a dead or
+/// misconfigured rule. See `EnforcementEngine::rule_coverage`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RuleCoverage {
+    pub rule: String,
+    pub evaluated: u32,
+    pub flagged: u32,
+    pub per_file: Vec<FileRuleCoverage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileRuleCoverage {
+    pub file: String,
+    pub evaluated: u32,
+    pub flagged: u32,
+}
+
+/// Summary produced by `--batch-end`: the deduplicated violations
+/// accumulated across every `keel compile <file>` invocation since the
+/// matching `--batch-start`, read back from the on-disk batch journal.
+/// See `keel_enforce::batch::BatchJournal`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchEndResult {
+    pub version: String,
+    pub command: String,
+    pub files_checked: Vec<String>,
+    pub errors: Vec<Violation>,
+    pub warnings: Vec<Violation>,
+}
+
+/// Summary produced by `--batch-status`: a read-only snapshot of an
+/// in-progress batch, without ending it or touching the journal on disk.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BatchStatusResult {
+    pub version: String,
+    pub command: String,
+    pub active: bool,
+    pub started_at_unix_ms: Option<u64>,
+    pub files_checked: Vec<String>,
+    pub deferred_count: u32,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DiscoverResult {
     pub version: String,
@@ -62,6 +109,28 @@ pub struct DiscoverResult {
     pub module_context: ModuleContext,
 }
 
+/// Response for `POST /discover/batch`: each requested hash either has a
+/// full `DiscoverResult` in `results`, or is listed in `not_found` -- an
+/// unresolvable hash never fails the whole batch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscoverBatchResult {
+    pub version: String,
+    pub command: String,
+    pub results: HashMap<String, DiscoverResult>,
+    pub not_found: Vec<String>,
+}
+
+/// Response for `POST /query`: the projected columns named in the query's
+/// `| Var, Var, ...` clause, and one row per distinct binding the bottom-up
+/// evaluator produced. See `keel_enforce::query`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryResult {
+    pub version: String,
+    pub command: String,
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NodeInfo {
     pub hash: String,
@@ -233,6 +302,35 @@ pub struct FixPlan {
     pub target_name: String,
     pub cause: String,
     pub actions: Vec<FixAction>,
+    /// Short machine-readable label distinguishing this plan from sibling
+    /// alternatives for the same violation, e.g. `"restore_function"` vs
+    /// `"rewrite_callers"` for the same E004. Plans with no real
+    /// alternative still carry one (e.g. `"update_callers"`).
+    pub kind: String,
+    /// How likely this specific alternative is to be the right fix,
+    /// independent of the violation's own detection `confidence`. Lets a
+    /// caller rank multiple plans for the same violation.
+    pub confidence: f64,
+    /// Filesystem-level edits this plan needs beyond text changes to an
+    /// existing file, e.g. restoring a removed function as a new stub
+    /// module, or creating a suggested module before a caller moves into
+    /// it. Most plans have none.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fs_edits: Vec<FileSystemEdit>,
+}
+
+/// A filesystem-level edit the apply engine performs directly, separate
+/// from `FixAction`'s text edits to an existing file's contents. Mirrors
+/// rust-analyzer's `CreateFile`/file-move `FileSystemEdit`s.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum FileSystemEdit {
+    /// Write a brand-new file. `validate_fix_plan` rejects this if `path`
+    /// already exists.
+    CreateFile { path: String, content: String },
+    /// Rename/move a file. `validate_fix_plan` rejects this if `from` is
+    /// missing or `to` already exists.
+    MoveFile { from: String, to: String },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -242,6 +340,30 @@ pub struct FixAction {
     pub old_text: String,
     pub new_text: String,
     pub description: String,
+    /// 0-based byte column on `line` where `old_text` begins, when known
+    /// precisely (e.g. a call's argument-list span from the tree-sitter
+    /// parse). Disambiguates multiple actions on the same line; `None`
+    /// means the apply engine falls back to matching `old_text` by
+    /// substring search instead.
+    #[serde(default)]
+    pub column: Option<u32>,
+    /// Exact whole-file byte range `old_text` occupies, when known from the
+    /// same tree-sitter parse that produced `column`. `line` stays the
+    /// human-readable diagnostic location for reporting; this is the fix's
+    /// own edit location, used by `validate_fix_plan` to confirm `old_text`
+    /// still matches what's on disk before applying -- a staleness guard
+    /// against a file having changed since the plan was generated.
+    #[serde(default)]
+    pub range: Option<TextRange>,
+}
+
+/// A byte-offset span into a whole file's contents, independent of line
+/// number. Mirrors the `start_byte`/`end_byte` pair tree-sitter node spans
+/// already carry elsewhere in this crate (e.g. `CallArgumentSpan`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TextRange {
+    pub start_byte: usize,
+    pub end_byte: usize,
 }
 
 // --- Fix apply result types ---
@@ -266,6 +388,23 @@ pub struct FixApplyDetail {
     pub error: Option<String>,
 }
 
+/// Outcome of [`crate::fix_generator::apply_fix_plans`]'s atomic,
+/// grouped-by-file write. Either every action in the batch lands
+/// (`files_changed` non-empty, `skipped` empty) or none of them do
+/// (`files_changed` empty, every action accounted for in `skipped`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ApplyReport {
+    pub files_changed: Vec<String>,
+    pub skipped: Vec<SkippedAction>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkippedAction {
+    pub file: String,
+    pub line: u32,
+    pub reason: String,
+}
+
 // --- Name command types ---
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -46,5 +46,6 @@ pub struct CheckCalleeRef {
 pub struct CheckSuggestion {
     pub kind: String,  // "inline_candidate" | "high_fan_in" | "cross_module_impact"
     pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub related_hash: Option<String>,
 }
@@ -32,12 +32,16 @@ pub struct Violation {
     pub hash: String,
     pub confidence: f64,
     pub resolution_tier: String,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub fix_hint: Option<String>,
     pub suppressed: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub suppress_hint: Option<String>,
     pub affected: Vec<AffectedNode>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub suggested_module: Option<String>, // W001 only
-    pub existing: Option<ExistingNode>,   // W002 only
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub existing: Option<ExistingNode>, // W002 only
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -82,6 +86,7 @@ pub struct NodeInfo {
     pub file: String,
     pub line_start: u32,
     pub line_end: u32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub docstring: Option<String>,
     pub type_hints_present: bool,
     pub has_docstring: bool,
@@ -94,6 +99,7 @@ pub struct CallerInfo {
     pub signature: String,
     pub file: String,
     pub line: u32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub docstring: Option<String>,
     pub call_line: u32,
     /// BFS distance from target node (1 = direct caller)
@@ -108,6 +114,7 @@ pub struct CalleeInfo {
     pub signature: String,
     pub file: String,
     pub line: u32,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
     pub docstring: Option<String>,
     pub call_line: u32,
     /// BFS distance from target node (1 = direct callee)
@@ -233,6 +233,90 @@ pub fn check_removed_functions(
     violations
 }
 
+/// Check E006: layer_violation — a resolved call or import crosses a
+/// package boundary that `[monorepo.layers]` forbids.
+///
+/// `deny` always wins over `allow`; a package with no rule in `layers` is
+/// unrestricted. Both packages must be known (set on `GraphNode::package`
+/// by `FileWalker::walk_with_packages` during `keel map`) for a reference to
+/// be checked at all -- a non-monorepo project has no packages, so this rule
+/// is a no-op for it.
+pub fn check_layer_violations(
+    file: &FileIndex,
+    store: &dyn GraphStore,
+    layers: &std::collections::HashMap<String, keel_core::config::LayerRule>,
+) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    if layers.is_empty() {
+        return violations;
+    }
+
+    let Some(from_package) = store
+        .get_nodes_in_file(&file.file_path)
+        .iter()
+        .find_map(|n| n.package.clone())
+    else {
+        return violations;
+    };
+
+    let Some(rule) = layers.get(&from_package) else {
+        return violations;
+    };
+
+    for reference in &file.references {
+        use keel_parsers::resolver::ReferenceKind;
+        let verb = match reference.kind {
+            ReferenceKind::Call => "calls",
+            ReferenceKind::Import => "imports",
+            _ => continue,
+        };
+        let Some(target_hash) = &reference.resolved_to else {
+            continue;
+        };
+        let Some(target_node) = store.get_node(target_hash) else {
+            continue;
+        };
+        let Some(to_package) = &target_node.package else {
+            continue;
+        };
+        if *to_package == from_package {
+            continue;
+        }
+
+        let denied = rule.deny.iter().any(|p| p == to_package);
+        let not_allowlisted = !rule.allow.is_empty() && !rule.allow.iter().any(|p| p == to_package);
+        if !denied && !not_allowlisted {
+            continue;
+        }
+
+        violations.push(Violation {
+            code: "E006".to_string(),
+            severity: "ERROR".to_string(),
+            category: "layer_violation".to_string(),
+            message: format!(
+                "Package `{}` may not depend on `{}` ({} `{}`)",
+                from_package, to_package, verb, target_node.name
+            ),
+            file: file.file_path.clone(),
+            line: reference.line,
+            hash: target_node.hash.clone(),
+            confidence: 1.0,
+            resolution_tier: "tree-sitter".to_string(),
+            fix_hint: Some(format!(
+                "Remove the dependency on `{}`, or add it to monorepo.layers.{}.allow",
+                to_package, from_package
+            )),
+            suppressed: false,
+            suppress_hint: None,
+            affected: vec![],
+            suggested_module: None,
+            existing: None,
+        });
+    }
+
+    violations
+}
+
 /// Check E005: arity_mismatch — caller passes wrong number of arguments.
 /// Compares reference argument counts against definition parameter counts.
 pub fn check_arity_mismatch(
@@ -24,6 +24,7 @@ pub fn format_fix(result: &FixResult) -> String {
             "\nVIOLATION {} hash={} {} on `{}`\n",
             plan.code, plan.hash, plan.category, plan.target_name,
         ));
+        out.push_str(&format!("  FIX kind={} conf={:.2}\n", plan.kind, plan.confidence));
         out.push_str(&format!("  CAUSE: {}\n", plan.cause));
         if !plan.actions.is_empty() {
             out.push_str(&format!("  CALLERS: {}\n", plan.actions.len()));
@@ -115,7 +116,12 @@ mod tests {
                     old_text: "validateToken(req.token)".into(),
                     new_text: "validateToken(req.token, Options::default())".into(),
                     description: "Update call site".into(),
+                    column: None,
+                    range: None,
                 }],
+                kind: "update_callers".into(),
+                confidence: 0.92,
+                fs_edits: vec![],
             }],
         };
         let out = format_fix(&result);
@@ -4,6 +4,8 @@ use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
+use keel_core::symbol_index::SymbolIndex;
+
 use crate::resolver::{
     CallSite, Definition, LanguageResolver, ParseResult, Reference, ResolvedEdge,
 };
@@ -23,6 +25,30 @@ pub struct GoResolver {
     embeddings: Mutex<HashMap<String, Vec<String>>>,
     /// Parsed interface definitions with their method signatures.
     interfaces: Mutex<Vec<InterfaceInfo>>,
+    /// Structural interface satisfaction: interface name -> concrete types
+    /// implementing it (including promoted embedded methods). Rebuilt after
+    /// every parse from `type_methods`, `embeddings`, and `interfaces`.
+    interface_impls: Mutex<HashMap<String, Vec<String>>>,
+    /// FST-backed `definition name -> symbol_index_files slots` index.
+    /// Lazily rebuilt (see `symbol_index_dirty`) from every cached file's
+    /// definitions the first time it's needed after a parse, replacing the
+    /// linear scan over every cached file that unqualified cross-file call
+    /// resolution used to do. The index itself matches case-insensitively
+    /// (so it can also drive fuzzy/prefix lookups); callers that care about
+    /// Go's case-sensitive identifiers -- like `resolve_same_package_call`
+    /// -- must re-check `symbol_index_names` for an exact match.
+    symbol_index: Mutex<Option<SymbolIndex>>,
+    /// Parallel to the ids handed out by `symbol_index`: slot `i` is the
+    /// file and exact (case-preserved) name of the `i`-th `(name, id)`
+    /// pair it was built from.
+    symbol_index_files: Mutex<Vec<PathBuf>>,
+    symbol_index_names: Mutex<Vec<String>>,
+    /// Set whenever `cache` changes; cleared by `ensure_symbol_index`.
+    /// Avoids re-rebuilding the index (an O(total definitions) scan) on
+    /// every single `parse_file` call during a bulk parse -- only the next
+    /// call resolution pays for a rebuild, once, however many files were
+    /// parsed in between.
+    symbol_index_dirty: Mutex<bool>,
 }
 
 impl GoResolver {
@@ -33,6 +59,11 @@ impl GoResolver {
             type_methods: Mutex::new(HashMap::new()),
             embeddings: Mutex::new(HashMap::new()),
             interfaces: Mutex::new(Vec::new()),
+            interface_impls: Mutex::new(HashMap::new()),
+            symbol_index: Mutex::new(None),
+            symbol_index_files: Mutex::new(Vec::new()),
+            symbol_index_names: Mutex::new(Vec::new()),
+            symbol_index_dirty: Mutex::new(true),
         }
     }
 
@@ -83,17 +114,96 @@ impl GoResolver {
             interfaces.extend(ifaces);
         }
 
+        // Tier 2: recompute structural interface satisfaction now that a new
+        // file's type methods/embeddings/interfaces have been folded in.
+        {
+            let type_methods = self.type_methods.lock().unwrap();
+            let embeddings = self.embeddings.lock().unwrap();
+            let interfaces = self.interfaces.lock().unwrap();
+            let mut interface_impls = self.interface_impls.lock().unwrap();
+            *interface_impls =
+                type_resolution::build_interface_impls(&interfaces, &type_methods, &embeddings);
+        }
+
         self.cache
             .lock()
             .unwrap()
             .insert(path.to_path_buf(), result.clone());
+        *self.symbol_index_dirty.lock().unwrap() = true;
         result
     }
 
+    /// Rebuild the FST symbol index from every definition in every cached
+    /// file, if `cache` has changed since the last rebuild. Deferred to
+    /// first use (rather than run on every `parse_file` call) so parsing
+    /// N files costs one O(total definitions) rebuild, not N of them.
+    fn ensure_symbol_index(&self) {
+        let mut dirty = self.symbol_index_dirty.lock().unwrap();
+        if !*dirty {
+            return;
+        }
+        let cache = self.cache.lock().unwrap();
+        let mut pairs = Vec::new();
+        let mut files = Vec::new();
+        let mut names = Vec::new();
+        for (path, result) in cache.iter() {
+            for def in &result.definitions {
+                pairs.push((def.name.clone(), files.len() as u64));
+                files.push(path.clone());
+                names.push(def.name.clone());
+            }
+        }
+        drop(cache);
+        *self.symbol_index.lock().unwrap() = Some(SymbolIndex::build(pairs));
+        *self.symbol_index_files.lock().unwrap() = files;
+        *self.symbol_index_names.lock().unwrap() = names;
+        *dirty = false;
+    }
+
     fn get_cached(&self, path: &Path) -> Option<ParseResult> {
         self.cache.lock().unwrap().get(path).cloned()
     }
 
+    /// Resolve an unqualified `callee` against another file in
+    /// `caller_dir` (Go's package == directory convention) by looking it
+    /// up in the symbol index rather than scanning every cached file.
+    ///
+    /// The index itself is case-insensitive, so a lookup can return ids
+    /// for symbols that only share `callee`'s name once lowercased (e.g.
+    /// `Helper` and `helper`, which are distinct, legal Go identifiers);
+    /// `symbol_index_names` is checked for an exact, case-sensitive match
+    /// before any candidate is accepted.
+    fn resolve_same_package_call(
+        &self,
+        callee: &str,
+        caller_file: &Path,
+        caller_dir: &Path,
+    ) -> Option<ResolvedEdge> {
+        self.ensure_symbol_index();
+        let index = self.symbol_index.lock().unwrap();
+        let bucket = index.as_ref()?.lookup(callee)?;
+        let files = self.symbol_index_files.lock().unwrap();
+        let names = self.symbol_index_names.lock().unwrap();
+        for &id in &bucket.ids {
+            let id = id as usize;
+            if names.get(id).map(String::as_str) != Some(callee) {
+                continue;
+            }
+            let path = files.get(id)?;
+            if path != caller_file && path.parent() == Some(caller_dir) {
+                return Some(ResolvedEdge {
+                    target_file: path.to_string_lossy().to_string(),
+                    target_name: callee.to_string(),
+                    confidence: 0.80,
+                    resolution_tier: "tier2_heuristic".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
+                });
+            }
+        }
+        None
+    }
+
     /// Resolve a receiver.method() call using type-aware heuristics.
     fn resolve_receiver_call(
         &self,
@@ -113,6 +223,34 @@ impl GoResolver {
             &ifaces,
         )
     }
+
+    /// Resolve a call made through an interface value (`var w io.Writer =
+    /// &File{}; w.Write(p)`) to its candidate concrete implementations.
+    ///
+    /// Unlike `resolve_call_edge`, which must return at most one edge, a
+    /// dynamic interface dispatch genuinely has multiple possible targets,
+    /// so this returns one candidate edge per type that structurally
+    /// satisfies `interface_name`, each tagged `tier2_interface_dispatch`
+    /// at reduced confidence.
+    pub fn resolve_interface_edges(
+        &self,
+        interface_name: &str,
+        method_name: &str,
+        file_path: &str,
+    ) -> Vec<ResolvedEdge> {
+        let interface_impls = self.interface_impls.lock().unwrap();
+        type_resolution::resolve_interface_dispatch_edges(
+            interface_name,
+            method_name,
+            file_path,
+            &interface_impls,
+        )
+    }
+
+    /// True if `name` is a known interface declaration.
+    fn is_known_interface(&self, name: &str) -> bool {
+        self.interfaces.lock().unwrap().iter().any(|i| i.name == name)
+    }
 }
 
 impl Default for GoResolver {
@@ -159,8 +297,27 @@ impl LanguageResolver for GoResolver {
             let receiver_or_pkg = &callee[..dot_pos];
             let func_name = &callee[dot_pos + 1..];
 
-            // First: try receiver method resolution (type-aware)
+            // First: interface dispatch -- `receiver_or_pkg` is itself the
+            // declared interface type (this resolver doesn't track variable
+            // types, so it treats the receiver text as the type name, same
+            // as the concrete-receiver heuristics below). When several
+            // concrete types satisfy the interface, `resolve_interface_edges`
+            // returns them in a stable (sorted) order, so picking the first
+            // one here is deterministic rather than whichever the
+            // `type_methods` HashMap happened to enumerate first.
             drop(cache);
+            if self.is_known_interface(receiver_or_pkg) {
+                let candidates = self.resolve_interface_edges(
+                    receiver_or_pkg,
+                    func_name,
+                    &call_site.file_path,
+                );
+                if let Some(edge) = candidates.into_iter().next() {
+                    return Some(edge);
+                }
+            }
+
+            // Second: try receiver method resolution (type-aware)
             if let Some(edge) =
                 self.resolve_receiver_call(receiver_or_pkg, func_name, &call_site.file_path)
             {
@@ -169,7 +326,7 @@ impl LanguageResolver for GoResolver {
             let cache = self.cache.lock().unwrap();
             let caller_result = cache.get(&caller_file)?;
 
-            // Second: try import-based package resolution
+            // Third: try import-based package resolution
             let import = caller_result.imports.iter().find(|imp| {
                 if imp.imported_names.contains(&"_".to_string()) {
                     return false;
@@ -193,6 +350,8 @@ impl LanguageResolver for GoResolver {
                     } else {
                         "tier1".into()
                     },
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
                 });
             }
             // Re-release cache before unqualified checks below
@@ -214,30 +373,22 @@ impl LanguageResolver for GoResolver {
                     target_name: callee.clone(),
                     confidence: 0.90,
                     resolution_tier: "tier1".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
                 });
             }
         }
 
-        // Unqualified call -- cross-file same-package resolution
+        // Unqualified call -- cross-file same-package resolution, via the
+        // FST symbol index instead of a linear scan over every cached file.
+        drop(cache);
         if let Some(caller_dir) = caller_file.parent() {
-            for (path, result) in cache.iter() {
-                if path == &caller_file {
-                    continue;
-                }
-                if path.parent() == Some(caller_dir) {
-                    for def in &result.definitions {
-                        if def.name == *callee {
-                            return Some(ResolvedEdge {
-                                target_file: path.to_string_lossy().to_string(),
-                                target_name: callee.clone(),
-                                confidence: 0.80,
-                                resolution_tier: "tier2_heuristic".into(),
-                            });
-                        }
-                    }
-                }
+            if let Some(edge) = self.resolve_same_package_call(callee, &caller_file, caller_dir) {
+                return Some(edge);
             }
         }
+        let cache = self.cache.lock().unwrap();
+        let caller_result = cache.get(&caller_file)?;
 
         // Unqualified call -- check dot imports
         for imp in &caller_result.imports {
@@ -247,6 +398,8 @@ impl LanguageResolver for GoResolver {
                     target_name: callee.clone(),
                     confidence: 0.60,
                     resolution_tier: "tier2_heuristic".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
                 });
             }
         }
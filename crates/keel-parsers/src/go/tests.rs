@@ -115,6 +115,124 @@ func main() {
     );
 }
 
+#[test]
+fn test_go_interface_dispatch_resolves_to_concrete_types() {
+    let resolver = GoResolver::new();
+    let source = r#"
+package main
+
+type Writer interface {
+    Write(p []byte) (int, error)
+}
+
+type File struct{}
+
+func (f *File) Write(p []byte) (int, error) { return len(p), nil }
+
+func use(w Writer) {
+    w.Write(nil)
+}
+"#;
+    let path = Path::new("iface.go");
+    resolver.parse_file(path, source);
+
+    let edges = resolver.resolve_interface_edges("Writer", "Write", "iface.go");
+    assert_eq!(edges.len(), 1);
+    assert_eq!(edges[0].target_name, "File.Write");
+    assert_eq!(edges[0].confidence, 0.55);
+    assert_eq!(edges[0].resolution_tier, "tier2_interface_dispatch");
+
+    let edge = resolver.resolve_call_edge(&CallSite {
+        file_path: "iface.go".into(),
+        line: 12,
+        callee_name: "Writer.Write".into(),
+        receiver: Some("w".into()),
+    });
+    assert!(edge.is_some());
+    let edge = edge.unwrap();
+    assert_eq!(edge.target_name, "File.Write");
+    assert_eq!(edge.confidence, 0.55);
+}
+
+#[test]
+fn test_go_interface_dispatch_multiple_implementers() {
+    let resolver = GoResolver::new();
+    let source = r#"
+package main
+
+type Stringer interface {
+    String() string
+}
+
+type A struct{}
+type B struct{}
+
+func (a A) String() string { return "a" }
+func (b B) String() string { return "b" }
+"#;
+    let path = Path::new("iface_multi.go");
+    resolver.parse_file(path, source);
+
+    let edges = resolver.resolve_interface_edges("Stringer", "String", "iface_multi.go");
+    assert_eq!(edges.len(), 2);
+    assert!(edges.iter().all(|e| e.confidence == 0.55));
+    let mut targets: Vec<_> = edges.iter().map(|e| e.target_name.clone()).collect();
+    targets.sort();
+    assert_eq!(targets, vec!["A.String".to_string(), "B.String".to_string()]);
+}
+
+#[test]
+fn test_go_type_qualified_method_resolves_ahead_of_package_alias() {
+    let resolver = GoResolver::new();
+    let source = r#"
+package main
+
+type Point struct{}
+
+func (p Point) Dist() int { return 0 }
+
+func main() {
+    Point.Dist(Point{})
+}
+"#;
+    let path = Path::new("recv.go");
+    resolver.parse_file(path, source);
+
+    let edge = resolver.resolve_call_edge(&CallSite {
+        file_path: "recv.go".into(),
+        line: 9,
+        callee_name: "Point.Dist".into(),
+        receiver: None,
+    });
+    let edge = edge.expect("should resolve Point.Dist via the known local type");
+    assert_eq!(edge.target_name, "Dist");
+    assert_eq!(edge.resolution_tier, "tier2_heuristic");
+    // Concretely known local type -- must outrank a guessed package alias.
+    assert!(edge.confidence > 0.75);
+}
+
+#[test]
+fn test_go_unqualified_call_resolves_via_symbol_index_across_files() {
+    let resolver = GoResolver::new();
+    resolver.parse_file(
+        Path::new("pkg/helper.go"),
+        "package pkg\nfunc Helper() int { return 1 }",
+    );
+    resolver.parse_file(
+        Path::new("pkg/main.go"),
+        "package pkg\nfunc main() { Helper() }",
+    );
+    let edge = resolver.resolve_call_edge(&CallSite {
+        file_path: "pkg/main.go".into(),
+        line: 1,
+        callee_name: "Helper".into(),
+        receiver: None,
+    });
+    let edge = edge.expect("should resolve cross-file call in same package");
+    assert_eq!(edge.target_file, "pkg/helper.go");
+    assert_eq!(edge.resolution_tier, "tier2_heuristic");
+}
+
 #[test]
 fn test_go_cross_file_call_with_import() {
     let resolver = GoResolver::new();
@@ -203,7 +203,119 @@ pub fn find_interface_satisfiers(
     satisfiers
 }
 
+/// Collect every method name reachable from `type_name`, including methods
+/// promoted through (possibly nested) struct embedding.
+fn collect_promoted_method_names(
+    type_name: &str,
+    type_methods: &HashMap<String, Vec<(String, bool)>>,
+    embeddings: &HashMap<String, Vec<String>>,
+    visited: &mut Vec<String>,
+) -> Vec<String> {
+    if visited.contains(&type_name.to_string()) {
+        return Vec::new();
+    }
+    visited.push(type_name.to_string());
+
+    let mut names: Vec<String> = type_methods
+        .get(type_name)
+        .map(|methods| methods.iter().map(|(n, _)| n.clone()).collect())
+        .unwrap_or_default();
+
+    if let Some(embedded_types) = embeddings.get(type_name) {
+        for embedded in embedded_types {
+            names.extend(collect_promoted_method_names(
+                embedded,
+                type_methods,
+                embeddings,
+                visited,
+            ));
+        }
+    }
+    names
+}
+
+/// Find concrete types that structurally satisfy an interface, accounting
+/// for methods promoted through struct embedding (not just directly
+/// declared receiver methods).
+pub fn find_interface_satisfiers_structural(
+    iface: &InterfaceInfo,
+    type_methods: &HashMap<String, Vec<(String, bool)>>,
+    embeddings: &HashMap<String, Vec<String>>,
+) -> Vec<String> {
+    if iface.methods.is_empty() {
+        return Vec::new();
+    }
+    let mut satisfiers = Vec::new();
+    for type_name in type_methods.keys() {
+        let mut visited = Vec::new();
+        let names = collect_promoted_method_names(type_name, type_methods, embeddings, &mut visited);
+        if iface.methods.iter().all(|im| names.contains(im)) {
+            satisfiers.push(type_name.clone());
+        }
+    }
+    // `type_methods.keys()` iterates in nondeterministic HashMap order;
+    // sort so repeated runs (and callers that pick "the" satisfier, e.g.
+    // `resolve_call_edge`) see a stable result.
+    satisfiers.sort();
+    satisfiers
+}
+
+/// Build the interface -> satisfying concrete types map for every parsed
+/// interface, using structural satisfaction (including promoted embedded
+/// methods).
+pub fn build_interface_impls(
+    interfaces: &[InterfaceInfo],
+    type_methods: &HashMap<String, Vec<(String, bool)>>,
+    embeddings: &HashMap<String, Vec<String>>,
+) -> HashMap<String, Vec<String>> {
+    let mut impls = HashMap::new();
+    for iface in interfaces {
+        let satisfiers = find_interface_satisfiers_structural(iface, type_methods, embeddings);
+        if !satisfiers.is_empty() {
+            impls.insert(iface.name.clone(), satisfiers);
+        }
+    }
+    impls
+}
+
+/// Resolve a call made through an interface value (`w.Write(p)` where `w`
+/// is declared as an interface type) to its candidate concrete
+/// implementations. The dynamic dispatch target can't be known statically,
+/// so this emits one lower-confidence edge per type that structurally
+/// satisfies the interface, rather than a single resolved call.
+pub fn resolve_interface_dispatch_edges(
+    interface_name: &str,
+    method_name: &str,
+    file_path: &str,
+    interface_impls: &HashMap<String, Vec<String>>,
+) -> Vec<ResolvedEdge> {
+    let Some(types) = interface_impls.get(interface_name) else {
+        return Vec::new();
+    };
+    types
+        .iter()
+        .map(|type_name| ResolvedEdge {
+            target_file: file_path.to_string(),
+            target_name: format!("{type_name}.{method_name}"),
+            confidence: 0.55,
+            resolution_tier: "tier2_interface_dispatch".into(),
+            resolved_depth: 1,
+            unresolved_segments: 0,
+        })
+        .collect()
+}
+
 /// Resolve a method call on a receiver using type-aware heuristics.
+///
+/// `receiver` is the bare text to the left of the dot, which this resolver
+/// doesn't distinguish from a type name -- a local variable `t T` and a
+/// method-expression receiver `T.Method` both show up here as the same
+/// string. That's a feature, not a gap: it means a call like `T.Method(t)`
+/// resolves through the exact same direct-type-method branch below as a
+/// concrete variable receiver would, which is also why it's checked ahead
+/// of `resolve_call_edge`'s package-alias fallback -- a known local type
+/// is a stronger signal than "this identifier happens to match an import
+/// alias".
 pub fn resolve_receiver_method(
     receiver: &str,
     method_name: &str,
@@ -212,14 +324,19 @@ pub fn resolve_receiver_method(
     embeddings: &HashMap<String, Vec<String>>,
     interfaces: &[InterfaceInfo],
 ) -> Option<ResolvedEdge> {
-    // 1. Direct type method lookup
+    // 1. Direct type method lookup. Concretely known (the type and its
+    // methods were parsed straight from this repo's own source), so this
+    // outranks the package-alias heuristics in `resolve_call_edge`, which
+    // are guessing at an external, unparsed symbol.
     if let Some(methods) = type_methods.get(receiver) {
         if methods.iter().any(|(n, _)| n == method_name) {
             return Some(ResolvedEdge {
                 target_file: file_path.to_string(),
                 target_name: method_name.to_string(),
-                confidence: 0.70,
+                confidence: 0.85,
                 resolution_tier: "tier2_heuristic".into(),
+                resolved_depth: 1,
+                unresolved_segments: 0,
             });
         }
     }
@@ -247,6 +364,8 @@ pub fn resolve_receiver_method(
                 target_name: method_name.to_string(),
                 confidence,
                 resolution_tier: "tier2_heuristic".into(),
+                resolved_depth: 1,
+                unresolved_segments: 0,
             });
         }
     }
@@ -273,6 +392,8 @@ fn resolve_embedded_method(
                         target_name: method_name.to_string(),
                         confidence: 0.65,
                         resolution_tier: "tier2_heuristic".into(),
+                        resolved_depth: 1,
+                        unresolved_segments: 0,
                     });
                 }
             }
@@ -319,4 +440,76 @@ mod tests {
         assert!(emb.contains_key("Outer"));
         assert_eq!(emb["Outer"], vec!["Inner".to_string()]);
     }
+
+    #[test]
+    fn test_find_interface_satisfiers_structural_direct() {
+        let iface = InterfaceInfo {
+            name: "Writer".to_string(),
+            methods: vec!["Write".to_string()],
+            file_path: "io.go".to_string(),
+        };
+        let mut type_methods = HashMap::new();
+        type_methods.insert("File".to_string(), vec![("Write".to_string(), true)]);
+        let satisfiers =
+            find_interface_satisfiers_structural(&iface, &type_methods, &HashMap::new());
+        assert_eq!(satisfiers, vec!["File".to_string()]);
+    }
+
+    #[test]
+    fn test_find_interface_satisfiers_structural_via_embedding() {
+        let iface = InterfaceInfo {
+            name: "Writer".to_string(),
+            methods: vec!["Write".to_string()],
+            file_path: "io.go".to_string(),
+        };
+        let mut type_methods = HashMap::new();
+        type_methods.insert("BaseWriter".to_string(), vec![("Write".to_string(), true)]);
+        let mut embeddings = HashMap::new();
+        embeddings.insert("LoggingWriter".to_string(), vec!["BaseWriter".to_string()]);
+        type_methods.insert("LoggingWriter".to_string(), vec![]);
+        let satisfiers = find_interface_satisfiers_structural(&iface, &type_methods, &embeddings);
+        assert!(satisfiers.contains(&"LoggingWriter".to_string()));
+    }
+
+    #[test]
+    fn test_find_interface_satisfiers_structural_is_sorted() {
+        let iface = InterfaceInfo {
+            name: "Writer".to_string(),
+            methods: vec!["Write".to_string()],
+            file_path: "io.go".to_string(),
+        };
+        let mut type_methods = HashMap::new();
+        for name in ["ZWriter", "AWriter", "MWriter"] {
+            type_methods.insert(name.to_string(), vec![("Write".to_string(), true)]);
+        }
+        let satisfiers = find_interface_satisfiers_structural(&iface, &type_methods, &HashMap::new());
+        assert_eq!(
+            satisfiers,
+            vec!["AWriter".to_string(), "MWriter".to_string(), "ZWriter".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_resolve_interface_dispatch_edges_one_per_satisfier() {
+        let mut impls = HashMap::new();
+        impls.insert(
+            "Writer".to_string(),
+            vec!["File".to_string(), "Buffer".to_string()],
+        );
+        let edges = resolve_interface_dispatch_edges("Writer", "Write", "main.go", &impls);
+        assert_eq!(edges.len(), 2);
+        assert!(edges.iter().all(|e| e.confidence == 0.55));
+        assert!(edges
+            .iter()
+            .all(|e| e.resolution_tier == "tier2_interface_dispatch"));
+        assert!(edges.iter().any(|e| e.target_name == "File.Write"));
+        assert!(edges.iter().any(|e| e.target_name == "Buffer.Write"));
+    }
+
+    #[test]
+    fn test_resolve_interface_dispatch_edges_unknown_interface() {
+        let impls = HashMap::new();
+        let edges = resolve_interface_dispatch_edges("Writer", "Write", "main.go", &impls);
+        assert!(edges.is_empty());
+    }
 }
@@ -7,8 +7,10 @@
 //!
 //! Supported languages: TypeScript/JavaScript, Python, Go, Rust.
 
+pub mod monorepo;
 pub mod queries;
 pub mod resolver;
+pub mod ssr;
 pub mod treesitter;
 pub mod walker;
 
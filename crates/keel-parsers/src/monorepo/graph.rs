@@ -0,0 +1,211 @@
+//! Builds a precise inter-package dependency graph for a detected monorepo,
+//! augmenting the glob-based `MonorepoLayout` with real dependency edges
+//! instead of just a flat package list.
+
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use super::{MonorepoKind, MonorepoLayout};
+
+/// A directed dependency edge: `from` depends on `to`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PackageEdge {
+    pub from: String,
+    pub to: String,
+}
+
+/// Inter-package dependency graph for a monorepo.
+///
+/// `packages` mirrors `MonorepoLayout::packages` (by name) so callers don't
+/// need to keep both structures around. `features` is only populated for
+/// `CargoWorkspace` -- it's the Cargo-specific notion of declared (not
+/// necessarily enabled) feature names per package.
+#[derive(Debug, Clone, Default)]
+pub struct PackageGraph {
+    pub packages: Vec<String>,
+    pub edges: Vec<PackageEdge>,
+    pub features: HashMap<String, Vec<String>>,
+}
+
+/// Build a `PackageGraph` for `layout`, using the most precise dependency
+/// source available for its `kind`: `cargo metadata` for Cargo workspaces,
+/// `package.json` deps for npm/Nx/Turbo/Lerna, `go.mod` requires for Go
+/// workspaces. Falls back to a graph with no edges -- just the packages
+/// `detect_monorepo` already enumerated -- if that source is unavailable
+/// (e.g. `cargo` isn't on `PATH`, or a package is missing its manifest).
+pub fn build_package_graph(root: &Path, layout: &MonorepoLayout) -> PackageGraph {
+    let packages = layout.packages.iter().map(|p| p.name.clone()).collect();
+
+    let (edges, features) = match layout.kind {
+        MonorepoKind::CargoWorkspace => cargo_metadata_edges(root, layout).unwrap_or_default(),
+        MonorepoKind::NpmWorkspaces
+        | MonorepoKind::NxMonorepo
+        | MonorepoKind::TurboMonorepo
+        | MonorepoKind::LernaMonorepo => (npm_package_json_edges(layout), HashMap::new()),
+        MonorepoKind::GoWorkspace => (go_mod_edges(layout), HashMap::new()),
+        MonorepoKind::None => (Vec::new(), HashMap::new()),
+    };
+
+    PackageGraph {
+        packages,
+        edges,
+        features,
+    }
+}
+
+/// Shell out to `cargo metadata --no-deps`, which reports each workspace
+/// member's direct dependencies by name (without needing the full
+/// resolve graph). Returns `None` if `cargo` isn't available or the
+/// output can't be parsed, so the caller can fall back to an edge-less graph.
+fn cargo_metadata_edges(
+    root: &Path,
+    layout: &MonorepoLayout,
+) -> Option<(Vec<PackageEdge>, HashMap<String, Vec<String>>)> {
+    let output = Command::new("cargo")
+        .args(["metadata", "--format-version", "1", "--no-deps"])
+        .current_dir(root)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let parsed: serde_json::Value = serde_json::from_slice(&output.stdout).ok()?;
+    let meta_packages = parsed.get("packages")?.as_array()?;
+
+    let known: HashSet<&str> = layout.packages.iter().map(|p| p.name.as_str()).collect();
+    let mut edges = Vec::new();
+    let mut features = HashMap::new();
+
+    for pkg in meta_packages {
+        let Some(name) = pkg.get("name").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        if !known.contains(name) {
+            continue;
+        }
+
+        if let Some(deps) = pkg.get("dependencies").and_then(|v| v.as_array()) {
+            for dep in deps {
+                if let Some(dep_name) = dep.get("name").and_then(|v| v.as_str()) {
+                    if dep_name != name && known.contains(dep_name) {
+                        edges.push(PackageEdge {
+                            from: name.to_string(),
+                            to: dep_name.to_string(),
+                        });
+                    }
+                }
+            }
+        }
+
+        if let Some(feats) = pkg.get("features").and_then(|v| v.as_object()) {
+            features.insert(name.to_string(), feats.keys().cloned().collect());
+        }
+    }
+
+    Some((edges, features))
+}
+
+/// Read each package's `package.json` and match its `dependencies` /
+/// `devDependencies` keys against the npm package names of other
+/// workspace members (which may differ from the `PackageInfo` directory
+/// name, e.g. a scoped `@org/core`).
+fn npm_package_json_edges(layout: &MonorepoLayout) -> Vec<PackageEdge> {
+    let mut npm_name_to_pkg: HashMap<String, String> = HashMap::new();
+    let mut pkg_deps: Vec<(String, Vec<String>)> = Vec::new();
+
+    for pkg in &layout.packages {
+        let Ok(content) = fs::read_to_string(pkg.path.join("package.json")) else {
+            continue;
+        };
+        let Ok(parsed) = serde_json::from_str::<serde_json::Value>(&content) else {
+            continue;
+        };
+
+        if let Some(npm_name) = parsed.get("name").and_then(|v| v.as_str()) {
+            npm_name_to_pkg.insert(npm_name.to_string(), pkg.name.clone());
+        }
+
+        let mut deps = Vec::new();
+        for field in ["dependencies", "devDependencies"] {
+            if let Some(obj) = parsed.get(field).and_then(|v| v.as_object()) {
+                deps.extend(obj.keys().cloned());
+            }
+        }
+        pkg_deps.push((pkg.name.clone(), deps));
+    }
+
+    let mut edges = Vec::new();
+    for (pkg_name, deps) in &pkg_deps {
+        for dep in deps {
+            if let Some(target) = npm_name_to_pkg.get(dep) {
+                if target != pkg_name {
+                    edges.push(PackageEdge {
+                        from: pkg_name.clone(),
+                        to: target.clone(),
+                    });
+                }
+            }
+        }
+    }
+    edges
+}
+
+/// Read each package's `go.mod` and match its `require`d module paths
+/// against the declared `module` path of other workspace members.
+fn go_mod_edges(layout: &MonorepoLayout) -> Vec<PackageEdge> {
+    let mut module_to_pkg: HashMap<String, String> = HashMap::new();
+    let mut pkg_requires: Vec<(String, Vec<String>)> = Vec::new();
+
+    for pkg in &layout.packages {
+        let Ok(content) = fs::read_to_string(pkg.path.join("go.mod")) else {
+            continue;
+        };
+
+        let mut requires = Vec::new();
+        let mut in_require_block = false;
+        for line in content.lines() {
+            let trimmed = line.trim();
+            if let Some(module_path) = trimmed.strip_prefix("module ") {
+                module_to_pkg.insert(module_path.trim().to_string(), pkg.name.clone());
+                continue;
+            }
+            if trimmed == "require (" {
+                in_require_block = true;
+                continue;
+            }
+            if in_require_block {
+                if trimmed == ")" {
+                    in_require_block = false;
+                } else if let Some(module_path) = trimmed.split_whitespace().next() {
+                    requires.push(module_path.to_string());
+                }
+                continue;
+            }
+            if let Some(rest) = trimmed.strip_prefix("require ") {
+                if let Some(module_path) = rest.split_whitespace().next() {
+                    requires.push(module_path.to_string());
+                }
+            }
+        }
+        pkg_requires.push((pkg.name.clone(), requires));
+    }
+
+    let mut edges = Vec::new();
+    for (pkg_name, requires) in &pkg_requires {
+        for req in requires {
+            if let Some(target) = module_to_pkg.get(req) {
+                if target != pkg_name {
+                    edges.push(PackageEdge {
+                        from: pkg_name.clone(),
+                        to: target.clone(),
+                    });
+                }
+            }
+        }
+    }
+    edges
+}
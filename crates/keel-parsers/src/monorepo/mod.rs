@@ -4,6 +4,7 @@
 //! monorepos by inspecting config files at the project root.
 
 mod detect;
+mod graph;
 mod helpers;
 
 use std::path::Path;
@@ -13,6 +14,8 @@ use serde::{Deserialize, Serialize};
 
 use detect::*;
 
+pub use graph::{build_package_graph, PackageEdge, PackageGraph};
+
 /// The kind of monorepo detected at the project root.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum MonorepoKind {
@@ -115,3 +115,109 @@ members = [
     let vals = helpers::extract_toml_array(content, "members").unwrap();
     assert_eq!(vals, vec!["crates/*", "tools/cli"]);
 }
+
+#[test]
+fn test_package_graph_npm_edges_follow_declared_dependencies() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("packages/web")).unwrap();
+    fs::create_dir_all(dir.path().join("packages/core")).unwrap();
+    fs::write(
+        dir.path().join("packages/web/package.json"),
+        r#"{ "name": "@acme/web", "dependencies": { "@acme/core": "workspace:*" } }"#,
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("packages/core/package.json"),
+        r#"{ "name": "@acme/core" }"#,
+    )
+    .unwrap();
+
+    let layout = MonorepoLayout {
+        kind: MonorepoKind::NpmWorkspaces,
+        packages: vec![
+            PackageInfo {
+                name: "web".to_string(),
+                path: dir.path().join("packages/web"),
+                kind: MonorepoKind::NpmWorkspaces,
+                language: "typescript".to_string(),
+            },
+            PackageInfo {
+                name: "core".to_string(),
+                path: dir.path().join("packages/core"),
+                kind: MonorepoKind::NpmWorkspaces,
+                language: "typescript".to_string(),
+            },
+        ],
+    };
+
+    let graph = build_package_graph(dir.path(), &layout);
+    assert_eq!(graph.packages, vec!["web".to_string(), "core".to_string()]);
+    assert_eq!(
+        graph.edges,
+        vec![PackageEdge {
+            from: "web".to_string(),
+            to: "core".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_package_graph_go_mod_edges_follow_require_block() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir_all(dir.path().join("svc")).unwrap();
+    fs::create_dir_all(dir.path().join("lib")).unwrap();
+    fs::write(
+        dir.path().join("svc/go.mod"),
+        "module example.com/svc\n\ngo 1.21\n\nrequire (\n\texample.com/lib v0.0.0\n)\n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("lib/go.mod"), "module example.com/lib\n\ngo 1.21\n").unwrap();
+
+    let layout = MonorepoLayout {
+        kind: MonorepoKind::GoWorkspace,
+        packages: vec![
+            PackageInfo {
+                name: "svc".to_string(),
+                path: dir.path().join("svc"),
+                kind: MonorepoKind::GoWorkspace,
+                language: "go".to_string(),
+            },
+            PackageInfo {
+                name: "lib".to_string(),
+                path: dir.path().join("lib"),
+                kind: MonorepoKind::GoWorkspace,
+                language: "go".to_string(),
+            },
+        ],
+    };
+
+    let graph = build_package_graph(dir.path(), &layout);
+    assert_eq!(
+        graph.edges,
+        vec![PackageEdge {
+            from: "svc".to_string(),
+            to: "lib".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn test_package_graph_falls_back_to_edgeless_without_manifest() {
+    let dir = tempfile::tempdir().unwrap();
+    let layout = MonorepoLayout {
+        kind: MonorepoKind::CargoWorkspace,
+        packages: vec![PackageInfo {
+            name: "core".to_string(),
+            path: dir.path().join("core"),
+            kind: MonorepoKind::CargoWorkspace,
+            language: "rust".to_string(),
+        }],
+    };
+
+    // No Cargo.toml at `dir`, so `cargo metadata` can't resolve anything here --
+    // the graph should still carry the known packages with zero edges rather
+    // than failing outright.
+    let graph = build_package_graph(dir.path(), &layout);
+    assert_eq!(graph.packages, vec!["core".to_string()]);
+    assert!(graph.edges.is_empty());
+}
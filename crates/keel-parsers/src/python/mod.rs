@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::fs;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
@@ -14,6 +15,10 @@ use crate::treesitter::TreeSitterParser;
 pub struct PyResolver {
     parser: Mutex<TreeSitterParser>,
     cache: Mutex<HashMap<PathBuf, ParseResult>>,
+    /// Package root that absolute imports (`from pkg.mod import x`) resolve
+    /// against, derived from `pyproject.toml`/`setup.cfg`'s `package-dir`
+    /// override or the conventional `src/` layout.
+    package_root: Mutex<Option<PathBuf>>,
 }
 
 impl PyResolver {
@@ -21,9 +26,17 @@ impl PyResolver {
         PyResolver {
             parser: Mutex::new(TreeSitterParser::new()),
             cache: Mutex::new(HashMap::new()),
+            package_root: Mutex::new(None),
         }
     }
 
+    /// Load `pyproject.toml`/`setup.cfg` project layout from `project_root`
+    /// so absolute imports can be resolved against the real file tree
+    /// instead of left as bare dotted module names.
+    pub fn load_project_config(&self, project_root: &Path) {
+        *self.package_root.lock().unwrap() = Some(detect_package_root(project_root));
+    }
+
     fn parse_and_cache(&self, path: &Path, content: &str) -> ParseResult {
         let mut parser = self.parser.lock().unwrap();
         let mut result = match parser.parse_file("python", path, content) {
@@ -61,13 +74,19 @@ impl PyResolver {
             }
         }
 
-        // Tier 2: resolve relative imports to file paths
+        // Tier 2: resolve relative imports to file paths, and absolute
+        // imports against the configured package root (if any).
         let dir = path.parent().unwrap_or(Path::new("."));
+        let package_root = self.package_root.lock().unwrap().clone();
         for imp in &mut result.imports {
             if imp.is_relative {
                 if let Some(resolved) = resolve_python_relative_import(dir, &imp.source) {
                     imp.source = resolved;
                 }
+            } else if let Some(root) = &package_root {
+                if let Some(resolved) = resolve_python_absolute_import(root, &imp.source) {
+                    imp.source = resolved;
+                }
             }
         }
 
@@ -133,6 +152,8 @@ impl LanguageResolver for PyResolver {
                 target_name: call_site.callee_name.clone(),
                 confidence,
                 resolution_tier: "tier1".into(),
+                resolved_depth: 1,
+                unresolved_segments: 0,
             });
         }
 
@@ -144,6 +165,8 @@ impl LanguageResolver for PyResolver {
                     target_name: call_site.callee_name.clone(),
                     confidence: 0.95,
                     resolution_tier: "tier1".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
                 });
             }
         }
@@ -205,6 +228,74 @@ fn resolve_python_relative_import(dir: &Path, source: &str) -> Option<String> {
     }
 }
 
+/// Resolve an absolute Python import (`pkg.sub.mod`) against the configured
+/// package root. e.g. `pkg.sub` under package root `/project/src` resolves
+/// to `/project/src/pkg/sub.py` (or `/project/src/pkg/sub/__init__.py`).
+/// Unlike `resolve_python_relative_import`, a non-existent target returns
+/// `None` rather than a fabricated path: most absolute imports name a
+/// third-party or stdlib package, not something in this project's tree, so
+/// only a confirmed on-disk match should replace the bare dotted source.
+fn resolve_python_absolute_import(package_root: &Path, source: &str) -> Option<String> {
+    if source.is_empty() {
+        return None;
+    }
+    let module_path = source.replace('.', "/");
+    let as_file = package_root.join(format!("{module_path}.py"));
+    let as_pkg = package_root.join(&module_path).join("__init__.py");
+
+    if as_file.exists() {
+        Some(as_file.to_string_lossy().to_string())
+    } else if as_pkg.exists() {
+        Some(as_pkg.to_string_lossy().to_string())
+    } else {
+        None
+    }
+}
+
+/// Detect the package root that absolute imports resolve against: an
+/// explicit `package-dir`/`package_dir` root override in `pyproject.toml`
+/// or `setup.cfg` (`"" = "src"` / `= src`), then a conventional `src/`
+/// layout if one exists, then the project root itself.
+fn detect_package_root(project_root: &Path) -> PathBuf {
+    if let Some(dir) = package_dir_override(&project_root.join("pyproject.toml"))
+        .or_else(|| package_dir_override(&project_root.join("setup.cfg")))
+    {
+        return project_root.join(dir);
+    }
+    let src_dir = project_root.join("src");
+    if src_dir.is_dir() {
+        src_dir
+    } else {
+        project_root.to_path_buf()
+    }
+}
+
+/// Extract the target directory from a `package-dir`/`package_dir` root
+/// mapping: `"" = "src"` in `pyproject.toml`'s TOML syntax, or a bare
+/// `= src` line under `package_dir =` in `setup.cfg`'s INI syntax.
+fn package_dir_override(config_path: &Path) -> Option<String> {
+    let content = fs::read_to_string(config_path).ok()?;
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("\"\"") {
+            let value = rest
+                .trim_start()
+                .strip_prefix('=')?
+                .trim()
+                .trim_matches('"');
+            if !value.is_empty() {
+                return Some(value.to_string());
+            }
+        } else if let Some(value) = trimmed.strip_prefix('=') {
+            let value = value.trim();
+            if !value.is_empty() {
+                return Some(value.to_string());
+            }
+        }
+    }
+    None
+}
+
 /// Result of parsing `__all__` from a Python module.
 enum DunderAll {
     /// `__all__` is a literal list of string names.
@@ -359,4 +450,92 @@ def main():
         assert!(result.is_some());
         assert!(result.unwrap().contains("foo.py"));
     }
+
+    #[test]
+    fn test_resolve_absolute_import_against_package_root() {
+        let tmp = std::env::temp_dir().join("keel_test_py_absolute_import");
+        let pkg_dir = tmp.join("pkg");
+        fs::create_dir_all(&pkg_dir).unwrap();
+        fs::write(pkg_dir.join("mod.py"), "").unwrap();
+
+        let result = resolve_python_absolute_import(&tmp, "pkg.mod");
+        assert_eq!(
+            result,
+            Some(pkg_dir.join("mod.py").to_string_lossy().to_string())
+        );
+
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn test_resolve_absolute_import_leaves_third_party_packages_unresolved() {
+        let tmp = std::env::temp_dir().join("keel_test_py_absolute_import_missing");
+        fs::create_dir_all(&tmp).unwrap();
+
+        let result = resolve_python_absolute_import(&tmp, "numpy.typing");
+        assert!(result.is_none());
+
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn test_detect_package_root_honors_pyproject_package_dir() {
+        let tmp = std::env::temp_dir().join("keel_test_py_package_root_pyproject");
+        fs::create_dir_all(&tmp).unwrap();
+        fs::write(
+            tmp.join("pyproject.toml"),
+            "[tool.setuptools.package-dir]\n\"\" = \"src\"\n",
+        )
+        .unwrap();
+
+        assert_eq!(detect_package_root(&tmp), tmp.join("src"));
+
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn test_detect_package_root_falls_back_to_src_layout() {
+        let tmp = std::env::temp_dir().join("keel_test_py_package_root_src_layout");
+        fs::create_dir_all(tmp.join("src")).unwrap();
+
+        assert_eq!(detect_package_root(&tmp), tmp.join("src"));
+
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn test_detect_package_root_defaults_to_project_root() {
+        let tmp = std::env::temp_dir().join("keel_test_py_package_root_flat");
+        fs::create_dir_all(&tmp).unwrap();
+
+        assert_eq!(detect_package_root(&tmp), tmp);
+
+        fs::remove_dir_all(&tmp).ok();
+    }
+
+    #[test]
+    fn test_load_project_config_resolves_absolute_import_in_parse_file() {
+        let tmp = std::env::temp_dir().join("keel_test_py_load_project_config");
+        let pkg_dir = tmp.join("src").join("pkg");
+        fs::create_dir_all(&pkg_dir).unwrap();
+        fs::write(pkg_dir.join("helper.py"), "").unwrap();
+
+        let resolver = PyResolver::new();
+        resolver.load_project_config(&tmp);
+        let source = "from pkg.helper import do_thing\n";
+        let result = resolver.parse_file(Path::new("caller.py"), source);
+
+        let imp = result
+            .imports
+            .iter()
+            .find(|i| i.imported_names.contains(&"do_thing".to_string()))
+            .expect("import should be recorded");
+        assert_eq!(
+            imp.source,
+            pkg_dir.join("helper.py").to_string_lossy().to_string()
+        );
+        assert!(!imp.is_relative);
+
+        fs::remove_dir_all(&tmp).ok();
+    }
 }
@@ -70,6 +70,8 @@ pub struct Definition {
     pub type_hints_present: bool,
     /// Raw body text (used for hash computation after AST normalization).
     pub body_text: String,
+    /// Which namespace this definition occupies (value, type, or both).
+    pub namespace: Namespace,
 }
 
 /// The flavour of a reference occurrence.
@@ -81,6 +83,33 @@ pub enum ReferenceKind {
     Import,
     /// A type annotation or type-level reference.
     TypeRef,
+    /// A dependency-injection site: a consumer (constructor parameter) that
+    /// requires a provider. `Reference::name` is the provider type name, or
+    /// the token string for token-based injection (e.g. `@Inject('TOKEN')`).
+    Inject,
+}
+
+/// Which namespace a `Definition` or `Reference` lives in, mirroring
+/// rustc's `PerNS`/`Namespace` split. TypeScript (and Rust) let a single
+/// name denote a type and a value independently -- `export type { Foo }`
+/// only populates the type namespace, so it must resolve for type queries
+/// but never produce a call edge. Most languages/constructs only ever
+/// occupy one namespace; `Both` is for declarations (e.g. a class, which
+/// is simultaneously a constructor value and a type) that occupy both.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Namespace {
+    Value,
+    Type,
+    Both,
+}
+
+impl Namespace {
+    /// Whether this namespace includes the value namespace -- i.e. whether
+    /// a definition/reference tagged with it can participate in call-edge
+    /// resolution.
+    pub fn includes_value(&self) -> bool {
+        matches!(self, Namespace::Value | Namespace::Both)
+    }
 }
 
 /// A reference (usage) of a symbol within a file.
@@ -96,6 +125,8 @@ pub struct Reference {
     pub kind: ReferenceKind,
     /// If already resolved, the hash/id of the target definition.
     pub resolved_to: Option<String>,
+    /// Which namespace this reference looks up (value, type, or both).
+    pub namespace: Namespace,
 }
 
 /// An import statement extracted from source.
@@ -136,6 +167,23 @@ pub struct ResolvedEdge {
     /// Resolution confidence (0.0 = guess, 1.0 = certain).
     /// Low-confidence edges produce WARNINGs, not ERRORs.
     pub confidence: f64,
+    /// Which resolution strategy produced this edge (e.g. `"tier1"`,
+    /// `"tier2_heuristic"`, `"tier2_interface_dispatch"`). Surfaced in
+    /// diagnostics so a reviewer can tell a certain same-file match from a
+    /// guessed one without just squinting at `confidence`.
+    pub resolution_tier: String,
+    /// Number of leading segments of a qualified call (e.g. `ns.sub.process`
+    /// has 3) that were actually bound to a file/symbol, mirroring rustc's
+    /// `PathResolution`. `1` for an ordinary unqualified call. Only the
+    /// TypeScript resolver counts segments; the other resolvers report `1`
+    /// here and `0` in `unresolved_segments` for every edge they return.
+    pub resolved_depth: u32,
+    /// Trailing segments of a qualified call the resolver could not walk
+    /// through (e.g. a barrel re-export chain that bottoms out before the
+    /// final segment). `0` means the edge is a full resolution; resolution
+    /// never fails silently on a partial match -- it returns the deepest
+    /// binding it found plus this count instead of `None`.
+    pub unresolved_segments: u32,
 }
 
 /// Aggregated index for a single file -- used by the incremental pipeline
@@ -7,8 +7,8 @@ use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
 use crate::resolver::{
-    CallSite, Definition, Import, LanguageResolver, ParseResult, Reference, ReferenceKind,
-    ResolvedEdge,
+    CallSite, Definition, Import, LanguageResolver, Namespace, ParseResult, Reference,
+    ReferenceKind, ResolvedEdge,
 };
 use crate::treesitter::TreeSitterParser;
 use helpers::{find_import_for_name, resolve_rust_use_path, rust_is_public};
@@ -158,6 +158,7 @@ impl RustLangResolver {
                 line,
                 kind: ReferenceKind::TypeRef,
                 resolved_to: None,
+                namespace: Namespace::Type,
             });
         }
         for (name, line) in helpers::extract_attribute_macros(content) {
@@ -167,6 +168,7 @@ impl RustLangResolver {
                 line,
                 kind: ReferenceKind::Call,
                 resolved_to: None,
+                namespace: Namespace::Value,
             });
         }
 
@@ -240,6 +242,8 @@ impl LanguageResolver for RustLangResolver {
                     target_name: macro_name.to_string(),
                     confidence: 0.60,
                     resolution_tier: "tier2".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
                 });
             }
             // Cross-file: search all cached parse results
@@ -253,6 +257,8 @@ impl LanguageResolver for RustLangResolver {
                         target_name: macro_name.to_string(),
                         confidence: 0.50,
                         resolution_tier: "tier2".into(),
+                        resolved_depth: 1,
+                        unresolved_segments: 0,
                     });
                 }
             }
@@ -273,6 +279,8 @@ impl LanguageResolver for RustLangResolver {
                 target_name: callee.clone(),
                 confidence,
                 resolution_tier: "tier1".into(),
+                resolved_depth: 1,
+                unresolved_segments: 0,
             });
         }
 
@@ -281,6 +289,32 @@ impl LanguageResolver for RustLangResolver {
             let func_name = &callee[sep_pos + 2..];
             let module_path = &callee[..sep_pos];
 
+            // Associated-function call through a locally known type
+            // (`Type::new()`, the colon-path counterpart of a `self.method()`
+            // receiver call) -- checked before `mod_paths`/import resolution
+            // below so a type name is never misread as a module alias. A
+            // type whose impl blocks this resolver has actually parsed is a
+            // stronger signal than "this segment happens to match a module
+            // path", hence the higher confidence than both of those (0.90;
+            // a generic impl drops it to 0.65, below both).
+            let impl_map = self.impl_map.lock().unwrap();
+            if let Some(methods) = impl_map.get(module_path) {
+                if methods.iter().any(|m| m == func_name) {
+                    let cc = self.content_cache.lock().unwrap();
+                    let is_generic = cc.values().any(|c| helpers::is_generic_impl(c, module_path));
+                    let confidence = if is_generic { 0.65 } else { 0.90 };
+                    return Some(ResolvedEdge {
+                        target_file: call_site.file_path.clone(),
+                        target_name: func_name.to_string(),
+                        confidence,
+                        resolution_tier: "tier2_associated_fn".into(),
+                        resolved_depth: 1,
+                        unresolved_segments: 0,
+                    });
+                }
+            }
+            drop(impl_map);
+
             // Check mod_paths first for `mod foo;` declared modules
             let mod_paths = self.mod_paths.lock().unwrap();
             if let Some(mod_file) = mod_paths.get(module_path) {
@@ -289,6 +323,8 @@ impl LanguageResolver for RustLangResolver {
                     target_name: func_name.to_string(),
                     confidence: 0.85,
                     resolution_tier: "tier2".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
                 });
             }
             drop(mod_paths);
@@ -306,6 +342,8 @@ impl LanguageResolver for RustLangResolver {
                     target_name: func_name.to_string(),
                     confidence: 0.80,
                     resolution_tier: "tier1".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
                 });
             }
         }
@@ -349,6 +387,8 @@ impl LanguageResolver for RustLangResolver {
                             target_name: callee.clone(),
                             confidence,
                             resolution_tier: "tier2".into(),
+                            resolved_depth: 1,
+                            unresolved_segments: 0,
                         });
                     }
                 }
@@ -366,6 +406,8 @@ impl LanguageResolver for RustLangResolver {
                     target_name: callee.clone(),
                     confidence: 0.70,
                     resolution_tier: "tier2".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
                 });
             }
             // dyn Trait: receiver looks like "dyn TraitName"
@@ -382,6 +424,8 @@ impl LanguageResolver for RustLangResolver {
                         target_name: callee.clone(),
                         confidence: 0.40,
                         resolution_tier: "tier2".into(),
+                        resolved_depth: 1,
+                        unresolved_segments: 0,
                     });
                 }
             }
@@ -398,6 +442,8 @@ impl LanguageResolver for RustLangResolver {
                         target_name: callee.clone(),
                         confidence,
                         resolution_tier: "tier2".into(),
+                        resolved_depth: 1,
+                        unresolved_segments: 0,
                     });
                 }
             }
@@ -411,6 +457,8 @@ impl LanguageResolver for RustLangResolver {
                     target_name: callee.clone(),
                     confidence: 0.95,
                     resolution_tier: "tier1".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
                 });
             }
         }
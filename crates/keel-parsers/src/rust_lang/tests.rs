@@ -122,6 +122,38 @@ fn main() {
     assert!(store_imp.unwrap().is_relative);
 }
 
+#[test]
+fn test_rust_resolver_associated_function_resolves_to_known_type() {
+    let resolver = RustLangResolver::new();
+    let source = r#"
+struct Counter;
+
+impl Counter {
+    pub fn new() -> Counter { Counter }
+}
+
+fn main() {
+    Counter::new();
+}
+"#;
+    let path = Path::new("assoc.rs");
+    resolver.parse_file(path, source);
+    let edge = resolver.resolve_call_edge(&CallSite {
+        file_path: "assoc.rs".into(),
+        line: 9,
+        callee_name: "Counter::new".into(),
+        receiver: None,
+    });
+    assert!(
+        edge.is_some(),
+        "should resolve Counter::new as an associated function on the known local type"
+    );
+    let edge = edge.unwrap();
+    assert_eq!(edge.target_name, "new");
+    assert_eq!(edge.resolution_tier, "tier2_associated_fn");
+    assert!(edge.confidence > 0.85);
+}
+
 #[test]
 fn test_rust_resolver_cross_file_call_via_import() {
     let resolver = RustLangResolver::new();
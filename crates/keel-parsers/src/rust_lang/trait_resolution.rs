@@ -158,6 +158,8 @@ pub fn resolve_generic_method_call(
                 target_name: method.to_string(),
                 confidence: 0.65,
                 resolution_tier: "tier2".into(),
+                resolved_depth: 1,
+                unresolved_segments: 0,
             });
         }
     }
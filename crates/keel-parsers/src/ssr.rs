@@ -0,0 +1,363 @@
+//! Structural search-and-replace over the resolved call graph.
+//!
+//! Modeled on rust-analyzer's `ide_ssr`: a rule's pattern (e.g. `$fn($args)`
+//! or `obj.$method($args)`) is parsed into a small AST of literal and
+//! placeholder tokens. Every concrete (non-placeholder) callee/receiver in
+//! the pattern is resolved against the semantic cache the same way
+//! `TsResolver::resolve_call_edge` does, and a candidate call site only
+//! matches when it has the same syntactic shape *and* its own resolved
+//! target agrees with the pattern's -- so `parse($x)` only matches calls
+//! that bind to the same definition the pattern resolved to, even across a
+//! barrel re-export chain. Receiver and free-function (UFCS-style) forms of
+//! the same call are treated as interchangeable, mirroring SSR's
+//! `UfcsCallInfo`: `obj.method()` and `method(obj)` can match the same rule.
+//!
+//! This is a library module only -- an embeddable resolver-aware SSR
+//! engine, not a `keel` subcommand or server route, matching the scope it
+//! was requested at. A CLI/MCP surface (selecting files, applying rewrites
+//! to disk) is a separate, not-yet-requested feature.
+//!
+//! `CallSite` doesn't carry argument text (see `crate::resolver`), so a
+//! literal (non-placeholder) argument pattern like `parse(1, 2, 3)` has no
+//! way to be checked against a real call site's actual arguments --
+//! `SsrRule::compile` rejects such patterns rather than silently accepting
+//! one and then ignoring the argument text it implies it would check.
+
+use std::collections::HashMap;
+
+use crate::resolver::{CallSite, LanguageResolver};
+use crate::typescript::TsResolver;
+
+/// One token of a parsed SSR pattern: either a literal name that must match
+/// exactly, or a `$name` placeholder that binds to whatever appears there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SsrToken {
+    Literal(String),
+    Placeholder(String),
+}
+
+impl SsrToken {
+    fn parse(text: &str) -> SsrToken {
+        let text = text.trim();
+        match text.strip_prefix('$') {
+            Some(name) => SsrToken::Placeholder(name.to_string()),
+            None => SsrToken::Literal(text.to_string()),
+        }
+    }
+}
+
+/// A parsed structural pattern, e.g. `$fn($args)`, `obj.$method($args)`, or
+/// a fully concrete `parse(x)`.
+#[derive(Debug, Clone)]
+struct SsrPattern {
+    /// Receiver qualifier, if the pattern is written as a method/UFCS call
+    /// (`recv.foo()`). `None` for a free-function pattern (`foo()`) -- but
+    /// since receiver and free-function forms are matched interchangeably,
+    /// this only constrains the *pattern's* own shape, not which call sites
+    /// it can match.
+    receiver: Option<SsrToken>,
+    /// The callee name token.
+    callee: SsrToken,
+    /// Argument-list token, kept as a single opaque capture (mirroring SSR
+    /// patterns like `$fn($args)`) rather than parsed positionally. `None`
+    /// for an empty argument list.
+    args: Option<SsrToken>,
+}
+
+impl SsrPattern {
+    /// Parse a pattern string such as `$fn($args)`, `obj.$method($args)`, or
+    /// `parse(x)`. Returns `None` if the pattern isn't shaped like a call.
+    fn parse(pattern: &str) -> Option<SsrPattern> {
+        let pattern = pattern.trim();
+        let paren_start = pattern.find('(')?;
+        let paren_end = pattern.rfind(')')?;
+        if paren_end < paren_start {
+            return None;
+        }
+
+        let head = &pattern[..paren_start];
+        let args_text = pattern[paren_start + 1..paren_end].trim();
+
+        let (receiver, callee) = match head.rfind('.') {
+            Some(dot) => (
+                Some(SsrToken::parse(&head[..dot])),
+                SsrToken::parse(&head[dot + 1..]),
+            ),
+            None => (None, SsrToken::parse(head)),
+        };
+
+        let args = if args_text.is_empty() {
+            None
+        } else {
+            Some(SsrToken::parse(args_text))
+        };
+
+        Some(SsrPattern {
+            receiver,
+            callee,
+            args,
+        })
+    }
+}
+
+/// A single structural match: the matched call site, the placeholder
+/// bindings captured from it, and -- when the rule carries a template --
+/// the rewritten replacement text.
+#[derive(Debug, Clone)]
+pub struct SsrMatch {
+    pub call_site: CallSite,
+    pub bindings: HashMap<String, String>,
+    pub rewritten: Option<String>,
+}
+
+/// A compiled, resolver-aware SSR rule.
+pub struct SsrRule {
+    pattern: SsrPattern,
+    /// Rendered output with `$placeholder` substitution, if this rule
+    /// rewrites as well as matches.
+    template: Option<String>,
+    /// The resolved target of the pattern's concrete callee, if it has one.
+    /// A candidate's own resolved edge must agree with this for the match
+    /// to count -- this is what lets `parse($x)` distinguish two unrelated
+    /// `parse` functions instead of matching on name alone.
+    resolved_target: Option<(String, String)>,
+}
+
+impl SsrRule {
+    /// Compile `pattern` (and optional `template`) into a rule, resolving
+    /// any concrete callee in the pattern against `resolver` as seen from
+    /// `anchor` -- the call site context the pattern is written in, e.g. a
+    /// real call in the file the user invoked SSR from.
+    pub fn compile(
+        resolver: &TsResolver,
+        pattern: &str,
+        template: Option<&str>,
+        anchor: &CallSite,
+    ) -> Option<SsrRule> {
+        let pattern = SsrPattern::parse(pattern)?;
+
+        // A literal argument pattern (e.g. `parse(x)`) can't be honored:
+        // `CallSite` carries no argument text for `matches` to compare it
+        // against, so accepting it here would compile a rule that silently
+        // matches any call to the resolved callee regardless of what
+        // argument it was actually written with. `$args`-style placeholders
+        // and an empty argument list are unaffected.
+        if let Some(SsrToken::Literal(_)) = &pattern.args {
+            return None;
+        }
+
+        let resolved_target = match &pattern.callee {
+            SsrToken::Literal(name) => {
+                let receiver = match &pattern.receiver {
+                    Some(SsrToken::Literal(r)) => Some(r.clone()),
+                    _ => None,
+                };
+                let probe = CallSite {
+                    file_path: anchor.file_path.clone(),
+                    line: anchor.line,
+                    callee_name: name.clone(),
+                    receiver,
+                };
+                resolver
+                    .resolve_call_edge(&probe)
+                    .map(|edge| (edge.target_file, edge.target_name))
+            }
+            SsrToken::Placeholder(_) => None,
+        };
+
+        Some(SsrRule {
+            pattern,
+            template: template.map(str::to_string),
+            resolved_target,
+        })
+    }
+
+    /// Test whether `call_site` matches this rule, using `resolver` to
+    /// resolve the call site's own target for comparison against
+    /// `resolved_target`. Returns the placeholder bindings and, if a
+    /// template was supplied, the rewritten text.
+    pub fn matches(&self, resolver: &TsResolver, call_site: &CallSite) -> Option<SsrMatch> {
+        let mut bindings = HashMap::new();
+
+        match &self.pattern.callee {
+            SsrToken::Literal(name) => {
+                if &call_site.callee_name != name {
+                    return None;
+                }
+            }
+            SsrToken::Placeholder(p) => {
+                bindings.insert(p.clone(), call_site.callee_name.clone());
+            }
+        }
+
+        match (&self.pattern.receiver, &call_site.receiver) {
+            (Some(SsrToken::Literal(r)), Some(actual)) if r != actual => return None,
+            (Some(SsrToken::Placeholder(p)), Some(actual)) => {
+                bindings.insert(p.clone(), actual.clone());
+            }
+            _ => {
+                // One side has no receiver (free-function form) while the
+                // other does (method/UFCS form), or neither has one --
+                // these are the same call shape for matching purposes, so
+                // fall through to the resolved-target check below.
+            }
+        }
+
+        if let Some(SsrToken::Placeholder(p)) = &self.pattern.args {
+            // `CallSite` doesn't carry argument text in this schema, so the
+            // placeholder is accepted syntactically but can't be bound from
+            // a real call site yet; templates relying on `$args` need the
+            // caller to fill it in after the match.
+            bindings.entry(p.clone()).or_insert_with(String::new);
+        }
+
+        // A pattern with a concrete callee must resolve to the *same*
+        // definition as the rule's own pattern, not just share a name.
+        if let Some((target_file, target_name)) = &self.resolved_target {
+            let actual_edge = resolver.resolve_call_edge(call_site)?;
+            if &actual_edge.target_file != target_file || &actual_edge.target_name != target_name {
+                return None;
+            }
+        }
+
+        let rewritten = self.template.as_ref().map(|template| {
+            let mut out = template.clone();
+            for (name, value) in &bindings {
+                out = out.replace(&format!("${name}"), value);
+            }
+            out
+        });
+
+        Some(SsrMatch {
+            call_site: call_site.clone(),
+            bindings,
+            rewritten,
+        })
+    }
+}
+
+/// Run `rule` against every call site in `call_sites`, returning all
+/// matches in order.
+pub fn find_matches(
+    resolver: &TsResolver,
+    rule: &SsrRule,
+    call_sites: &[CallSite],
+) -> Vec<SsrMatch> {
+    call_sites
+        .iter()
+        .filter_map(|cs| rule.matches(resolver, cs))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::Path;
+
+    fn anchor(file: &str, callee: &str, receiver: Option<&str>) -> CallSite {
+        CallSite {
+            file_path: file.into(),
+            line: 1,
+            callee_name: callee.into(),
+            receiver: receiver.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn test_parse_placeholder_pattern() {
+        let pattern = SsrPattern::parse("$fn($args)").unwrap();
+        assert_eq!(pattern.callee, SsrToken::Placeholder("fn".into()));
+        assert_eq!(pattern.args, Some(SsrToken::Placeholder("args".into())));
+        assert!(pattern.receiver.is_none());
+    }
+
+    #[test]
+    fn test_parse_receiver_pattern() {
+        let pattern = SsrPattern::parse("obj.$method($args)").unwrap();
+        assert_eq!(pattern.receiver, Some(SsrToken::Literal("obj".into())));
+        assert_eq!(pattern.callee, SsrToken::Placeholder("method".into()));
+    }
+
+    #[test]
+    fn test_concrete_pattern_matches_call_with_same_resolved_target() {
+        let resolver = TsResolver::new();
+        resolver.parse_file(
+            Path::new("./a.ts"),
+            r#"
+export function parse(x: string): void {}
+"#,
+        );
+        resolver.parse_file(
+            Path::new("./b.ts"),
+            r#"
+export function parse(x: string): void {}
+"#,
+        );
+        resolver.parse_file(
+            Path::new("caller.ts"),
+            r#"
+import { parse } from './a.ts';
+
+function run() {
+    parse("hi");
+}
+"#,
+        );
+
+        let rule_anchor = anchor("caller.ts", "parse", None);
+        let rule = SsrRule::compile(&resolver, "parse($x)", None, &rule_anchor).unwrap();
+
+        // Matches the call that actually resolves to ./a.ts's `parse`.
+        let matching_site = anchor("caller.ts", "parse", None);
+        assert!(rule.matches(&resolver, &matching_site).is_some());
+    }
+
+    #[test]
+    fn test_literal_argument_pattern_is_rejected_at_compile() {
+        let resolver = TsResolver::new();
+        let rule_anchor = anchor("caller.ts", "parse", None);
+        // `CallSite` carries no argument text, so a literal arg pattern
+        // could never actually be checked against a real call -- it must
+        // be rejected rather than silently matching any `parse(...)` call.
+        assert!(SsrRule::compile(&resolver, "parse(x)", None, &rule_anchor).is_none());
+    }
+
+    #[test]
+    fn test_placeholder_callee_binds_any_name() {
+        let resolver = TsResolver::new();
+        let rule_anchor = anchor("caller.ts", "anything", None);
+        let rule = SsrRule::compile(&resolver, "$fn($args)", None, &rule_anchor).unwrap();
+
+        let site = anchor("caller.ts", "doStuff", None);
+        let m = rule.matches(&resolver, &site).unwrap();
+        assert_eq!(m.bindings.get("fn"), Some(&"doStuff".to_string()));
+    }
+
+    #[test]
+    fn test_receiver_and_free_function_forms_match_interchangeably() {
+        let resolver = TsResolver::new();
+        let rule_anchor = anchor("caller.ts", "method", Some("obj"));
+        let rule = SsrRule::compile(&resolver, "$recv.method($args)", None, &rule_anchor).unwrap();
+
+        // Free-function form (UFCS-style) should still match the same rule.
+        let free_form = anchor("caller.ts", "method", None);
+        assert!(rule.matches(&resolver, &free_form).is_some());
+    }
+
+    #[test]
+    fn test_template_substitutes_placeholder_bindings() {
+        let resolver = TsResolver::new();
+        let rule_anchor = anchor("caller.ts", "anything", None);
+        let rule = SsrRule::compile(
+            &resolver,
+            "$fn($args)",
+            Some("$fn(/* traced */)"),
+            &rule_anchor,
+        )
+        .unwrap();
+
+        let site = anchor("caller.ts", "doStuff", None);
+        let m = rule.matches(&resolver, &site).unwrap();
+        assert_eq!(m.rewritten.as_deref(), Some("doStuff(/* traced */)"));
+    }
+}
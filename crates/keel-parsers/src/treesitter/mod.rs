@@ -5,7 +5,7 @@ use tree_sitter::{Language, Parser, Query, QueryCursor, Tree};
 
 use crate::queries;
 use crate::resolver::{
-    Definition, Import, ParseResult, Reference, ReferenceKind,
+    Definition, Import, Namespace, ParseResult, Reference, ReferenceKind,
 };
 use keel_core::types::NodeKind;
 
@@ -51,7 +51,7 @@ impl TreeSitterParser {
         let bytes = source.as_bytes();
         let root = tree.root_node();
 
-        let mut definitions = extract_definitions(&query, root, bytes, &file_path);
+        let mut definitions = extract_definitions(&query, root, bytes, &file_path, lang_name);
         let references = extract_references(&query, root, bytes, &file_path);
         let imports = extract_imports(&query, root, bytes, &file_path);
 
@@ -74,6 +74,7 @@ impl TreeSitterParser {
                 is_public: true,
                 type_hints_present: false,
                 body_text: String::new(),
+                namespace: Namespace::Value,
             },
         );
 
@@ -126,6 +127,7 @@ fn extract_definitions(
     root: tree_sitter::Node<'_>,
     source: &[u8],
     file_path: &str,
+    lang_name: &str,
 ) -> Vec<Definition> {
     let mut cursor = QueryCursor::new();
     let mut defs = Vec::new();
@@ -138,6 +140,8 @@ fn extract_definitions(
         let mut params_text = String::new();
         let mut return_type_text = String::new();
         let mut body_text = String::new();
+        let mut body_node = None;
+        let mut def_node = None;
         let mut line_start = 0u32;
         let mut line_end = 0u32;
 
@@ -169,6 +173,7 @@ fn extract_definitions(
                 | "def.struct.body" | "def.enum.body"
                 | "def.trait.body" | "def.impl.body" => {
                     body_text = node_text(cap.node, source).to_string();
+                    body_node = Some(cap.node);
                 }
                 "def.func" | "def.method" | "def.class"
                 | "def.type" | "def.struct" | "def.enum"
@@ -177,6 +182,7 @@ fn extract_definitions(
                 | "def.method.receiver" | "def.impl.type" => {
                     line_start = cap.node.start_position().row as u32 + 1;
                     line_end = cap.node.end_position().row as u32 + 1;
+                    def_node = Some(cap.node);
                 }
                 _ => {}
             }
@@ -192,6 +198,13 @@ fn extract_definitions(
                 && (params_text.contains(':') || params_text.contains(" int")
                     || params_text.contains(" string") || params_text.contains(" bool"));
 
+            let namespace = if k == NodeKind::Class {
+                // A class is simultaneously a type and a constructor value.
+                Namespace::Both
+            } else {
+                Namespace::Value
+            };
+            let docstring = extract_docstring(lang_name, def_node, body_node, source);
             defs.push(Definition {
                 name: n,
                 kind: k,
@@ -199,10 +212,11 @@ fn extract_definitions(
                 file_path: file_path.to_string(),
                 line_start,
                 line_end,
-                docstring: None,
+                docstring,
                 is_public: true,
                 type_hints_present: has_type_hints,
                 body_text,
+                namespace,
             });
         }
     }
@@ -212,6 +226,134 @@ fn extract_definitions(
     defs
 }
 
+/// Pull a doc comment / docstring for a definition, dispatching on language.
+/// Returns `None` when no doc comment is present rather than guessing.
+fn extract_docstring(
+    lang_name: &str,
+    def_node: Option<tree_sitter::Node<'_>>,
+    body_node: Option<tree_sitter::Node<'_>>,
+    source: &[u8],
+) -> Option<String> {
+    match lang_name {
+        "python" => python_docstring(body_node?, source),
+        "typescript" | "javascript" | "tsx" => jsdoc_comment(def_node?, source),
+        "rust" => rust_doc_comment(def_node?, source),
+        _ => None,
+    }
+}
+
+/// Python: the first statement of a function/class body, if it's a bare
+/// string expression, is the docstring (PEP 257).
+fn python_docstring(body: tree_sitter::Node<'_>, source: &[u8]) -> Option<String> {
+    let first_stmt = body.named_child(0)?;
+    if first_stmt.kind() != "expression_statement" {
+        return None;
+    }
+    let string_node = first_stmt.named_child(0)?;
+    if string_node.kind() != "string" {
+        return None;
+    }
+    dedent_python_string(node_text(string_node, source))
+}
+
+/// Strip a Python string literal's prefix (`r`/`b`/`f`/`u`, any case/combo)
+/// and surrounding triple or single quotes, then dedent common whitespace.
+fn dedent_python_string(raw: &str) -> Option<String> {
+    let quote_start = raw.find(['"', '\''])?;
+    let body = &raw[quote_start..];
+    let inner = if body.starts_with("\"\"\"") || body.starts_with("'''") {
+        body.get(3..body.len().saturating_sub(3))?
+    } else {
+        body.get(1..body.len().saturating_sub(1))?
+    };
+
+    let lines: Vec<&str> = inner.lines().collect();
+    let indent = lines
+        .iter()
+        .skip(1)
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let dedented: Vec<String> = lines
+        .iter()
+        .enumerate()
+        .map(|(i, l)| {
+            if i == 0 {
+                l.to_string()
+            } else {
+                l.get(indent..).unwrap_or(l).to_string()
+            }
+        })
+        .collect();
+    let text = dedented.join("\n").trim().to_string();
+    if text.is_empty() {
+        None
+    } else {
+        Some(text)
+    }
+}
+
+/// TypeScript/JS: walk up through wrapping nodes with no preceding sibling
+/// of their own (e.g. a bare `function` inside an `export_statement`) until
+/// a preceding named sibling is found, then check it's a leading JSDoc block.
+fn jsdoc_comment(def_node: tree_sitter::Node<'_>, source: &[u8]) -> Option<String> {
+    let mut node = def_node;
+    let comment = loop {
+        if let Some(sib) = node.prev_named_sibling() {
+            break sib;
+        }
+        node = node.parent()?;
+    };
+    if comment.kind() != "comment" {
+        return None;
+    }
+    parse_jsdoc(node_text(comment, source))
+}
+
+fn parse_jsdoc(raw: &str) -> Option<String> {
+    let trimmed = raw.trim();
+    let inner = trimmed.strip_prefix("/**")?.strip_suffix("*/")?;
+    let lines: Vec<&str> = inner
+        .lines()
+        .map(|l| l.trim().trim_start_matches('*').trim())
+        .filter(|l| !l.is_empty())
+        .collect();
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// Rust: one or more consecutive `///` line comments immediately preceding
+/// the definition, skipping over attributes (e.g. `#[allow(dead_code)]`).
+fn rust_doc_comment(def_node: tree_sitter::Node<'_>, source: &[u8]) -> Option<String> {
+    let mut comments = Vec::new();
+    let mut node = def_node;
+    while let Some(sib) = node.prev_named_sibling() {
+        if sib.kind() == "attribute_item" {
+            node = sib;
+            continue;
+        }
+        if sib.kind() != "line_comment" {
+            break;
+        }
+        let text = node_text(sib, source);
+        let Some(doc) = text.strip_prefix("///") else {
+            break;
+        };
+        comments.push(doc.trim().to_string());
+        node = sib;
+    }
+    if comments.is_empty() {
+        None
+    } else {
+        comments.reverse();
+        Some(comments.join("\n"))
+    }
+}
+
 fn extract_references(
     query: &Query,
     root: tree_sitter::Node<'_>,
@@ -266,6 +408,7 @@ fn extract_references(
                     line,
                     kind: ReferenceKind::Call,
                     resolved_to: None,
+                    namespace: Namespace::Value,
                 });
             }
         }
@@ -425,6 +568,77 @@ pub fn detect_language(path: &Path) -> Option<&'static str> {
     }
 }
 
+/// Byte span of a call expression's argument list (parens included), its
+/// 0-based byte column on its source line, and the exact source text of
+/// that span.
+pub struct CallArgumentSpan {
+    pub start_byte: usize,
+    pub end_byte: usize,
+    pub column: u32,
+    pub text: String,
+}
+
+/// Locate the argument-list span of a call to `callee_name` on `line`
+/// (1-based) in `source`, for use by `keel-enforce`'s fix generator when it
+/// needs to rewrite a call site surgically instead of guessing by line.
+///
+/// `callee_name` may be qualified (`fmt.Println`, `Vec::new`); matching is
+/// against the call's trailing identifier, same as how qualified call names
+/// are built in [`extract_references`]. If a line has more than one call to
+/// the same name, the first (leftmost) one is returned -- good enough for
+/// the common case of one broken call per line per caller edge.
+pub fn find_call_argument_span(
+    lang_name: &str,
+    source: &str,
+    line: u32,
+    callee_name: &str,
+) -> Option<CallArgumentSpan> {
+    let lang = language_for_name(lang_name).ok()?;
+    let mut parser = Parser::new();
+    parser.set_language(&lang).ok()?;
+    let tree = parser.parse(source.as_bytes(), None)?;
+    let target_row = line.checked_sub(1)? as usize;
+    let bare_name = callee_name.rsplit(['.', ':']).next().unwrap_or(callee_name);
+    let bytes = source.as_bytes();
+
+    find_call_argument_span_in(tree.root_node(), bytes, target_row, bare_name)
+}
+
+fn find_call_argument_span_in(
+    node: tree_sitter::Node<'_>,
+    source: &[u8],
+    target_row: usize,
+    bare_name: &str,
+) -> Option<CallArgumentSpan> {
+    if matches!(node.kind(), "call_expression" | "call") && node.start_position().row == target_row {
+        if let (Some(function), Some(arguments)) =
+            (node.child_by_field_name("function"), node.child_by_field_name("arguments"))
+        {
+            if node_text(function, source).ends_with(bare_name) {
+                let line_start = source[..node.start_byte()]
+                    .iter()
+                    .rposition(|b| *b == b'\n')
+                    .map(|pos| pos + 1)
+                    .unwrap_or(0);
+                return Some(CallArgumentSpan {
+                    start_byte: arguments.start_byte(),
+                    end_byte: arguments.end_byte(),
+                    column: (arguments.start_byte() - line_start) as u32,
+                    text: node_text(arguments, source).to_string(),
+                });
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if let Some(span) = find_call_argument_span_in(child, source, target_row, bare_name) {
+            return Some(span);
+        }
+    }
+    None
+}
+
 #[cfg(test)]
 mod tests;
 
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
@@ -9,7 +9,8 @@ use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
 
 use crate::resolver::{
-    CallSite, Definition, Import, LanguageResolver, ParseResult, Reference, ResolvedEdge,
+    CallSite, Definition, Import, LanguageResolver, Namespace, ParseResult, Reference,
+    ReferenceKind, ResolvedEdge,
 };
 use crate::treesitter::TreeSitterParser;
 
@@ -18,8 +19,32 @@ use crate::treesitter::TreeSitterParser;
 struct OxcSymbolInfo {
     /// Symbol name -> (is_exported, has_type_annotation)
     symbols: HashMap<String, (bool, bool)>,
-    /// Re-export mappings: local_name -> (source_module, original_name)
-    reexports: HashMap<String, (String, String)>,
+    /// Re-export mappings: local_name -> (source_module, original_name, namespace).
+    /// `export type { Foo } from './m'` tags `Foo` as `Namespace::Type`, so
+    /// `resolve_call_edge` can refuse to follow a type-only re-export chain.
+    reexports: HashMap<String, (String, String, Namespace)>,
+    /// Module specifiers from bare `export * from './module'` statements.
+    /// Unlike `reexports`, these can't be mapped to individual names at
+    /// parse time -- a name reachable through more than one of these is
+    /// ambiguous (rustc's glob-import ambiguity rule) and is resolved
+    /// lazily, against the other files' own symbol tables, by
+    /// `resolve_star_reexport_candidates`.
+    star_reexport_sources: Vec<String>,
+}
+
+/// A detected cycle in a barrel re-export chain, e.g. `a.ts -> b.ts -> a.ts`
+/// re-exporting the same symbol back to where it started. Modeled on
+/// Dhall's `ImportStack`: the chain walker keeps an ordered stack of
+/// `(file, symbol)` frames and, on revisiting a frame, reports the full
+/// loop instead of silently discarding it -- resolution still terminates
+/// and the call edge is left unresolved either way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReexportCycle {
+    /// The chain of files visited, in order, ending with the file that
+    /// closed the loop (i.e. `path[0] == path[path.len() - 1]`).
+    pub path: Vec<PathBuf>,
+    /// The re-exported symbol name that cycles.
+    pub symbol: String,
 }
 
 /// Tier 1 + Tier 2 resolver for TypeScript and JavaScript.
@@ -34,6 +59,17 @@ pub struct TsResolver {
     module_resolver: Resolver,
     /// tsconfig.json path aliases: alias prefix -> resolved base path
     path_aliases: Mutex<HashMap<String, String>>,
+    /// Per-file set of class names decorated as DI providers (`@Injectable`,
+    /// `@Controller`, `@Module`, or a custom factory decorator), used to
+    /// resolve `ReferenceKind::Inject` edges.
+    provider_cache: Mutex<HashMap<PathBuf, HashSet<String>>>,
+    /// Per-file namespace imports (`import * as ns from './barrel'`):
+    /// local alias -> raw module specifier. Used to walk qualified member
+    /// access (`ns.sub.process()`) through a barrel re-export chain.
+    namespace_import_cache: Mutex<HashMap<PathBuf, HashMap<String, String>>>,
+    /// Cycles detected while walking multi-hop barrel re-export chains in
+    /// `follow_reexport_chain`, surfaced to callers via `reexport_diagnostics`.
+    reexport_cycles: Mutex<Vec<ReexportCycle>>,
 }
 
 impl TsResolver {
@@ -58,9 +94,18 @@ impl TsResolver {
             semantic_cache: Mutex::new(HashMap::new()),
             module_resolver: Resolver::new(options),
             path_aliases: Mutex::new(HashMap::new()),
+            provider_cache: Mutex::new(HashMap::new()),
+            namespace_import_cache: Mutex::new(HashMap::new()),
+            reexport_cycles: Mutex::new(Vec::new()),
         }
     }
 
+    /// Cycles detected so far while walking barrel re-export chains (see
+    /// `follow_reexport_chain`). Empty if none have been found.
+    pub fn reexport_diagnostics(&self) -> Vec<ReexportCycle> {
+        self.reexport_cycles.lock().unwrap().clone()
+    }
+
     /// Load tsconfig.json path aliases from a project root.
     pub fn load_tsconfig_paths(&self, project_root: &Path) {
         let tsconfig_path = project_root.join("tsconfig.json");
@@ -111,6 +156,7 @@ impl TsResolver {
             return OxcSymbolInfo {
                 symbols: HashMap::new(),
                 reexports: HashMap::new(),
+                star_reexport_sources: Vec::new(),
             };
         }
 
@@ -136,10 +182,12 @@ impl TsResolver {
 
         // Detect re-exports: `export { X } from './module'`
         let reexports = extract_reexports(content);
+        let star_reexport_sources = extract_star_reexport_sources(content);
 
         let info = OxcSymbolInfo {
             symbols: symbol_map,
             reexports,
+            star_reexport_sources,
         };
         self.semantic_cache
             .lock()
@@ -179,6 +227,32 @@ impl TsResolver {
             }
         }
 
+        // Tier 1.5: recognize DI provider decorators and constructor-parameter
+        // injection, emitting ReferenceKind::Inject edges from each consumer
+        // to the provider type (or @Inject token) it requires.
+        let providers = extract_provider_classes(content);
+        self.provider_cache
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), providers);
+
+        let namespace_imports = extract_namespace_imports(content);
+        self.namespace_import_cache
+            .lock()
+            .unwrap()
+            .insert(path.to_path_buf(), namespace_imports);
+
+        for injection in extract_constructor_injections(content) {
+            result.references.push(Reference {
+                name: injection.name,
+                file_path: path.to_string_lossy().to_string(),
+                line: injection.line,
+                kind: ReferenceKind::Inject,
+                resolved_to: None,
+                namespace: Namespace::Value,
+            });
+        }
+
         // Tier 2: resolve import paths using oxc_resolver + path aliases
         let dir = path.parent().unwrap_or(Path::new("."));
         let aliases = self.path_aliases.lock().unwrap();
@@ -205,6 +279,252 @@ impl TsResolver {
         self.cache.lock().unwrap().get(path).cloned()
     }
 
+    /// Resolve an `Inject` reference to the provider class that satisfies it.
+    /// Looks first in the consumer's own file, then across every other file
+    /// this resolver has parsed, for a class carrying a provider decorator
+    /// with a matching name.
+    ///
+    /// Returns `None` both when no such provider is registered and when the
+    /// name resolves to an external package import (e.g. `@nestjs/common`).
+    /// Use `is_external_injection` to tell the two apart before reporting an
+    /// unresolved injection as an error.
+    pub fn resolve_injection_edge(&self, reference: &Reference) -> Option<ResolvedEdge> {
+        if reference.kind != ReferenceKind::Inject {
+            return None;
+        }
+        let providers = self.provider_cache.lock().unwrap();
+        let consumer_file = PathBuf::from(&reference.file_path);
+
+        if let Some(local) = providers.get(&consumer_file) {
+            if local.contains(&reference.name) {
+                return Some(ResolvedEdge {
+                    target_file: reference.file_path.clone(),
+                    target_name: reference.name.clone(),
+                    confidence: 0.9, // Tier 1: same-file provider
+                    resolution_tier: "tier1".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
+                });
+            }
+        }
+
+        for (file, names) in providers.iter() {
+            if file == &consumer_file {
+                continue;
+            }
+            if names.contains(&reference.name) {
+                return Some(ResolvedEdge {
+                    target_file: file.to_string_lossy().to_string(),
+                    target_name: reference.name.clone(),
+                    confidence: 0.75, // Tier 1: cross-file provider
+                    resolution_tier: "tier1".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
+                });
+            }
+        }
+
+        None
+    }
+
+    /// Whether an unresolved `Inject` reference's name comes from an
+    /// external package import (e.g. `import { Foo } from '@nestjs/common'`)
+    /// rather than a missing local provider. Callers should treat external
+    /// injections as unresolved-but-external, not as errors.
+    pub fn is_external_injection(&self, reference: &Reference) -> bool {
+        let cache = self.cache.lock().unwrap();
+        let consumer_file = PathBuf::from(&reference.file_path);
+        let Some(parsed) = cache.get(&consumer_file) else {
+            return false;
+        };
+        find_import_for_name(&parsed.imports, &reference.name)
+            .map(|imp| !imp.is_relative)
+            .unwrap_or(false)
+    }
+
+    /// Walk a qualified member-access path (e.g. `ns.sub.process`) through a
+    /// namespace import (`import * as ns from './barrel'`) and its barrel
+    /// re-export chain, binding as many segments as the semantic cache
+    /// allows. Returns `None` only when `receiver`'s first segment isn't a
+    /// known namespace import -- the ordinary single-name resolution path in
+    /// `resolve_call_edge` handles everything else. Once a namespace import
+    /// is found this never returns `None`: it reports the deepest binding it
+    /// could make plus how many trailing segments it could not resolve.
+    fn resolve_namespace_path_edge(
+        &self,
+        call_site: &CallSite,
+        receiver: &str,
+    ) -> Option<ResolvedEdge> {
+        let mut segments: Vec<&str> = receiver.split('.').collect();
+        segments.push(call_site.callee_name.as_str());
+        let total = segments.len() as u32;
+
+        let caller_file = PathBuf::from(&call_site.file_path);
+        let namespace_imports = self.namespace_import_cache.lock().unwrap();
+        let file_imports = namespace_imports.get(&caller_file)?;
+        let base_source = file_imports.get(segments[0])?.clone();
+        drop(namespace_imports);
+
+        let mut current_target = base_source;
+        let mut current_name = segments[0].to_string();
+        let mut depth = 1u32;
+
+        let semantic_cache = self.semantic_cache.lock().unwrap();
+        for (i, segment) in segments.iter().enumerate().skip(1) {
+            let is_last = i as u32 == total - 1;
+            let target_path = PathBuf::from(&current_target);
+            let Some(target_info) = semantic_cache.get(&target_path) else {
+                break;
+            };
+
+            if is_last {
+                if let Some((is_exported, _)) = target_info.symbols.get(*segment) {
+                    if *is_exported {
+                        return Some(ResolvedEdge {
+                            target_file: current_target,
+                            target_name: segment.to_string(),
+                            confidence: 0.90, // Tier 2: qualified barrel path, fully walked
+                            resolution_tier: "tier2_barrel".into(),
+                            resolved_depth: total,
+                            unresolved_segments: 0,
+                        });
+                    }
+                }
+                if let Some((real_source, original_name, namespace)) =
+                    target_info.reexports.get(*segment)
+                {
+                    if namespace.includes_value() {
+                        return Some(ResolvedEdge {
+                            target_file: real_source.clone(),
+                            target_name: original_name.clone(),
+                            confidence: 0.90, // Tier 2: qualified barrel path, fully walked
+                            resolution_tier: "tier2_barrel".into(),
+                            resolved_depth: total,
+                            unresolved_segments: 0,
+                        });
+                    }
+                }
+                break;
+            }
+
+            // An intermediate segment can only be carried further by a
+            // named re-export (`export { sub } from './sub-module'`);
+            // `export * as sub from` lines are not recorded as re-exports.
+            let Some((real_source, _original_name, _namespace)) =
+                target_info.reexports.get(*segment)
+            else {
+                break;
+            };
+            current_target = real_source.clone();
+            current_name = segment.to_string();
+            depth += 1;
+        }
+        drop(semantic_cache);
+
+        let unresolved_segments = total - depth;
+        Some(ResolvedEdge {
+            target_file: current_target,
+            target_name: current_name,
+            confidence: (0.85 - 0.20 * unresolved_segments as f64).max(0.20),
+            resolution_tier: "tier2_barrel".into(),
+            resolved_depth: depth,
+            unresolved_segments,
+        })
+    }
+
+    /// Walk a barrel re-export chain starting at `(file, symbol)`, following
+    /// `export { x } from './y'` hop by hop until it bottoms out at a real
+    /// definition. Maintains an ordered stack of visited `(file, symbol)`
+    /// frames, Dhall-`ImportStack`-style; if a hop would revisit a frame
+    /// already on the stack, the chain is circular, so the full loop is
+    /// recorded as a `ReexportCycle` (retrievable via `reexport_diagnostics`)
+    /// and resolution stops there rather than recursing forever. Returns
+    /// `None` if the chain is circular, type-only, or runs off the edge of
+    /// what's in the semantic cache; `Some((file, name))` at the real
+    /// definition otherwise.
+    fn follow_reexport_chain(&self, file: &str, symbol: &str) -> Option<(String, String)> {
+        let semantic_cache = self.semantic_cache.lock().unwrap();
+        let mut current_file = file.to_string();
+        let mut current_symbol = symbol.to_string();
+        let mut stack: Vec<(PathBuf, String)> = Vec::new();
+
+        loop {
+            let frame = (PathBuf::from(&current_file), current_symbol.clone());
+            if let Some(cycle_start) = stack.iter().position(|f| *f == frame) {
+                let mut path: Vec<PathBuf> = stack[cycle_start..]
+                    .iter()
+                    .map(|(p, _)| p.clone())
+                    .collect();
+                path.push(frame.0);
+                drop(semantic_cache);
+                self.reexport_cycles.lock().unwrap().push(ReexportCycle {
+                    path,
+                    symbol: current_symbol,
+                });
+                return None;
+            }
+            stack.push(frame);
+
+            let target_path = PathBuf::from(&current_file);
+            let target_info = semantic_cache.get(&target_path)?;
+
+            if let Some((is_exported, _)) = target_info.symbols.get(&current_symbol) {
+                if *is_exported {
+                    return Some((current_file, current_symbol));
+                }
+            }
+
+            let (real_source, original_name, namespace) =
+                target_info.reexports.get(&current_symbol)?;
+            if !namespace.includes_value() {
+                return None;
+            }
+            current_file = real_source.clone();
+            current_symbol = original_name.clone();
+        }
+    }
+
+    /// Resolve `name` against every `export * from './module'` source of
+    /// `importer_file`, mirroring rustc's glob-import ambiguity rule: a
+    /// direct `export { name }` or a local definition always wins (callers
+    /// must check those first -- this only looks at star sources), but when
+    /// `name` is reachable through more than one star source, no single one
+    /// of them can be picked, so every contributing file comes back here
+    /// instead of `resolve_call_edge` guessing one.
+    pub fn resolve_star_reexport_candidates(
+        &self,
+        importer_file: &str,
+        name: &str,
+    ) -> Vec<ResolvedEdge> {
+        let semantic_cache = self.semantic_cache.lock().unwrap();
+        let Some(importer_info) = semantic_cache.get(&PathBuf::from(importer_file)) else {
+            return Vec::new();
+        };
+
+        let mut candidates: Vec<ResolvedEdge> = importer_info
+            .star_reexport_sources
+            .iter()
+            .filter_map(|source| {
+                let target_info = semantic_cache.get(&PathBuf::from(source))?;
+                let (is_exported, _) = target_info.symbols.get(name)?;
+                is_exported.then_some(ResolvedEdge {
+                    target_file: source.clone(),
+                    target_name: name.to_string(),
+                    confidence: 0.90, // Tier 2: single star source, unambiguous
+                    resolution_tier: "tier2_barrel".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
+                })
+            })
+            .collect();
+
+        if candidates.len() > 1 {
+            for candidate in &mut candidates {
+                candidate.confidence = 0.30; // Tier 2: ambiguous -- multiple star sources export this name
+            }
+        }
+        candidates
+    }
 }
 
 impl Default for TsResolver {
@@ -235,6 +555,16 @@ impl LanguageResolver for TsResolver {
     }
 
     fn resolve_call_edge(&self, call_site: &CallSite) -> Option<ResolvedEdge> {
+        // Qualified member access through a namespace import, e.g.
+        // `ns.sub.process()` where `ns` comes from `import * as ns from
+        // './barrel'`. Walk as many segments as the semantic cache can bind
+        // and report how far we got rather than giving up at the first hop.
+        if let Some(receiver) = &call_site.receiver {
+            if let Some(edge) = self.resolve_namespace_path_edge(call_site, receiver) {
+                return Some(edge);
+            }
+        }
+
         let cache = self.cache.lock().unwrap();
         let caller_file = PathBuf::from(&call_site.file_path);
         let caller_result = cache.get(&caller_file)?;
@@ -256,18 +586,50 @@ impl LanguageResolver for TsResolver {
                             target_file,
                             target_name: call_site.callee_name.clone(),
                             confidence: 0.95, // Tier 2: oxc-verified
+                            resolution_tier: "tier2_oxc".into(),
+                            resolved_depth: 1,
+                            unresolved_segments: 0,
                         });
                     }
                 }
-                // Check if it's a re-export
-                if let Some((real_source, original_name)) =
+                // Check if it's a re-export. A type-only re-export
+                // (`export type { X } from ...`) resolves for type queries
+                // but must never produce a call edge.
+                if let Some((real_source, original_name, namespace)) =
                     target_info.reexports.get(&call_site.callee_name)
                 {
-                    return Some(ResolvedEdge {
-                        target_file: real_source.clone(),
-                        target_name: original_name.clone(),
-                        confidence: 0.95, // Tier 2: barrel re-export traced
-                    });
+                    if !namespace.includes_value() {
+                        return None;
+                    }
+                    let start_file = real_source.clone();
+                    let start_symbol = original_name.clone();
+                    drop(semantic_cache);
+                    return self.follow_reexport_chain(&start_file, &start_symbol).map(
+                        |(target_file, target_name)| ResolvedEdge {
+                            target_file,
+                            target_name,
+                            confidence: 0.95, // Tier 2: barrel re-export chain traced
+                            resolution_tier: "tier2_barrel".into(),
+                            resolved_depth: 1,
+                            unresolved_segments: 0,
+                        },
+                    );
+                }
+                // Neither a direct definition nor a named re-export --
+                // check whether the barrel's `export * from` sources carry
+                // this name. A local definition or named re-export always
+                // takes precedence over a star source (already handled
+                // above); a name reachable through more than one star
+                // source is ambiguous and must not be guessed at.
+                if !target_info.star_reexport_sources.is_empty() {
+                    drop(semantic_cache);
+                    let candidates =
+                        self.resolve_star_reexport_candidates(&target_file, &call_site.callee_name);
+                    return match candidates.len() {
+                        0 => None,
+                        1 => candidates.into_iter().next(),
+                        _ => None, // Ambiguous: multiple star sources export this name
+                    };
                 }
             }
             drop(semantic_cache);
@@ -277,6 +639,9 @@ impl LanguageResolver for TsResolver {
                 target_file,
                 target_name: call_site.callee_name.clone(),
                 confidence: 0.85, // Tier 1 only
+                resolution_tier: "tier1".into(),
+                resolved_depth: 1,
+                unresolved_segments: 0,
             });
         }
 
@@ -287,6 +652,9 @@ impl LanguageResolver for TsResolver {
                     target_file: call_site.file_path.clone(),
                     target_name: call_site.callee_name.clone(),
                     confidence: 0.95,
+                    resolution_tier: "tier1".into(),
+                    resolved_depth: 1,
+                    unresolved_segments: 0,
                 });
             }
         }
@@ -388,6 +756,193 @@ fn extract_decl_name(s: &str) -> Option<String> {
     }
 }
 
+/// A dependency-injection site recognized in a constructor parameter list.
+struct Injection {
+    line: u32,
+    /// Provider type name, or the `@Inject('TOKEN')` string when the
+    /// parameter's type is opaque (primitive, generic, or absent).
+    name: String,
+}
+
+/// Find class declarations decorated with a provider decorator
+/// (`@Injectable`, `@Controller`, `@Module`, or any custom factory
+/// decorator like `@Throttle`) and return their names. Per NestJS-style
+/// DI conventions, any class-level decorator marks the class as
+/// framework-managed and eligible to satisfy an injection.
+fn extract_provider_classes(content: &str) -> HashSet<String> {
+    let mut providers = HashSet::new();
+    let lines: Vec<&str> = content.lines().collect();
+    for (i, line) in lines.iter().enumerate() {
+        if !line.trim().starts_with('@') {
+            continue;
+        }
+        // Walk past any stacked decorators to the declaration they annotate.
+        for next in lines.iter().skip(i + 1) {
+            let next = next.trim();
+            if next.is_empty() || next.starts_with('@') {
+                continue;
+            }
+            if let Some(name) = extract_class_name(next) {
+                providers.insert(name);
+            }
+            break;
+        }
+    }
+    providers
+}
+
+/// Extract a class name from a declaration fragment like
+/// `export abstract class Foo {`, stripping leading modifiers.
+fn extract_class_name(s: &str) -> Option<String> {
+    let mut rest = s;
+    for modifier in ["export default ", "export ", "abstract "] {
+        if let Some(stripped) = rest.strip_prefix(modifier) {
+            rest = stripped;
+        }
+    }
+    let rest = rest.strip_prefix("class ")?;
+    let name: String = rest
+        .trim()
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+        .collect();
+    if name.is_empty() {
+        None
+    } else {
+        Some(name)
+    }
+}
+
+/// Find the index of the `)` matching the `(` at `open_idx`.
+fn find_matching_paren(s: &str, open_idx: usize) -> Option<usize> {
+    let mut depth = 0i32;
+    for (i, b) in s.bytes().enumerate().skip(open_idx) {
+        match b {
+            b'(' => depth += 1,
+            b')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+/// Split `s` on top-level commas (not nested inside `()`, `<>`, `{}`, `[]`),
+/// returning each piece with its byte offset within `s`.
+fn split_top_level_commas(s: &str) -> Vec<(usize, &str)> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut start = 0usize;
+    for (i, c) in s.char_indices() {
+        match c {
+            '(' | '<' | '{' | '[' => depth += 1,
+            ')' | '>' | '}' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                parts.push((start, &s[start..i]));
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < s.len() {
+        parts.push((start, &s[start..]));
+    }
+    parts
+}
+
+/// Scan `content` for `constructor(...)` parameter lists and recognize
+/// injection sites: a `@Inject('TOKEN')` decorator, or an accessibility
+/// modifier (`private`/`public`/`protected`/`readonly`) paired with a
+/// concrete type annotation (`constructor(private dep: SomeService)`).
+fn extract_constructor_injections(content: &str) -> Vec<Injection> {
+    let mut injections = Vec::new();
+    let mut search_from = 0usize;
+    while let Some(rel) = content[search_from..].find("constructor(") {
+        let ctor_start = search_from + rel;
+        let open_paren = ctor_start + "constructor".len();
+        let Some(close_paren) = find_matching_paren(content, open_paren) else {
+            break;
+        };
+        let params_text = &content[open_paren + 1..close_paren];
+        let base_line = content[..open_paren].matches('\n').count() as u32 + 1;
+
+        for (offset, param) in split_top_level_commas(params_text) {
+            let param = param.trim();
+            if param.is_empty() {
+                continue;
+            }
+            if let Some(name) = parse_injected_param(param) {
+                let line = base_line + params_text[..offset].matches('\n').count() as u32;
+                injections.push(Injection { line, name });
+            }
+        }
+
+        search_from = close_paren + 1;
+    }
+    injections
+}
+
+/// Parse a single constructor parameter fragment and return the name to
+/// record as an `Inject` reference, if this parameter is an injection site.
+fn parse_injected_param(param: &str) -> Option<String> {
+    let mut rest = param.trim();
+
+    // Leading decorators, e.g. `@Optional() @Inject('TOKEN')`.
+    let mut token = None;
+    while let Some(stripped) = rest.strip_prefix('@') {
+        let paren = stripped.find('(')?;
+        let decorator_name = stripped[..paren].trim();
+        let close = find_matching_paren(stripped, paren)?;
+        if decorator_name == "Inject" {
+            token = extract_string_literal(&stripped[paren + 1..close]);
+        }
+        rest = stripped[close + 1..].trim_start();
+    }
+
+    loop {
+        let stripped = ["private ", "public ", "protected ", "readonly "]
+            .iter()
+            .find_map(|m| rest.strip_prefix(m));
+        match stripped {
+            Some(s) => rest = s.trim_start(),
+            None => break,
+        }
+    }
+
+    // `rest` now looks like `dep: SomeService`, `dep?: string`, or `dep`.
+    let mut name_end = rest
+        .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'))
+        .unwrap_or(rest.len());
+    if name_end == 0 {
+        return token;
+    }
+    if rest[name_end..].starts_with('?') {
+        name_end += 1;
+    }
+    let type_name = rest[name_end..]
+        .trim_start()
+        .strip_prefix(':')
+        .map(|t| t.split('=').next().unwrap_or(t).trim().to_string());
+
+    match type_name {
+        Some(t) if !t.is_empty() && !is_opaque_type(&t) => Some(t),
+        _ => token,
+    }
+}
+
+/// Types too generic to identify a concrete provider; injection with one of
+/// these only counts if paired with an `@Inject('TOKEN')` decorator.
+fn is_opaque_type(t: &str) -> bool {
+    matches!(
+        t,
+        "string" | "number" | "boolean" | "any" | "unknown" | "object"
+    ) || t.starts_with("Record<")
+}
+
 /// Apply tsconfig path alias resolution.
 /// E.g., `@components/Button` -> `/abs/path/src/components/Button`
 fn resolve_path_alias(source: &str, aliases: &HashMap<String, String>) -> Option<String> {
@@ -405,20 +960,27 @@ fn resolve_path_alias(source: &str, aliases: &HashMap<String, String>) -> Option
 }
 
 /// Extract re-exports from source text.
-/// Parses patterns like: `export { Foo, Bar } from './module'`
-fn extract_reexports(content: &str) -> HashMap<String, (String, String)> {
+/// Parses patterns like: `export { Foo, Bar } from './module'` (value
+/// namespace) and `export type { Foo } from './module'` (type namespace
+/// only -- must never produce a call edge).
+fn extract_reexports(content: &str) -> HashMap<String, (String, String, Namespace)> {
     let mut reexports = HashMap::new();
     for line in content.lines() {
         let trimmed = line.trim();
         if !trimmed.starts_with("export") || !trimmed.contains("from") {
             continue;
         }
-        // Simple pattern: export { names } from 'source'
-        if let Some(brace_start) = trimmed.find('{') {
-            if let Some(brace_end) = trimmed.find('}') {
-                let names_part = &trimmed[brace_start + 1..brace_end];
-                let from_idx = trimmed.find("from").unwrap_or(trimmed.len());
-                let source_part = &trimmed[from_idx..];
+        let after_export = trimmed.strip_prefix("export").unwrap().trim_start();
+        let (after_export, namespace) = match after_export.strip_prefix("type ") {
+            Some(rest) => (rest, Namespace::Type),
+            None => (after_export, Namespace::Value),
+        };
+        // Simple pattern: export [type] { names } from 'source'
+        if let Some(brace_start) = after_export.find('{') {
+            if let Some(brace_end) = after_export.find('}') {
+                let names_part = &after_export[brace_start + 1..brace_end];
+                let from_idx = after_export.find("from").unwrap_or(after_export.len());
+                let source_part = &after_export[from_idx..];
                 let source = extract_string_literal(source_part);
                 if let Some(src) = source {
                     for name_entry in names_part.split(',') {
@@ -429,7 +991,7 @@ fn extract_reexports(content: &str) -> HashMap<String, (String, String)> {
                         } else {
                             original.clone()
                         };
-                        reexports.insert(local, (src.clone(), original));
+                        reexports.insert(local, (src.clone(), original, namespace));
                     }
                 }
             }
@@ -439,6 +1001,65 @@ fn extract_reexports(content: &str) -> HashMap<String, (String, String)> {
     reexports
 }
 
+/// Extract the module specifiers of bare `export * from './module'`
+/// statements (as opposed to `extract_reexports`, which only handles named
+/// `export { ... } from` forms). A name exported by more than one of these
+/// sources is ambiguous; see `TsResolver::resolve_star_reexport_candidates`.
+fn extract_star_reexport_sources(content: &str) -> Vec<String> {
+    let mut sources = Vec::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if !trimmed.starts_with("export") || !trimmed.contains('*') || !trimmed.contains("from") {
+            continue;
+        }
+        // Exclude `export * as ns from '...'` -- that's a namespace
+        // re-export of a single binding, not a wildcard of many names.
+        if trimmed.contains("* as ") {
+            continue;
+        }
+        let from_idx = match trimmed.find("from") {
+            Some(idx) => idx,
+            None => continue,
+        };
+        if let Some(source) = extract_string_literal(&trimmed[from_idx..]) {
+            sources.push(source);
+        }
+    }
+    sources
+}
+
+/// Extract namespace imports from source text: `import * as Name from
+/// 'source'`. Returns local alias -> raw module specifier, matching the
+/// unresolved-path convention `extract_reexports` uses, so the result can be
+/// looked up directly against the semantic cache.
+fn extract_namespace_imports(content: &str) -> HashMap<String, String> {
+    let mut imports = HashMap::new();
+    for line in content.lines() {
+        let trimmed = line.trim();
+        if !trimmed.starts_with("import") || !trimmed.contains("* as ") {
+            continue;
+        }
+        let Some(as_idx) = trimmed.find("* as ") else {
+            continue;
+        };
+        let alias: String = trimmed[as_idx + "* as ".len()..]
+            .trim_start()
+            .chars()
+            .take_while(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+            .collect();
+        if alias.is_empty() {
+            continue;
+        }
+        let Some(from_idx) = trimmed.find("from") else {
+            continue;
+        };
+        if let Some(source) = extract_string_literal(&trimmed[from_idx..]) {
+            imports.insert(alias, source);
+        }
+    }
+    imports
+}
+
 /// Extract a string literal from a `from '...'` or `from "..."` fragment.
 fn extract_string_literal(s: &str) -> Option<String> {
     let start_single = s.find('\'');
@@ -574,11 +1195,71 @@ export * from './utils';
         assert_eq!(reexports.len(), 2);
         assert_eq!(
             reexports.get("UserService").unwrap(),
-            &("./user-service".to_string(), "UserService".to_string())
+            &("./user-service".to_string(), "UserService".to_string(), Namespace::Value)
         );
         assert_eq!(
             reexports.get("Auth").unwrap(),
-            &("./auth-service".to_string(), "AuthService".to_string())
+            &("./auth-service".to_string(), "AuthService".to_string(), Namespace::Value)
+        );
+    }
+
+    #[test]
+    fn test_barrel_file_type_reexport_is_type_namespace() {
+        let reexports = extract_reexports(
+            r#"
+export type { Parser } from './parser';
+export { parse } from './parser';
+"#,
+        );
+        assert_eq!(
+            reexports.get("Parser").unwrap(),
+            &("./parser".to_string(), "Parser".to_string(), Namespace::Type)
+        );
+        assert_eq!(
+            reexports.get("parse").unwrap(),
+            &("./parser".to_string(), "parse".to_string(), Namespace::Value)
+        );
+    }
+
+    #[test]
+    fn test_type_only_barrel_reexport_does_not_resolve_call_edge() {
+        let resolver = TsResolver::new();
+
+        // "./impl.ts" declares `parse`; the barrel re-exports it under the
+        // type namespace only.
+        resolver.parse_file(
+            Path::new("./impl.ts"),
+            r#"
+export function parse(input: string): unknown {
+    return JSON.parse(input);
+}
+"#,
+        );
+        resolver.parse_file(
+            Path::new("./barrel.ts"),
+            r#"
+export type { parse } from './impl.ts';
+"#,
+        );
+
+        let caller_source = r#"
+import { parse } from './barrel.ts';
+
+function run() {
+    parse("{}");
+}
+"#;
+        resolver.parse_file(Path::new("caller.ts"), caller_source);
+
+        let edge = resolver.resolve_call_edge(&CallSite {
+            file_path: "caller.ts".into(),
+            line: 5,
+            callee_name: "parse".into(),
+            receiver: None,
+        });
+        assert!(
+            edge.is_none(),
+            "a type-only barrel re-export must never produce a call edge"
         );
     }
 
@@ -636,6 +1317,282 @@ function handleRequest() {
         assert!(edge.confidence >= 0.85);
     }
 
+    #[test]
+    fn test_namespace_import_full_qualified_path_resolves_through_barrel_chain() {
+        let resolver = TsResolver::new();
+
+        resolver.parse_file(
+            Path::new("./sub-module.ts"),
+            r#"
+export function process(x: string): void {}
+"#,
+        );
+        resolver.parse_file(
+            Path::new("./barrel.ts"),
+            r#"
+export { sub } from './sub-module.ts';
+"#,
+        );
+        resolver.parse_file(
+            Path::new("caller.ts"),
+            r#"
+import * as ns from './barrel.ts';
+
+function run() {
+    ns.sub.process("hi");
+}
+"#,
+        );
+
+        let edge = resolver.resolve_call_edge(&CallSite {
+            file_path: "caller.ts".into(),
+            line: 5,
+            callee_name: "process".into(),
+            receiver: Some("ns.sub".into()),
+        });
+        let edge = edge.expect("fully-walkable qualified path should resolve");
+        assert_eq!(edge.target_file, "./sub-module.ts");
+        assert_eq!(edge.target_name, "process");
+        assert_eq!(edge.resolved_depth, 3);
+        assert_eq!(edge.unresolved_segments, 0);
+        assert!(edge.confidence >= 0.85);
+    }
+
+    #[test]
+    fn test_namespace_import_partial_path_reports_unresolved_segments() {
+        let resolver = TsResolver::new();
+
+        resolver.parse_file(
+            Path::new("./barrel.ts"),
+            r#"
+export function unrelated(): void {}
+"#,
+        );
+        resolver.parse_file(
+            Path::new("caller.ts"),
+            r#"
+import * as ns from './barrel.ts';
+
+function run() {
+    ns.sub.process("hi");
+}
+"#,
+        );
+
+        // The barrel never re-exports anything named `sub`, so resolution
+        // can only bind the namespace import itself (depth 1) and must
+        // report the two trailing segments it couldn't walk through --
+        // never silently fail just because the full path didn't resolve.
+        let edge = resolver.resolve_call_edge(&CallSite {
+            file_path: "caller.ts".into(),
+            line: 5,
+            callee_name: "process".into(),
+            receiver: Some("ns.sub".into()),
+        });
+        let edge = edge.expect("a known namespace import must yield a partial binding, not None");
+        assert_eq!(edge.target_file, "./barrel.ts");
+        assert_eq!(edge.target_name, "ns");
+        assert_eq!(edge.resolved_depth, 1);
+        assert_eq!(edge.unresolved_segments, 2);
+        assert!(edge.confidence < 0.85);
+    }
+
+    #[test]
+    fn test_reexport_chain_walks_multiple_barrel_hops() {
+        let resolver = TsResolver::new();
+
+        resolver.parse_file(
+            Path::new("./impl.ts"),
+            r#"
+export function parse(x: string): void {}
+"#,
+        );
+        resolver.parse_file(
+            Path::new("./inner-barrel.ts"),
+            r#"
+export { parse } from './impl.ts';
+"#,
+        );
+        resolver.parse_file(
+            Path::new("./outer-barrel.ts"),
+            r#"
+export { parse } from './inner-barrel.ts';
+"#,
+        );
+        resolver.parse_file(
+            Path::new("caller.ts"),
+            r#"
+import { parse } from './outer-barrel.ts';
+
+function run() {
+    parse("hi");
+}
+"#,
+        );
+
+        let edge = resolver.resolve_call_edge(&CallSite {
+            file_path: "caller.ts".into(),
+            line: 5,
+            callee_name: "parse".into(),
+            receiver: None,
+        });
+        let edge = edge.expect("a non-circular multi-hop barrel chain should still resolve");
+        assert_eq!(edge.target_file, "./impl.ts");
+        assert_eq!(edge.target_name, "parse");
+        assert!(resolver.reexport_diagnostics().is_empty());
+    }
+
+    #[test]
+    fn test_circular_barrel_reexport_is_diagnosed_and_terminates() {
+        let resolver = TsResolver::new();
+
+        resolver.parse_file(
+            Path::new("./a.ts"),
+            r#"
+export { helper } from './b.ts';
+"#,
+        );
+        resolver.parse_file(
+            Path::new("./b.ts"),
+            r#"
+export { helper } from './a.ts';
+"#,
+        );
+        resolver.parse_file(
+            Path::new("caller.ts"),
+            r#"
+import { helper } from './a.ts';
+
+function run() {
+    helper();
+}
+"#,
+        );
+
+        // The cycle must not hang resolution -- it terminates with no edge...
+        let edge = resolver.resolve_call_edge(&CallSite {
+            file_path: "caller.ts".into(),
+            line: 5,
+            callee_name: "helper".into(),
+            receiver: None,
+        });
+        assert!(edge.is_none());
+
+        // ...and the full loop is reported rather than silently discarded.
+        let cycles = resolver.reexport_diagnostics();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].symbol, "helper");
+        assert_eq!(
+            cycles[0].path,
+            vec![
+                PathBuf::from("./b.ts"),
+                PathBuf::from("./a.ts"),
+                PathBuf::from("./b.ts"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ambiguous_star_reexport_refuses_to_pick_one() {
+        let resolver = TsResolver::new();
+
+        resolver.parse_file(
+            Path::new("./a.ts"),
+            r#"
+export function alpha(): void {}
+"#,
+        );
+        resolver.parse_file(
+            Path::new("./b.ts"),
+            r#"
+export function alpha(): void {}
+"#,
+        );
+        resolver.parse_file(
+            Path::new("./barrel.ts"),
+            r#"
+export * from './a.ts';
+export * from './b.ts';
+"#,
+        );
+        resolver.parse_file(
+            Path::new("caller.ts"),
+            r#"
+import { alpha } from './barrel.ts';
+
+function run() {
+    alpha();
+}
+"#,
+        );
+
+        let edge = resolver.resolve_call_edge(&CallSite {
+            file_path: "caller.ts".into(),
+            line: 5,
+            callee_name: "alpha".into(),
+            receiver: None,
+        });
+        assert!(
+            edge.is_none(),
+            "a name reachable through two star sources must not be guessed at"
+        );
+
+        let candidates = resolver.resolve_star_reexport_candidates("./barrel.ts", "alpha");
+        let mut sources: Vec<_> = candidates.iter().map(|e| e.target_file.clone()).collect();
+        sources.sort();
+        assert_eq!(sources, vec!["./a.ts".to_string(), "./b.ts".to_string()]);
+        assert!(candidates.iter().all(|e| e.confidence < 0.5));
+    }
+
+    #[test]
+    fn test_named_reexport_shadows_star_reexport_of_same_name() {
+        let resolver = TsResolver::new();
+
+        resolver.parse_file(
+            Path::new("./a.ts"),
+            r#"
+export function alpha(): void {}
+"#,
+        );
+        resolver.parse_file(
+            Path::new("./local.ts"),
+            r#"
+export function alpha(): void {}
+"#,
+        );
+        resolver.parse_file(
+            Path::new("./barrel.ts"),
+            r#"
+export * from './a.ts';
+export { alpha } from './local.ts';
+"#,
+        );
+        resolver.parse_file(
+            Path::new("caller.ts"),
+            r#"
+import { alpha } from './barrel.ts';
+
+function run() {
+    alpha();
+}
+"#,
+        );
+
+        // A direct named re-export deterministically wins over a star
+        // source providing the same name, even though `alpha` is also
+        // reachable through `./a.ts`'s star export.
+        let edge = resolver
+            .resolve_call_edge(&CallSite {
+                file_path: "caller.ts".into(),
+                line: 5,
+                callee_name: "alpha".into(),
+                receiver: None,
+            })
+            .expect("a named re-export must deterministically win over a star source");
+        assert_eq!(edge.target_file, "./local.ts");
+        assert_eq!(edge.target_name, "alpha");
+    }
+
     #[test]
     fn test_extract_string_literal() {
         assert_eq!(
@@ -648,4 +1605,96 @@ function handleRequest() {
         );
         assert_eq!(extract_string_literal("no quotes here"), None);
     }
+
+    #[test]
+    fn test_constructor_injection_emits_inject_reference() {
+        let resolver = TsResolver::new();
+        let source = r#"
+@Injectable()
+class UserService {
+    constructor(private db: DatabaseService) {}
+}
+"#;
+        let result = resolver.parse_file(Path::new("user-service.ts"), source);
+        let injections: Vec<_> = result
+            .references
+            .iter()
+            .filter(|r| r.kind == ReferenceKind::Inject)
+            .collect();
+        assert_eq!(injections.len(), 1);
+        assert_eq!(injections[0].name, "DatabaseService");
+    }
+
+    #[test]
+    fn test_token_injection_records_token_for_opaque_type() {
+        let resolver = TsResolver::new();
+        let source = r#"
+@Injectable()
+class ConfigConsumer {
+    constructor(@Inject('APP_CONFIG') private config: string) {}
+}
+"#;
+        let result = resolver.parse_file(Path::new("consumer.ts"), source);
+        let injections: Vec<_> = result
+            .references
+            .iter()
+            .filter(|r| r.kind == ReferenceKind::Inject)
+            .collect();
+        assert_eq!(injections.len(), 1);
+        assert_eq!(injections[0].name, "APP_CONFIG");
+    }
+
+    #[test]
+    fn test_resolve_injection_edge_cross_file() {
+        let resolver = TsResolver::new();
+        resolver.parse_file(
+            Path::new("database.service.ts"),
+            r#"
+@Injectable()
+class DatabaseService {
+    query(id: number): unknown {
+        return null;
+    }
+}
+"#,
+        );
+        let consumer_source = r#"
+@Injectable()
+class UserService {
+    constructor(private db: DatabaseService) {}
+}
+"#;
+        let result = resolver.parse_file(Path::new("user-service.ts"), consumer_source);
+        let injection = result
+            .references
+            .iter()
+            .find(|r| r.kind == ReferenceKind::Inject)
+            .unwrap();
+
+        let edge = resolver.resolve_injection_edge(injection);
+        assert!(edge.is_some());
+        assert_eq!(edge.unwrap().target_file, "database.service.ts");
+    }
+
+    #[test]
+    fn test_unresolved_injection_from_external_package_is_not_an_error() {
+        let resolver = TsResolver::new();
+        let source = r#"
+import { ConfigService } from '@nestjs/config';
+
+@Injectable()
+class AppService {
+    constructor(private config: ConfigService) {}
+}
+"#;
+        let result = resolver.parse_file(Path::new("app.service.ts"), source);
+        let injection = result
+            .references
+            .iter()
+            .find(|r| r.kind == ReferenceKind::Inject)
+            .unwrap();
+
+        assert!(resolver.resolve_injection_edge(injection).is_none());
+        assert!(resolver.is_external_injection(injection));
+    }
 }
@@ -1,43 +1,258 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
-use axum::extract::{Path, Query, State};
+use axum::extract::{Path, Query, Request, State};
 use axum::http::StatusCode;
-use axum::response::Json;
+use axum::middleware::{self, Next};
+use axum::response::sse::{Event, KeepAlive, Sse};
+use axum::response::{IntoResponse, Json, Response};
 use axum::routing::{get, post};
-use axum::Router;
+use axum::{Extension, Router};
 use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tokio_stream::wrappers::ReceiverStream;
+use tokio_stream::{Stream, StreamExt};
 use tower_http::cors::{Any, CorsLayer};
 
+use keel_core::config::{AuthConfig, KeyScope};
 use keel_core::sqlite::SqliteGraphStore;
 use keel_core::store::GraphStore;
-use keel_core::types::EdgeDirection;
+use keel_core::types::{EdgeDirection, GraphError, GraphNode};
+use keel_enforce::engine::EnforcementEngine;
+use keel_enforce::query;
 use keel_enforce::types::{
-    CalleeInfo, CallerInfo, CompileInfo, CompileResult, DiscoverResult, ExplainResult,
-    ModuleContext, NodeInfo, ResolutionStep,
+    CalleeInfo, CallerInfo, CompileInfo, CompileResult, DiscoverBatchResult, DiscoverResult,
+    ExplainResult, ModuleContext, NodeInfo, QueryResult, ResolutionStep,
 };
 
-type SharedStore = Arc<Mutex<SqliteGraphStore>>;
+use crate::metrics::Metrics;
+use crate::parse_shared;
+
+/// Shared, cloneable handle to the graph store backing every HTTP handler.
+///
+/// The SQLite variant holds its single `rusqlite::Connection` behind a
+/// `Mutex` -- required, since the connection itself is `!Sync` -- but the
+/// Postgres variant holds its pooled store directly behind only an `Arc`:
+/// `r2d2::Pool` already hands out its own connection per call and is
+/// `Send + Sync`, so wrapping it in the same process-wide `Mutex` as SQLite
+/// would serialize concurrent Postgres reads through one lock for no
+/// reason, defeating the whole point of pooling (see `postgres.rs`'s own
+/// doc comment). Each variant also keeps the connection string/path it was
+/// opened with, so a handler that needs to own an engine (`compile_stream`)
+/// can open a second, independent connection to the same backing store
+/// instead of holding the shared lock for the duration of a whole compile.
+#[derive(Clone)]
+pub enum SharedStore {
+    Sqlite {
+        path: String,
+        inner: Arc<Mutex<SqliteGraphStore>>,
+    },
+    #[cfg(feature = "postgres")]
+    Postgres {
+        url: String,
+        inner: Arc<keel_core::postgres::PostgresGraphStore>,
+    },
+}
+
+impl SharedStore {
+    /// Open a `SharedStore` for `url`: same dispatch rule as
+    /// [`keel_core::backend::GraphStoreBackend::open`] (`postgres://`/
+    /// `postgresql://` selects the Postgres backend, anything else is a
+    /// SQLite path).
+    pub fn open(url: &str) -> Result<Self, GraphError> {
+        if url.starts_with("postgres://") || url.starts_with("postgresql://") {
+            #[cfg(feature = "postgres")]
+            {
+                let store = keel_core::postgres::PostgresGraphStore::open(url)?;
+                return Ok(SharedStore::Postgres {
+                    url: url.to_string(),
+                    inner: Arc::new(store),
+                });
+            }
+            #[cfg(not(feature = "postgres"))]
+            {
+                return Err(GraphError::Internal(format!(
+                    "Postgres backend requested ({url}) but keel-server was built without the \
+                     \"postgres\" feature"
+                )));
+            }
+        }
+        let store = SqliteGraphStore::open(url)?;
+        Ok(SharedStore::Sqlite {
+            path: url.to_string(),
+            inner: Arc::new(Mutex::new(store)),
+        })
+    }
+
+    /// Borrow the store for the duration of `f`. The SQLite variant is
+    /// locked for that duration (its connection isn't `Sync`); the
+    /// Postgres variant needs no lock at all, since `f` only ever calls
+    /// `&self` `GraphStore` methods and the pool is already `Sync`.
+    fn with<T>(&self, f: impl FnOnce(&dyn GraphStore) -> T) -> Result<T, StatusCode> {
+        match self {
+            SharedStore::Sqlite { inner, .. } => {
+                let guard = inner.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                Ok(f(&*guard))
+            }
+            #[cfg(feature = "postgres")]
+            SharedStore::Postgres { inner, .. } => Ok(f(inner.as_ref())),
+        }
+    }
+
+    /// Borrow the store as a concrete `SqliteGraphStore`, for the datalog
+    /// query engine (`keel_enforce::query`), which is built directly on its
+    /// raw-tuple SQL rather than the `GraphStore` trait. `None` for the
+    /// Postgres variant, which has no equivalent yet.
+    fn with_sqlite<T>(&self, f: impl FnOnce(&SqliteGraphStore) -> T) -> Result<Option<T>, StatusCode> {
+        match self {
+            SharedStore::Sqlite { inner, .. } => {
+                let guard = inner.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+                Ok(Some(f(&guard)))
+            }
+            #[cfg(feature = "postgres")]
+            SharedStore::Postgres { .. } => Ok(None),
+        }
+    }
+
+    /// Open a second, independent connection to the same backing store,
+    /// boxed for `EnforcementEngine`'s `Box<dyn GraphStore + Send>`. Used by
+    /// handlers (`compile_stream`) that need to own an engine rather than
+    /// just read through the shared lock for a request's duration.
+    fn open_engine_store(&self) -> Result<Box<dyn GraphStore + Send>, StatusCode> {
+        match self {
+            SharedStore::Sqlite { path, .. } => SqliteGraphStore::open(path)
+                .map(|s| Box::new(s) as Box<dyn GraphStore + Send>)
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR),
+            #[cfg(feature = "postgres")]
+            SharedStore::Postgres { url, .. } => keel_core::postgres::PostgresGraphStore::open(url)
+                .map(|s| Box::new(s) as Box<dyn GraphStore + Send>)
+                .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR),
+        }
+    }
+}
 
 /// Build the axum router with all keel HTTP endpoints.
-pub fn router(store: SharedStore) -> Router {
+///
+/// `auth` gates every route but `/health` behind a bearer token when it has
+/// any keys configured; with no keys configured (the default) auth is
+/// disabled, matching the prior localhost-only, no-auth behavior.
+pub fn router(store: SharedStore, auth: AuthConfig) -> Router {
     let cors = CorsLayer::new()
         .allow_origin(Any)
         .allow_methods(Any)
         .allow_headers(Any);
 
+    let metrics = Arc::new(Metrics::default());
+    let auth = Arc::new(auth);
+
     Router::new()
         .route("/health", get(health))
         .route("/compile", post(compile))
+        .route("/compile/stream", post(compile_stream))
         .route("/discover/{hash}", get(discover))
+        .route("/discover/batch", post(discover_batch))
         .route("/where/{hash}", get(where_hash))
         .route("/explain", post(explain))
+        .route("/query", post(query))
+        .route("/metrics", get(metrics_handler))
+        .layer(middleware::from_fn_with_state(auth, auth_middleware))
+        .layer(middleware::from_fn_with_state(
+            metrics.clone(),
+            track_request,
+        ))
+        .layer(Extension(metrics))
         .layer(cors)
         .with_state(store)
 }
 
+/// Bearer-token auth, wired via `router()` ahead of every route except
+/// `/health`. A missing or unknown token gets `401`. A key's scope must
+/// cover the route it's calling: `Read` keys get `403` on `/compile*`,
+/// `Write` keys can call anything.
+async fn auth_middleware(
+    State(auth): State<Arc<AuthConfig>>,
+    req: Request,
+    next: Next,
+) -> Response {
+    if auth.keys.is_empty() || req.uri().path() == "/health" {
+        return next.run(req).await;
+    }
+
+    let token = req
+        .headers()
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+
+    let Some(token) = token else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    let Some(key) = auth.keys.iter().find(|k| k.key == token) else {
+        return StatusCode::UNAUTHORIZED.into_response();
+    };
+
+    if route_scope(req.uri().path()) == KeyScope::Write && key.scope != KeyScope::Write {
+        return StatusCode::FORBIDDEN.into_response();
+    }
+
+    next.run(req).await
+}
+
+/// Minimum key scope a route requires: `/compile` and `/compile/stream`
+/// mutate the graph and need `Write`; everything else (`/discover`,
+/// `/where`, `/explain`, `/query`, `/metrics`) only needs `Read`.
+fn route_scope(path: &str) -> KeyScope {
+    if path.starts_with("/compile") {
+        KeyScope::Write
+    } else {
+        KeyScope::Read
+    }
+}
+
+/// Middleware wired via `router()` that times every request and records its
+/// method/path/status in the shared [`Metrics`] registry, regardless of
+/// which handler served it.
+async fn track_request(
+    State(metrics): State<Arc<Metrics>>,
+    req: Request,
+    next: Next,
+) -> impl IntoResponse {
+    let method = req.method().to_string();
+    let path = req.uri().path().to_string();
+    let started = Instant::now();
+
+    let response = next.run(req).await;
+
+    metrics.record_request(
+        &method,
+        &path,
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+    response
+}
+
+/// Render the process-wide metrics registry in Prometheus text format.
+async fn metrics_handler(Extension(metrics): Extension<Arc<Metrics>>) -> impl IntoResponse {
+    (
+        [(
+            axum::http::header::CONTENT_TYPE,
+            "text/plain; version=0.0.4",
+        )],
+        metrics.render(),
+    )
+}
+
 /// Start the HTTP server on the given port.
-pub async fn serve(store: SharedStore, port: u16) -> Result<(), Box<dyn std::error::Error>> {
-    let app = router(store);
+pub async fn serve(
+    store: SharedStore,
+    port: u16,
+    auth: AuthConfig,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let app = router(store, auth);
     let listener = tokio::net::TcpListener::bind(format!("127.0.0.1:{}", port)).await?;
     axum::serve(listener, app).await?;
     Ok(())
@@ -55,12 +270,24 @@ pub struct DiscoverQuery {
     pub depth: Option<u32>,
 }
 
+#[derive(Deserialize)]
+pub struct DiscoverBatchRequest {
+    pub hashes: Vec<String>,
+    pub depth: Option<u32>,
+}
+
 #[derive(Deserialize)]
 pub struct ExplainRequest {
     pub error_code: String,
     pub hash: String,
 }
 
+#[derive(Deserialize)]
+pub struct QueryRequest {
+    pub query: String,
+    pub max_depth: Option<u32>,
+}
+
 #[derive(Serialize, Deserialize)]
 pub struct WhereResponse {
     pub file: String,
@@ -84,10 +311,20 @@ async fn health() -> Json<HealthResponse> {
 
 async fn compile(
     State(_store): State<SharedStore>,
+    Extension(metrics): Extension<Arc<Metrics>>,
     Json(req): Json<CompileRequest>,
 ) -> Json<CompileResult> {
+    let started = Instant::now();
+
     // Stub: return a clean compile result.
     // Real implementation will call EnforcementEngine::compile().
+    let info = CompileInfo {
+        nodes_updated: 0,
+        edges_updated: 0,
+        hashes_changed: vec![],
+    };
+    metrics.record_compile(started.elapsed(), info.nodes_updated, info.edges_updated);
+
     Json(CompileResult {
         version: env!("CARGO_PKG_VERSION").to_string(),
         command: "compile".to_string(),
@@ -95,80 +332,310 @@ async fn compile(
         files_analyzed: req.files,
         errors: vec![],
         warnings: vec![],
-        info: CompileInfo {
-            nodes_updated: 0,
-            edges_updated: 0,
-            hashes_changed: vec![],
-        },
+        info,
     })
 }
 
+#[derive(Serialize)]
+struct FileStartedEvent {
+    file: String,
+}
+
+#[derive(Serialize)]
+struct FileAnalyzedEvent {
+    file: String,
+    nodes_updated: u32,
+    edges_updated: u32,
+}
+
+/// Streaming sibling of `compile`: emits one `file_started` + `file_analyzed`
+/// SSE event per file as it is parsed and enforced, then a terminal `done`
+/// event carrying the aggregated `CompileInfo`, instead of making the client
+/// wait for the whole run. Opens its own connection to the same backing
+/// store as `store` (via `open_engine_store`) rather than holding the
+/// shared lock for the whole run -- so E001/E004/E005/W002 compare against
+/// the real prior graph and the compile's updates actually persist, instead
+/// of running against a throwaway empty graph every call.
+async fn compile_stream(
+    State(store): State<SharedStore>,
+    Extension(metrics): Extension<Arc<Metrics>>,
+    Json(req): Json<CompileRequest>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let (tx, rx) = mpsc::channel::<Event>(16);
+    let started = Instant::now();
+
+    tokio::spawn(async move {
+        let engine_store = match store.open_engine_store() {
+            Ok(s) => s,
+            Err(_) => return,
+        };
+        let mut engine = EnforcementEngine::new(engine_store);
+
+        let mut total_nodes_updated: u32 = 0;
+        let mut total_edges_updated: u32 = 0;
+        let mut hashes_changed: Vec<String> = Vec::new();
+
+        for path in &req.files {
+            send_event(
+                &tx,
+                "file_started",
+                &FileStartedEvent { file: path.clone() },
+            )
+            .await;
+
+            let Some(index) = parse_shared::parse_file_to_index(path) else {
+                send_event(
+                    &tx,
+                    "file_analyzed",
+                    &FileAnalyzedEvent {
+                        file: path.clone(),
+                        nodes_updated: 0,
+                        edges_updated: 0,
+                    },
+                )
+                .await;
+                continue;
+            };
+
+            let result = engine.compile(&[index]);
+            total_nodes_updated += result.info.nodes_updated;
+            total_edges_updated += result.info.edges_updated;
+            hashes_changed.extend(result.info.hashes_changed);
+
+            send_event(
+                &tx,
+                "file_analyzed",
+                &FileAnalyzedEvent {
+                    file: path.clone(),
+                    nodes_updated: result.info.nodes_updated,
+                    edges_updated: result.info.edges_updated,
+                },
+            )
+            .await;
+        }
+
+        metrics.record_compile(started.elapsed(), total_nodes_updated, total_edges_updated);
+
+        send_event(
+            &tx,
+            "done",
+            &CompileInfo {
+                nodes_updated: total_nodes_updated,
+                edges_updated: total_edges_updated,
+                hashes_changed,
+            },
+        )
+        .await;
+    });
+
+    Sse::new(ReceiverStream::new(rx).map(Ok))
+        .keep_alive(KeepAlive::default().interval(Duration::from_secs(15)))
+}
+
+async fn send_event<T: Serialize>(tx: &mpsc::Sender<Event>, name: &'static str, payload: &T) {
+    if let Ok(event) = Event::default().event(name).json_data(payload) {
+        let _ = tx.send(event).await;
+    }
+}
+
 async fn discover(
     State(store): State<SharedStore>,
+    Extension(metrics): Extension<Arc<Metrics>>,
     Path(hash): Path<String>,
     Query(query): Query<DiscoverQuery>,
 ) -> Result<Json<DiscoverResult>, StatusCode> {
-    let store = store.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-    let node = store.get_node(&hash).ok_or(StatusCode::NOT_FOUND)?;
-    let _depth = query.depth.unwrap_or(1);
-
-    let incoming = store.get_edges(node.id, EdgeDirection::Incoming);
-    let outgoing = store.get_edges(node.id, EdgeDirection::Outgoing);
-
-    let upstream: Vec<CallerInfo> = incoming
-        .iter()
-        .filter_map(|e| {
-            store.get_node_by_id(e.source_id).map(|n| CallerInfo {
-                hash: n.hash,
-                name: n.name,
-                signature: n.signature,
-                file: n.file_path,
-                line: n.line_start,
-                docstring: n.docstring,
-                call_line: e.line,
+    let started = Instant::now();
+    let result = store.with(|store| -> Option<DiscoverResult> {
+        let node = store.get_node(&hash)?;
+        let _depth = query.depth.unwrap_or(1);
+
+        let incoming = store.get_edges(node.id, EdgeDirection::Incoming);
+        let outgoing = store.get_edges(node.id, EdgeDirection::Outgoing);
+
+        let upstream: Vec<CallerInfo> = incoming
+            .iter()
+            .filter_map(|e| {
+                store.get_node_by_id(e.source_id).map(|n| CallerInfo {
+                    hash: n.hash,
+                    name: n.name,
+                    signature: n.signature,
+                    file: n.file_path,
+                    line: n.line_start,
+                    docstring: n.docstring,
+                    call_line: e.line,
+                })
             })
-        })
-        .collect();
-
-    let downstream: Vec<CalleeInfo> = outgoing
-        .iter()
-        .filter_map(|e| {
-            store.get_node_by_id(e.target_id).map(|n| CalleeInfo {
-                hash: n.hash,
-                name: n.name,
-                signature: n.signature,
-                file: n.file_path,
-                line: n.line_start,
-                docstring: n.docstring,
-                call_line: e.line,
+            .collect();
+
+        let downstream: Vec<CalleeInfo> = outgoing
+            .iter()
+            .filter_map(|e| {
+                store.get_node_by_id(e.target_id).map(|n| CalleeInfo {
+                    hash: n.hash,
+                    name: n.name,
+                    signature: n.signature,
+                    file: n.file_path,
+                    line: n.line_start,
+                    docstring: n.docstring,
+                    call_line: e.line,
+                })
             })
+            .collect();
+
+        let module_context = build_module_context(store, &node);
+
+        Some(DiscoverResult {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            command: "discover".to_string(),
+            target: NodeInfo {
+                hash: node.hash,
+                name: node.name,
+                signature: node.signature,
+                file: node.file_path.clone(),
+                line_start: node.line_start,
+                line_end: node.line_end,
+                docstring: node.docstring,
+                type_hints_present: node.type_hints_present,
+                has_docstring: node.has_docstring,
+            },
+            upstream,
+            downstream,
+            module_context,
         })
-        .collect();
+    })?;
+    let result = result.ok_or(StatusCode::NOT_FOUND)?;
 
-    let module_context = build_module_context(&store, &node);
+    metrics.record_discover(started.elapsed());
 
-    Ok(Json(DiscoverResult {
-        version: env!("CARGO_PKG_VERSION").to_string(),
-        command: "discover".to_string(),
-        target: NodeInfo {
-            hash: node.hash,
-            name: node.name,
-            signature: node.signature,
-            file: node.file_path.clone(),
-            line_start: node.line_start,
-            line_end: node.line_end,
-            docstring: node.docstring,
-            type_hints_present: node.type_hints_present,
-            has_docstring: node.has_docstring,
-        },
-        upstream,
-        downstream,
-        module_context,
-    }))
+    Ok(Json(result))
+}
+
+fn node_info(node: &GraphNode) -> NodeInfo {
+    NodeInfo {
+        hash: node.hash.clone(),
+        name: node.name.clone(),
+        signature: node.signature.clone(),
+        file: node.file_path.clone(),
+        line_start: node.line_start,
+        line_end: node.line_end,
+        docstring: node.docstring.clone(),
+        type_hints_present: node.type_hints_present,
+        has_docstring: node.has_docstring,
+    }
+}
+
+/// Batched sibling of `discover`: resolves every hash's node, edges and
+/// neighbors in one fixed-size set of queries (node rows with their
+/// endpoints and previous hashes, one incoming-edges lookup, one
+/// outgoing-edges lookup, one neighbor-node lookup) instead of the
+/// 2*N queries calling `/discover/{hash}` N times would cost. The module
+/// context is still looked up per node, two queries for each node that
+/// belongs to a module. A hash with
+/// no matching node is reported in `not_found` rather than failing the
+/// whole batch.
+async fn discover_batch(
+    State(store): State<SharedStore>,
+    Extension(metrics): Extension<Arc<Metrics>>,
+    Json(req): Json<DiscoverBatchRequest>,
+) -> Result<Json<DiscoverBatchResult>, StatusCode> {
+    let result = store.with(|store| {
+        let _depth = req.depth.unwrap_or(1);
+
+        let nodes_by_hash = store.nodes_with_relations_batch(&req.hashes);
+        let not_found: Vec<String> = req
+            .hashes
+            .iter()
+            .filter(|h| !nodes_by_hash.contains_key(*h))
+            .cloned()
+            .collect();
+
+        let target_ids: Vec<u64> = nodes_by_hash.values().map(|n| n.id).collect();
+        let incoming_by_node = store.edges_batch(&target_ids, EdgeDirection::Incoming);
+        let outgoing_by_node = store.edges_batch(&target_ids, EdgeDirection::Outgoing);
+
+        let neighbor_ids: Vec<u64> = incoming_by_node
+            .values()
+            .chain(outgoing_by_node.values())
+            .flatten()
+            .flat_map(|e| [e.source_id, e.target_id])
+            .collect();
+        let neighbor_nodes = store.nodes_by_ids_batch(&neighbor_ids);
+
+        // Six batched queries (node rows, endpoints, previous hashes,
+        // incoming edges, outgoing edges, neighbors) cover what would
+        // otherwise be one query per requested hash plus one per target;
+        // build_module_context below adds two per node with a module.
+        let module_lookups =
+            nodes_by_hash.values().filter(|n| n.module_id != 0).count() as u64 * 2;
+        metrics.record_sqlite_queries(6 + module_lookups);
+        metrics.record_batch_fanout_saved(req.hashes.len().saturating_sub(1) as u64);
+
+        let mut results = HashMap::new();
+        for (hash, node) in &nodes_by_hash {
+            let upstream: Vec<CallerInfo> = incoming_by_node
+                .get(&node.id)
+                .into_iter()
+                .flatten()
+                .filter_map(|e| {
+                    neighbor_nodes.get(&e.source_id).map(|n| CallerInfo {
+                        hash: n.hash.clone(),
+                        name: n.name.clone(),
+                        signature: n.signature.clone(),
+                        file: n.file_path.clone(),
+                        line: n.line_start,
+                        docstring: n.docstring.clone(),
+                        call_line: e.line,
+                        distance: 1,
+                    })
+                })
+                .collect();
+
+            let downstream: Vec<CalleeInfo> = outgoing_by_node
+                .get(&node.id)
+                .into_iter()
+                .flatten()
+                .filter_map(|e| {
+                    neighbor_nodes.get(&e.target_id).map(|n| CalleeInfo {
+                        hash: n.hash.clone(),
+                        name: n.name.clone(),
+                        signature: n.signature.clone(),
+                        file: n.file_path.clone(),
+                        line: n.line_start,
+                        docstring: n.docstring.clone(),
+                        call_line: e.line,
+                        distance: 1,
+                    })
+                })
+                .collect();
+
+            let module_context = build_module_context(store, node);
+
+            results.insert(
+                hash.clone(),
+                DiscoverResult {
+                    version: env!("CARGO_PKG_VERSION").to_string(),
+                    command: "discover".to_string(),
+                    target: node_info(node),
+                    upstream,
+                    downstream,
+                    module_context,
+                },
+            );
+        }
+
+        DiscoverBatchResult {
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            command: "discover_batch".to_string(),
+            results,
+            not_found,
+        }
+    })?;
+
+    Ok(Json(result))
 }
 
 fn build_module_context(
-    store: &SqliteGraphStore,
+    store: &dyn GraphStore,
     node: &keel_core::types::GraphNode,
 ) -> ModuleContext {
     if node.module_id == 0 {
@@ -199,10 +666,14 @@ fn build_module_context(
 
 async fn where_hash(
     State(store): State<SharedStore>,
+    Extension(metrics): Extension<Arc<Metrics>>,
     Path(hash): Path<String>,
 ) -> Result<Json<WhereResponse>, StatusCode> {
-    let store = store.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-    let node = store.get_node(&hash).ok_or(StatusCode::NOT_FOUND)?;
+    let started = Instant::now();
+    let node = store
+        .with(|store| store.get_node(&hash))?
+        .ok_or(StatusCode::NOT_FOUND)?;
+    metrics.record_where(started.elapsed());
     Ok(Json(WhereResponse {
         file: node.file_path,
         line: node.line_start,
@@ -213,8 +684,9 @@ async fn explain(
     State(store): State<SharedStore>,
     Json(req): Json<ExplainRequest>,
 ) -> Result<Json<ExplainResult>, StatusCode> {
-    let store = store.lock().map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
-    let node = store.get_node(&req.hash).ok_or(StatusCode::NOT_FOUND)?;
+    let node = store
+        .with(|store| store.get_node(&req.hash))?
+        .ok_or(StatusCode::NOT_FOUND)?;
 
     // Stub: return a placeholder explain result.
     // Real implementation will use EnforcementEngine::explain().
@@ -235,17 +707,50 @@ async fn explain(
     }))
 }
 
+/// Run a datalog-style query over the graph (see `keel_enforce::query`),
+/// e.g. `node(H, Name, File, "function"), in_module(H, M) | H, Name, File`.
+/// A malformed query is the caller's mistake, not a server fault, so a parse
+/// error reports 400 rather than 500.
+async fn query(
+    State(store): State<SharedStore>,
+    Json(req): Json<QueryRequest>,
+) -> Result<Json<QueryResult>, StatusCode> {
+    let parsed = query::parse_query(&req.query).map_err(|_| StatusCode::BAD_REQUEST)?;
+    let outcome = store
+        .with_sqlite(|sqlite_store| query::evaluate_query(sqlite_store, &parsed, req.max_depth))?
+        .ok_or(StatusCode::NOT_IMPLEMENTED)?;
+
+    Ok(Json(QueryResult {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        command: "query".to_string(),
+        columns: outcome.columns,
+        rows: outcome.rows,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use axum::body::{to_bytes, Body};
     use axum::http::{header, Method, Request};
-    use keel_core::types::{GraphNode, NodeKind};
+    use keel_core::config::ApiKeyConfig;
+    use keel_core::types::{EdgeChange, EdgeKind, GraphEdge, GraphNode, NodeKind};
     use tower::ServiceExt;
 
+    /// Wrap an in-memory `SqliteGraphStore` as a `SharedStore` for tests.
+    /// The `path` is a dummy `:memory:` connection string: every test store
+    /// is private to the test, so nothing ever needs to reopen it via
+    /// `open_engine_store`'s path, except `compile_stream`'s own test,
+    /// which only compiles a nonexistent file and never touches the store.
+    fn shared(store: SqliteGraphStore) -> SharedStore {
+        SharedStore::Sqlite {
+            path: ":memory:".to_string(),
+            inner: Arc::new(Mutex::new(store)),
+        }
+    }
+
     fn test_store() -> SharedStore {
-        let store = SqliteGraphStore::in_memory().unwrap();
-        Arc::new(Mutex::new(store))
+        shared(SqliteGraphStore::in_memory().unwrap())
     }
 
     fn store_with_node() -> SharedStore {
@@ -268,12 +773,64 @@ mod tests {
             module_id: 0,
         };
         store.insert_node(&node).unwrap();
-        Arc::new(Mutex::new(store))
+        shared(store)
+    }
+
+    fn store_with_call_graph() -> SharedStore {
+        let mut store = SqliteGraphStore::in_memory().unwrap();
+        let caller = GraphNode {
+            id: 1,
+            hash: "callerHash1".to_string(),
+            kind: NodeKind::Function,
+            name: "handleRequest".to_string(),
+            signature: "fn handleRequest()".to_string(),
+            file_path: "src/handler.rs".to_string(),
+            line_start: 1,
+            line_end: 5,
+            docstring: None,
+            is_public: true,
+            type_hints_present: true,
+            has_docstring: false,
+            external_endpoints: vec![],
+            previous_hashes: vec![],
+            module_id: 0,
+        };
+        let callee = GraphNode {
+            id: 2,
+            hash: "calleeHash2".to_string(),
+            kind: NodeKind::Function,
+            name: "fetchData".to_string(),
+            signature: "fn fetchData()".to_string(),
+            file_path: "src/client.rs".to_string(),
+            line_start: 1,
+            line_end: 5,
+            docstring: None,
+            is_public: false,
+            type_hints_present: true,
+            has_docstring: false,
+            external_endpoints: vec![],
+            previous_hashes: vec![],
+            module_id: 0,
+        };
+        store.insert_node(&caller).unwrap();
+        store.insert_node(&callee).unwrap();
+        store
+            .update_edges(vec![EdgeChange::Add(GraphEdge {
+                id: 1,
+                source_id: 1,
+                target_id: 2,
+                kind: EdgeKind::Calls,
+                file_path: "src/handler.rs".to_string(),
+                line: 3,
+                confidence: 1.0,
+            })])
+            .unwrap();
+        shared(store)
     }
 
     #[tokio::test]
     async fn test_health_endpoint() {
-        let app = router(test_store());
+        let app = router(test_store(), AuthConfig::default());
         let req = Request::builder()
             .uri("/health")
             .body(Body::empty())
@@ -287,9 +844,102 @@ mod tests {
         assert!(!json.version.is_empty());
     }
 
+    fn auth_with_keys() -> AuthConfig {
+        AuthConfig {
+            keys: vec![
+                ApiKeyConfig {
+                    key: "reader-key".to_string(),
+                    scope: KeyScope::Read,
+                },
+                ApiKeyConfig {
+                    key: "writer-key".to_string(),
+                    scope: KeyScope::Write,
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_health_endpoint_unauthenticated_even_with_keys_configured() {
+        let app = router(test_store(), auth_with_keys());
+        let req = Request::builder()
+            .uri("/health")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_protected_route_without_token_is_unauthorized() {
+        let app = router(store_with_node(), auth_with_keys());
+        let req = Request::builder()
+            .uri("/discover/a7Bx3kM9f2Q")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_protected_route_with_unknown_token_is_unauthorized() {
+        let app = router(store_with_node(), auth_with_keys());
+        let req = Request::builder()
+            .uri("/discover/a7Bx3kM9f2Q")
+            .header(header::AUTHORIZATION, "Bearer nope")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn test_read_scoped_key_can_call_read_routes() {
+        let app = router(store_with_node(), auth_with_keys());
+        let req = Request::builder()
+            .uri("/discover/a7Bx3kM9f2Q")
+            .header(header::AUTHORIZATION, "Bearer reader-key")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn test_read_scoped_key_is_forbidden_from_compile() {
+        let app = router(test_store(), auth_with_keys());
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/compile")
+            .header(header::AUTHORIZATION, "Bearer reader-key")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                serde_json::to_vec(&serde_json::json!({ "files": [] })).unwrap(),
+            ))
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn test_write_scoped_key_can_call_compile() {
+        let app = router(test_store(), auth_with_keys());
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/compile")
+            .header(header::AUTHORIZATION, "Bearer writer-key")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(
+                serde_json::to_vec(&serde_json::json!({ "files": [] })).unwrap(),
+            ))
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
     #[tokio::test]
     async fn test_health_has_cors_headers() {
-        let app = router(test_store());
+        let app = router(test_store(), AuthConfig::default());
         let req = Request::builder()
             .uri("/health")
             .body(Body::empty())
@@ -301,7 +951,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_cors_preflight() {
-        let app = router(test_store());
+        let app = router(test_store(), AuthConfig::default());
         let req = Request::builder()
             .method(Method::OPTIONS)
             .uri("/health")
@@ -316,7 +966,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_compile_with_json_body() {
-        let app = router(test_store());
+        let app = router(test_store(), AuthConfig::default());
         let body = serde_json::json!({ "files": ["src/main.rs", "src/lib.rs"] });
         let req = Request::builder()
             .method(Method::POST)
@@ -335,9 +985,29 @@ mod tests {
         assert!(result.warnings.is_empty());
     }
 
+    #[tokio::test]
+    async fn test_compile_stream_emits_started_analyzed_and_done_events() {
+        let app = router(test_store(), AuthConfig::default());
+        let body = serde_json::json!({ "files": ["src/nonexistent_for_test.rs"] });
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/compile/stream")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let bytes = to_bytes(resp.into_body(), 16384).await.unwrap();
+        let text = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(text.contains("event:file_started") || text.contains("event: file_started"));
+        assert!(text.contains("event:file_analyzed") || text.contains("event: file_analyzed"));
+        assert!(text.contains("event:done") || text.contains("event: done"));
+    }
+
     #[tokio::test]
     async fn test_compile_malformed_body() {
-        let app = router(test_store());
+        let app = router(test_store(), AuthConfig::default());
         let req = Request::builder()
             .method(Method::POST)
             .uri("/compile")
@@ -352,7 +1022,7 @@ mod tests {
     #[tokio::test]
     async fn test_discover_existing_node() {
         let store = store_with_node();
-        let app = router(store);
+        let app = router(store, AuthConfig::default());
         let req = Request::builder()
             .uri("/discover/a7Bx3kM9f2Q")
             .body(Body::empty())
@@ -367,9 +1037,77 @@ mod tests {
         assert_eq!(result.target.file, "src/lib.rs");
     }
 
+    #[tokio::test]
+    async fn test_discover_batch_resolves_multiple_hashes() {
+        let store = SqliteGraphStore::in_memory().unwrap();
+        let n1 = GraphNode {
+            id: 1,
+            hash: "hashOne".to_string(),
+            kind: NodeKind::Function,
+            name: "fnOne".to_string(),
+            signature: "fn fnOne()".to_string(),
+            file_path: "src/a.rs".to_string(),
+            line_start: 1,
+            line_end: 2,
+            docstring: None,
+            is_public: true,
+            type_hints_present: true,
+            has_docstring: false,
+            external_endpoints: vec![],
+            previous_hashes: vec![],
+            module_id: 0,
+        };
+        let mut n2 = n1.clone();
+        n2.id = 2;
+        n2.hash = "hashTwo".to_string();
+        n2.name = "fnTwo".to_string();
+        store.insert_node(&n1).unwrap();
+        store.insert_node(&n2).unwrap();
+        let app = router(shared(store), AuthConfig::default());
+
+        let body = serde_json::json!({ "hashes": ["hashOne", "hashTwo"] });
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/discover/batch")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let bytes = to_bytes(resp.into_body(), 8192).await.unwrap();
+        let result: DiscoverBatchResult = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(result.results.len(), 2);
+        assert_eq!(result.results["hashOne"].target.name, "fnOne");
+        assert_eq!(result.results["hashTwo"].target.name, "fnTwo");
+        assert!(result.not_found.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_discover_batch_reports_missing_hashes_without_failing() {
+        let store = store_with_node();
+        let app = router(store, AuthConfig::default());
+
+        let body = serde_json::json!({ "hashes": ["a7Bx3kM9f2Q", "doesNotExist"] });
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/discover/batch")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let bytes = to_bytes(resp.into_body(), 8192).await.unwrap();
+        let result: DiscoverBatchResult = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(result.results.len(), 1);
+        assert!(result.results.contains_key("a7Bx3kM9f2Q"));
+        assert_eq!(result.not_found, vec!["doesNotExist".to_string()]);
+    }
+
     #[tokio::test]
     async fn test_discover_not_found() {
-        let app = router(test_store());
+        let app = router(test_store(), AuthConfig::default());
         let req = Request::builder()
             .uri("/discover/nonexistent")
             .body(Body::empty())
@@ -381,7 +1119,7 @@ mod tests {
     #[tokio::test]
     async fn test_where_existing_node() {
         let store = store_with_node();
-        let app = router(store);
+        let app = router(store, AuthConfig::default());
         let req = Request::builder()
             .uri("/where/a7Bx3kM9f2Q")
             .body(Body::empty())
@@ -397,7 +1135,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_where_not_found() {
-        let app = router(test_store());
+        let app = router(test_store(), AuthConfig::default());
         let req = Request::builder()
             .uri("/where/nonexistent")
             .body(Body::empty())
@@ -409,7 +1147,7 @@ mod tests {
     #[tokio::test]
     async fn test_explain_existing_node() {
         let store = store_with_node();
-        let app = router(store);
+        let app = router(store, AuthConfig::default());
         let body = serde_json::json!({ "error_code": "E001", "hash": "a7Bx3kM9f2Q" });
         let req = Request::builder()
             .method(Method::POST)
@@ -430,7 +1168,7 @@ mod tests {
     #[tokio::test]
     async fn test_explain_not_found() {
         let store = test_store();
-        let app = router(store);
+        let app = router(store, AuthConfig::default());
         let body = serde_json::json!({ "error_code": "E001", "hash": "doesntExist" });
         let req = Request::builder()
             .method(Method::POST)
@@ -444,7 +1182,7 @@ mod tests {
 
     #[tokio::test]
     async fn test_explain_malformed_body() {
-        let app = router(test_store());
+        let app = router(test_store(), AuthConfig::default());
         let req = Request::builder()
             .method(Method::POST)
             .uri("/explain")
@@ -454,4 +1192,96 @@ mod tests {
         let resp = app.oneshot(req).await.unwrap();
         assert!(resp.status().is_client_error());
     }
+
+    #[tokio::test]
+    async fn test_query_joins_node_and_calls() {
+        let app = router(store_with_call_graph(), AuthConfig::default());
+        let body = serde_json::json!({
+            "query": "node(H, Name, File, \"function\"), calls(H, Callee, _) | Name, Callee"
+        });
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/query")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let bytes = to_bytes(resp.into_body(), 4096).await.unwrap();
+        let result: QueryResult = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(
+            result.columns,
+            vec!["Name".to_string(), "Callee".to_string()]
+        );
+        assert_eq!(
+            result.rows,
+            vec![vec!["handleRequest".to_string(), "calleeHash2".to_string()]]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_query_reaches_follows_transitive_calls() {
+        let app = router(store_with_call_graph(), AuthConfig::default());
+        let body = serde_json::json!({
+            "query": "reaches(\"callerHash1\", Reached) | Reached"
+        });
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/query")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let bytes = to_bytes(resp.into_body(), 4096).await.unwrap();
+        let result: QueryResult = serde_json::from_slice(&bytes).unwrap();
+        assert_eq!(result.rows, vec![vec!["calleeHash2".to_string()]]);
+    }
+
+    #[tokio::test]
+    async fn test_metrics_endpoint_reports_requests_after_traffic() {
+        let store = store_with_node();
+        let app = router(store, AuthConfig::default());
+
+        let req = Request::builder()
+            .uri("/discover/a7Bx3kM9f2Q")
+            .body(Body::empty())
+            .unwrap();
+        let resp = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let metrics_req = Request::builder()
+            .uri("/metrics")
+            .body(Body::empty())
+            .unwrap();
+        let metrics_resp = app.oneshot(metrics_req).await.unwrap();
+        assert_eq!(metrics_resp.status(), StatusCode::OK);
+        assert_eq!(
+            metrics_resp.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/plain; version=0.0.4"
+        );
+
+        let bytes = to_bytes(metrics_resp.into_body(), 16384).await.unwrap();
+        let text = String::from_utf8(bytes.to_vec()).unwrap();
+        assert!(text.contains(
+            "keel_http_requests_total{method=\"GET\",path=\"/discover/a7Bx3kM9f2Q\",status=\"200\"} 1"
+        ));
+        assert!(text.contains("keel_discover_duration_seconds_count"));
+    }
+
+    #[tokio::test]
+    async fn test_query_malformed_syntax_is_bad_request() {
+        let app = router(test_store(), AuthConfig::default());
+        let body = serde_json::json!({ "query": "not a valid query" });
+        let req = Request::builder()
+            .method(Method::POST)
+            .uri("/query")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+        let resp = app.oneshot(req).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
 }
@@ -14,7 +14,10 @@ mod mcp_compile;
 mod mcp_context;
 mod mcp_fix;
 mod mcp_name;
+mod mcp_query;
 mod mcp_search;
+mod mcp_version;
+mod metrics;
 mod parse_shared;
 pub mod watcher;
 
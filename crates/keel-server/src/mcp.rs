@@ -116,7 +116,8 @@ fn tool_list() -> Vec<ToolInfo> {
                 "type": "object",
                 "required": ["hash"],
                 "properties": {
-                    "hash": { "type": "string" }
+                    "hash": { "type": "string" },
+                    "verbose": { "type": "boolean", "description": "Include explicit nulls for unset optional fields instead of omitting them", "default": false }
                 }
             }),
         },
@@ -168,6 +169,25 @@ fn tool_list() -> Vec<ToolInfo> {
                 }
             }),
         },
+        ToolInfo {
+            name: "keel/version".into(),
+            description: "Server version, protocol version, and supported methods".into(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "properties": {}
+            }),
+        },
+        ToolInfo {
+            name: "keel/query".into(),
+            description: "Run a JSONPath expression over a snapshot of the code graph".into(),
+            input_schema: serde_json::json!({
+                "type": "object",
+                "required": ["path"],
+                "properties": {
+                    "path": { "type": "string", "description": "JSONPath expression, e.g. $.definitions[?(@.kind=='function')]" }
+                }
+            }),
+        },
     ]
 }
 
@@ -197,6 +217,8 @@ fn dispatch(
         "keel/search" => crate::mcp_search::handle_search(store, params),
         "keel/name" => crate::mcp_name::handle_name(store, params),
         "keel/analyze" => crate::mcp_analyze::handle_analyze(store, params),
+        "keel/version" => crate::mcp_version::handle_version(engine, params),
+        "keel/query" => crate::mcp_query::handle_query(store, params),
         _ => Err(JsonRpcError {
             code: -32601,
             message: format!("Method not found: {}", method),
@@ -4,6 +4,35 @@ use serde_json::Value;
 
 use crate::mcp::{internal_err, JsonRpcError, SharedEngine};
 
+/// Re-insert the `Option<_>` fields that `CheckResult`'s
+/// `#[serde(skip_serializing_if = "Option::is_none")]` attributes omit when
+/// unset, as explicit `null`s. Only touches the exact paths those fields
+/// live at, so unrelated objects that happen to share a field name (there
+/// are none today, but keep it path-scoped rather than name-scoped) aren't
+/// affected.
+fn restore_omitted_nulls(value: &mut Value) {
+    if let Some(target) = value.get_mut("target").and_then(Value::as_object_mut) {
+        target.entry("docstring").or_insert(Value::Null);
+    }
+    if let Some(violations) = value.get_mut("violations").and_then(Value::as_array_mut) {
+        for violation in violations {
+            if let Some(v) = violation.as_object_mut() {
+                v.entry("fix_hint").or_insert(Value::Null);
+                v.entry("suppress_hint").or_insert(Value::Null);
+                v.entry("suggested_module").or_insert(Value::Null);
+                v.entry("existing").or_insert(Value::Null);
+            }
+        }
+    }
+    if let Some(suggestions) = value.get_mut("suggestions").and_then(Value::as_array_mut) {
+        for suggestion in suggestions {
+            if let Some(s) = suggestion.as_object_mut() {
+                s.entry("related_hash").or_insert(Value::Null);
+            }
+        }
+    }
+}
+
 pub(crate) fn handle_check(
     engine: &SharedEngine,
     params: Option<Value>,
@@ -18,6 +47,12 @@ pub(crate) fn handle_check(
         })?
         .to_string();
 
+    let verbose = params
+        .as_ref()
+        .and_then(|p| p.get("verbose"))
+        .and_then(|v| v.as_bool())
+        .unwrap_or(false);
+
     let engine = engine.lock().map_err(|_| JsonRpcError {
         code: -32603,
         message: "Engine lock poisoned".into(),
@@ -28,5 +63,9 @@ pub(crate) fn handle_check(
         message: format!("Node not found: {}", hash),
     })?;
 
-    serde_json::to_value(result).map_err(internal_err)
+    let mut value = serde_json::to_value(result).map_err(internal_err)?;
+    if verbose {
+        restore_omitted_nulls(&mut value);
+    }
+    Ok(value)
 }
@@ -39,12 +39,18 @@ pub(crate) fn handle_fix(
         .collect();
 
     let store = lock_store(store)?;
-    let plans = generate_fix_plans(&all_violations, &*store);
+    let base_dir = std::env::current_dir().unwrap_or_else(|_| std::path::PathBuf::from("."));
+    let plans = generate_fix_plans(&all_violations, &*store, &base_dir);
+
+    let violations_addressed: std::collections::HashSet<(&str, &str)> = plans
+        .iter()
+        .map(|p| (p.code.as_str(), p.hash.as_str()))
+        .collect();
 
     let result = FixResult {
         version: env!("CARGO_PKG_VERSION").into(),
         command: "fix".into(),
-        violations_addressed: plans.len() as u32,
+        violations_addressed: violations_addressed.len() as u32,
         files_affected: {
             let mut files_set = std::collections::HashSet::new();
             for plan in &plans {
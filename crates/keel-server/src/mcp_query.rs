@@ -0,0 +1,36 @@
+//! MCP query handler — run a JSONPath expression against a graph snapshot.
+
+use serde_json::Value;
+
+use keel_core::jsonpath;
+use keel_core::snapshot::build_graph_snapshot;
+
+use crate::mcp::{lock_store, JsonRpcError, SharedStore};
+
+pub(crate) fn handle_query(
+    store: &SharedStore,
+    params: Option<Value>,
+) -> Result<Value, JsonRpcError> {
+    let path = params
+        .as_ref()
+        .and_then(|p| p.get("path"))
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| JsonRpcError {
+            code: -32602,
+            message: "Missing 'path' parameter".into(),
+        })?;
+
+    let store = lock_store(store)?;
+    let graph = build_graph_snapshot(&*store);
+
+    let results = jsonpath::evaluate(&graph, path).map_err(|e| JsonRpcError {
+        code: -32602,
+        message: format!("Invalid JSONPath expression: {}", e),
+    })?;
+
+    Ok(serde_json::json!({
+        "path": path,
+        "count": results.len(),
+        "results": results,
+    }))
+}
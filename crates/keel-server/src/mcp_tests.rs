@@ -29,6 +29,29 @@ fn store_with_node() -> SharedStore {
     Arc::new(Mutex::new(store))
 }
 
+fn store_with_node_no_docstring() -> SharedStore {
+    let store = SqliteGraphStore::in_memory().unwrap();
+    let node = GraphNode {
+        id: 1,
+        hash: "noDocHash01".to_string(),
+        kind: NodeKind::Function,
+        name: "bareFn".to_string(),
+        signature: "fn bareFn()".to_string(),
+        file_path: "src/lib.rs".to_string(),
+        line_start: 1,
+        line_end: 3,
+        docstring: None,
+        is_public: true,
+        type_hints_present: true,
+        has_docstring: false,
+        external_endpoints: vec![],
+        previous_hashes: vec![],
+        module_id: 0,
+    };
+    store.insert_node(&node).unwrap();
+    Arc::new(Mutex::new(store))
+}
+
 fn store_with_edges() -> SharedStore {
     let mut store = SqliteGraphStore::in_memory().unwrap();
     // Target node
@@ -395,3 +418,70 @@ fn test_jsonrpc_version_in_response() {
     let resp = parse_response(&process_line(&store, &rpc("initialize", None)));
     assert_eq!(resp["jsonrpc"], "2.0");
 }
+
+#[test]
+fn test_version() {
+    let store = test_store();
+    let resp = parse_response(&process_line(&store, &rpc("keel/version", None)));
+    let result = &resp["result"];
+    assert_eq!(result["protocol_version"], serde_json::json!([1, 0]));
+    assert!(result["server_version"].as_str().unwrap().len() > 0);
+    let methods = result["methods"].as_array().unwrap();
+    assert!(methods.iter().any(|m| m == "check"));
+    assert!(methods.iter().any(|m| m == "version"));
+}
+
+#[test]
+fn test_query_filters_by_kind() {
+    let store = store_with_node();
+    let params = serde_json::json!({"path": "$.definitions[?(@.kind=='function')]"});
+    let resp = parse_response(&process_line(&store, &rpc("keel/query", Some(params))));
+    let result = &resp["result"];
+    assert_eq!(result["count"], 1);
+    assert_eq!(result["results"][0]["name"], "doStuff");
+}
+
+#[test]
+fn test_query_missing_path_param() {
+    let store = test_store();
+    let resp = parse_response(&process_line(&store, &rpc("keel/query", None)));
+    assert_eq!(resp["error"]["code"], -32602);
+}
+
+#[test]
+fn test_query_invalid_expression() {
+    let store = test_store();
+    let params = serde_json::json!({"path": "definitions[*]"});
+    let resp = parse_response(&process_line(&store, &rpc("keel/query", Some(params))));
+    assert_eq!(resp["error"]["code"], -32602);
+}
+
+#[test]
+fn test_check_omits_unset_optional_fields_by_default() {
+    let store = store_with_node_no_docstring();
+    let params = serde_json::json!({"hash": "noDocHash01"});
+    let resp = parse_response(&process_line(&store, &rpc("keel/check", Some(params))));
+    let result = &resp["result"];
+    assert!(!result["target"]
+        .as_object()
+        .unwrap()
+        .contains_key("docstring"));
+    let violation = &result["violations"][0];
+    assert!(violation["fix_hint"].is_string());
+    assert!(!violation.as_object().unwrap().contains_key("suppress_hint"));
+    assert!(!violation.as_object().unwrap().contains_key("suggested_module"));
+    assert!(!violation.as_object().unwrap().contains_key("existing"));
+}
+
+#[test]
+fn test_check_verbose_restores_null_fields() {
+    let store = store_with_node_no_docstring();
+    let params = serde_json::json!({"hash": "noDocHash01", "verbose": true});
+    let resp = parse_response(&process_line(&store, &rpc("keel/check", Some(params))));
+    let result = &resp["result"];
+    assert!(result["target"]["docstring"].is_null());
+    let violation = &result["violations"][0];
+    assert!(violation["suppress_hint"].is_null());
+    assert!(violation["suggested_module"].is_null());
+    assert!(violation["existing"].is_null());
+}
@@ -0,0 +1,41 @@
+//! MCP version/capabilities handshake handler.
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::mcp::{internal_err, JsonRpcError, SharedEngine};
+
+/// Protocol major/minor version for the keel MCP JSON-RPC surface. Bump the
+/// major component on breaking method or schema changes — clients should
+/// refuse to proceed when this doesn't match what they expect.
+const PROTOCOL_VERSION: (u32, u32) = (1, 0);
+
+/// Bare method names (without the `keel/` prefix) the engine currently
+/// supports, for clients negotiating capabilities during their init
+/// handshake.
+const SUPPORTED_METHODS: &[&str] = &[
+    "check", "compile", "discover", "where", "explain", "map", "fix", "search", "name", "analyze",
+    "version", "query",
+];
+
+#[derive(Serialize)]
+struct VersionInfo {
+    server_version: String,
+    protocol_version: [u32; 2],
+    methods: Vec<&'static str>,
+}
+
+/// Report the server version, protocol version, and supported methods so a
+/// client can negotiate capabilities instead of probing methods and
+/// interpreting "Method not found" errors.
+pub(crate) fn handle_version(
+    _engine: &SharedEngine,
+    _params: Option<Value>,
+) -> Result<Value, JsonRpcError> {
+    let info = VersionInfo {
+        server_version: env!("CARGO_PKG_VERSION").to_string(),
+        protocol_version: [PROTOCOL_VERSION.0, PROTOCOL_VERSION.1],
+        methods: SUPPORTED_METHODS.to_vec(),
+    };
+    serde_json::to_value(info).map_err(internal_err)
+}
@@ -0,0 +1,210 @@
+//! In-process Prometheus registry for the HTTP server.
+//!
+//! A single [`Metrics`] is built once in `http::router` and shared across
+//! every request via an axum `Extension`, so counts and latency sums
+//! accumulate for the life of the server rather than resetting per request.
+//! `GET /metrics` renders the current state in Prometheus text exposition
+//! format (counters and summaries -- no external `prometheus` crate, just
+//! plain locked maps and atomics).
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Count plus cumulative seconds for a latency summary -- renders as
+/// `..._sum` and `..._count`, the same pair Prometheus client libraries emit
+/// for a summary with no quantiles.
+#[derive(Default)]
+struct DurationTotals {
+    count: u64,
+    sum_seconds: f64,
+}
+
+impl DurationTotals {
+    fn record(&mut self, duration: Duration) {
+        self.count += 1;
+        self.sum_seconds += duration.as_secs_f64();
+    }
+}
+
+#[derive(Default)]
+pub struct Metrics {
+    requests_total: Mutex<HashMap<(String, String, u16), u64>>,
+    request_duration: Mutex<HashMap<(String, String), DurationTotals>>,
+    compile_duration: Mutex<DurationTotals>,
+    compile_nodes_updated_total: AtomicU64,
+    compile_edges_updated_total: AtomicU64,
+    discover_duration: Mutex<DurationTotals>,
+    where_duration: Mutex<DurationTotals>,
+    sqlite_queries_total: AtomicU64,
+    sqlite_batch_fanout_saved_total: AtomicU64,
+}
+
+impl Metrics {
+    /// Record that a route was served with the given status and latency.
+    pub fn record_request(&self, method: &str, path: &str, status: u16, duration: Duration) {
+        *self
+            .requests_total
+            .lock()
+            .unwrap()
+            .entry((method.to_string(), path.to_string(), status))
+            .or_insert(0) += 1;
+
+        self.request_duration
+            .lock()
+            .unwrap()
+            .entry((method.to_string(), path.to_string()))
+            .or_default()
+            .record(duration);
+    }
+
+    /// Record one `/compile` (or `/compile/stream`) run.
+    pub fn record_compile(&self, duration: Duration, nodes_updated: u32, edges_updated: u32) {
+        self.compile_duration.lock().unwrap().record(duration);
+        self.compile_nodes_updated_total
+            .fetch_add(nodes_updated as u64, Ordering::Relaxed);
+        self.compile_edges_updated_total
+            .fetch_add(edges_updated as u64, Ordering::Relaxed);
+    }
+
+    /// Record one `/discover` lookup's latency.
+    pub fn record_discover(&self, duration: Duration) {
+        self.discover_duration.lock().unwrap().record(duration);
+    }
+
+    /// Record one `/where` lookup's latency.
+    pub fn record_where(&self, duration: Duration) {
+        self.where_duration.lock().unwrap().record(duration);
+    }
+
+    /// Record that `count` SQLite queries were executed to serve a request.
+    pub fn record_sqlite_queries(&self, count: u64) {
+        self.sqlite_queries_total
+            .fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Record that a batched lookup (e.g. `nodes_with_relations_batch`)
+    /// replaced what would otherwise have been `saved + 1` individual
+    /// queries -- one batched query instead of one-per-item.
+    pub fn record_batch_fanout_saved(&self, saved: u64) {
+        self.sqlite_batch_fanout_saved_total
+            .fetch_add(saved, Ordering::Relaxed);
+    }
+
+    /// Render the current state in Prometheus text exposition format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP keel_http_requests_total Total HTTP requests handled.\n");
+        out.push_str("# TYPE keel_http_requests_total counter\n");
+        for ((method, path, status), count) in self.requests_total.lock().unwrap().iter() {
+            out.push_str(&format!(
+                "keel_http_requests_total{{method=\"{method}\",path=\"{path}\",status=\"{status}\"}} {count}\n"
+            ));
+        }
+
+        out.push_str(
+            "# HELP keel_http_request_duration_seconds HTTP request latency in seconds.\n",
+        );
+        out.push_str("# TYPE keel_http_request_duration_seconds summary\n");
+        for ((method, path), totals) in self.request_duration.lock().unwrap().iter() {
+            out.push_str(&format!(
+                "keel_http_request_duration_seconds_sum{{method=\"{method}\",path=\"{path}\"}} {}\n",
+                totals.sum_seconds
+            ));
+            out.push_str(&format!(
+                "keel_http_request_duration_seconds_count{{method=\"{method}\",path=\"{path}\"}} {}\n",
+                totals.count
+            ));
+        }
+
+        {
+            let totals = self.compile_duration.lock().unwrap();
+            out.push_str(
+                "# HELP keel_compile_duration_seconds Time spent in /compile and /compile/stream.\n",
+            );
+            out.push_str("# TYPE keel_compile_duration_seconds summary\n");
+            out.push_str(&format!(
+                "keel_compile_duration_seconds_sum {}\n",
+                totals.sum_seconds
+            ));
+            out.push_str(&format!(
+                "keel_compile_duration_seconds_count {}\n",
+                totals.count
+            ));
+        }
+
+        out.push_str(
+            "# HELP keel_compile_nodes_updated_total Nodes updated across all compile runs.\n",
+        );
+        out.push_str("# TYPE keel_compile_nodes_updated_total counter\n");
+        out.push_str(&format!(
+            "keel_compile_nodes_updated_total {}\n",
+            self.compile_nodes_updated_total.load(Ordering::Relaxed)
+        ));
+
+        out.push_str(
+            "# HELP keel_compile_edges_updated_total Edges updated across all compile runs.\n",
+        );
+        out.push_str("# TYPE keel_compile_edges_updated_total counter\n");
+        out.push_str(&format!(
+            "keel_compile_edges_updated_total {}\n",
+            self.compile_edges_updated_total.load(Ordering::Relaxed)
+        ));
+
+        {
+            let totals = self.discover_duration.lock().unwrap();
+            out.push_str(
+                "# HELP keel_discover_duration_seconds Time spent resolving /discover lookups.\n",
+            );
+            out.push_str("# TYPE keel_discover_duration_seconds summary\n");
+            out.push_str(&format!(
+                "keel_discover_duration_seconds_sum {}\n",
+                totals.sum_seconds
+            ));
+            out.push_str(&format!(
+                "keel_discover_duration_seconds_count {}\n",
+                totals.count
+            ));
+        }
+
+        {
+            let totals = self.where_duration.lock().unwrap();
+            out.push_str(
+                "# HELP keel_where_duration_seconds Time spent resolving /where lookups.\n",
+            );
+            out.push_str("# TYPE keel_where_duration_seconds summary\n");
+            out.push_str(&format!(
+                "keel_where_duration_seconds_sum {}\n",
+                totals.sum_seconds
+            ));
+            out.push_str(&format!(
+                "keel_where_duration_seconds_count {}\n",
+                totals.count
+            ));
+        }
+
+        out.push_str("# HELP keel_sqlite_queries_total SQLite queries executed by handlers.\n");
+        out.push_str("# TYPE keel_sqlite_queries_total counter\n");
+        out.push_str(&format!(
+            "keel_sqlite_queries_total {}\n",
+            self.sqlite_queries_total.load(Ordering::Relaxed)
+        ));
+
+        out.push_str(
+            "# HELP keel_sqlite_batch_fanout_saved_total Individual queries avoided by using a batched lookup (e.g. nodes_with_relations_batch) instead of one query per item.\n",
+        );
+        out.push_str("# TYPE keel_sqlite_batch_fanout_saved_total counter\n");
+        out.push_str(&format!(
+            "keel_sqlite_batch_fanout_saved_total {}\n",
+            self.sqlite_batch_fanout_saved_total.load(Ordering::Relaxed)
+        ));
+
+        out
+    }
+}
+
+#[cfg(test)]
+#[path = "metrics_tests.rs"]
+mod tests;
@@ -0,0 +1,45 @@
+use super::*;
+
+#[test]
+fn test_record_request_counts_and_renders_by_route() {
+    let metrics = Metrics::default();
+    metrics.record_request("GET", "/health", 200, Duration::from_millis(5));
+    metrics.record_request("GET", "/health", 200, Duration::from_millis(7));
+
+    let rendered = metrics.render();
+    assert!(rendered
+        .contains("keel_http_requests_total{method=\"GET\",path=\"/health\",status=\"200\"} 2"));
+    assert!(rendered
+        .contains("keel_http_request_duration_seconds_count{method=\"GET\",path=\"/health\"} 2"));
+}
+
+#[test]
+fn test_record_compile_accumulates_nodes_and_edges() {
+    let metrics = Metrics::default();
+    metrics.record_compile(Duration::from_millis(10), 3, 2);
+    metrics.record_compile(Duration::from_millis(20), 1, 0);
+
+    let rendered = metrics.render();
+    assert!(rendered.contains("keel_compile_duration_seconds_count 2"));
+    assert!(rendered.contains("keel_compile_nodes_updated_total 4"));
+    assert!(rendered.contains("keel_compile_edges_updated_total 2"));
+}
+
+#[test]
+fn test_record_sqlite_queries_and_batch_fanout_saved() {
+    let metrics = Metrics::default();
+    metrics.record_sqlite_queries(4);
+    metrics.record_batch_fanout_saved(9);
+
+    let rendered = metrics.render();
+    assert!(rendered.contains("keel_sqlite_queries_total 4"));
+    assert!(rendered.contains("keel_sqlite_batch_fanout_saved_total 9"));
+}
+
+#[test]
+fn test_render_with_no_activity_still_emits_help_and_type_lines() {
+    let metrics = Metrics::default();
+    let rendered = metrics.render();
+    assert!(rendered.contains("# HELP keel_http_requests_total"));
+    assert!(rendered.contains("# TYPE keel_discover_duration_seconds summary"));
+}